@@ -1,3 +1,5 @@
+extern crate svd2ioregs;
+
 use std::ascii::AsciiExt;
 use std::env;
 use std::fs;
@@ -22,12 +24,32 @@ fn file_exists(file: &Path) -> bool {
     }
 }
 
-fn copy_linker_scripts<P: AsRef<Path>, Q: AsRef<Path>>(target: P, out_path: Q) -> io::Result<()> {
-  let path_prefix = if env::var("CARGO_MANIFEST_DIR").unwrap().find("/examples/").is_none() {
+fn get_path_prefix() -> &'static Path {
+  if env::var("CARGO_MANIFEST_DIR").unwrap().find("/examples/").is_none() {
     Path::new(".")
   } else {
     Path::new("./../..")
-  };
+  }
+}
+
+/// Returns the SVD file that platform registers are generated from.
+fn get_svd_file(platform: &str) -> Option<&'static str> {
+  match platform {
+    "lpc11xx" => Some("support/svd/data/NXP/LPC11xx-v6-z0.xml"),
+    "stm32f1" => Some("support/svd/data/STMicro/STM32F103xx.xml"),
+    _ => None,
+  }
+}
+
+fn generate_regs<P: AsRef<Path>>(platform: &str, out_path: P) -> Result<(), svd2ioregs::Error> {
+  match get_svd_file(platform) {
+    Some(svd) => svd2ioregs::generate(get_path_prefix().join(svd), out_path.as_ref().join("regs.rs")),
+    None => Ok(()),
+  }
+}
+
+fn copy_linker_scripts<P: AsRef<Path>, Q: AsRef<Path>>(target: P, out_path: Q) -> io::Result<()> {
+  let path_prefix = get_path_prefix();
   // Try copying the linker scripts
   let target_dir = Path::new("src/hal").join(target);
   let out_dir: &Path = out_path.as_ref();
@@ -57,6 +79,12 @@ fn main() {
     Err(e) => panic!("Failed to copy linker scripts: {}", e)
   }
 
+  // Generate register definitions from SVD
+  match generate_regs(&platform, &out_dir) {
+    Ok(_) => {},
+    Err(e) => panic!("Failed to generate registers: {}", e)
+  }
+
   // Make sure that the output dir is passed to linker
   println!("cargo:rustc-link-search=native={}", out_dir);
 }
@@ -4,44 +4,7 @@
 
 extern crate zinc;
 
-platformtree!(
-  lpc17xx@mcu {
-    clock {
-      source = "main-oscillator";
-      source_frequency = 12_000_000;
-      pll {
-        m = 50;
-        n = 3;
-        divisor = 4;
-      }
-    }
-
-    timer {
-      timer@1 {
-        counter = 25;
-        divisor = 4;
-      }
-    }
-
-    gpio {
-      1 {
-        led1@18 { direction = "out"; }
-        led2@20 { direction = "out"; }
-      }
-    }
-  }
-
-  os {
-    single_task {
-      loop = "run";
-      args {
-        timer = &timer;
-        led1 = &led1;
-        led2 = &led2;
-      }
-    }
-  }
-);
+platformtree!(file = "board.pt");
 
 fn run(args: &pt::run_args) {
   use zinc::hal::pin::Gpio;
@@ -31,13 +31,17 @@ pub struct BuildAccessors<'a, 'b> where 'b : 'a {
 impl<'a, 'b> node::RegVisitor for BuildAccessors<'a, 'b> {
   fn visit_prim_reg(&mut self, path: &Vec<String>, reg: &node::Reg,
                     fields: &Vec<node::Field>) {
-    if fields.iter().any(|f| f.access != node::Access::WriteOnly) {
+    if fields.iter().any(|f| f.access.is_readable()) {
       let item = build_get_fn(self.cx, path, reg);
       self.builder.push_item(item);
     }
-    if fields.iter().any(|f| f.access != node::Access::ReadOnly) {
+    if fields.iter().any(|f| f.access.is_writable()) {
       let item = build_ignoring_state_setter_fn(self.cx, path, reg);
       self.builder.push_item(item);
+      if reg.reset_value.is_some() {
+        let item = build_reset_fn(self.cx, path, reg);
+        self.builder.push_item(item);
+      }
     }
 
     for field in fields.iter() {
@@ -68,8 +72,10 @@ fn build_field_accessors(cx: &ExtCtxt, path: &Vec<String>,
                             build_field_get_fn(cx, path, reg, field)),
     node::Access::ReadOnly  => vec!(build_field_get_fn(cx, path, reg, field)),
     node::Access::WriteOnly => vec!(build_field_set_fn(cx, path, reg, field)),
-    node::Access::SetToClear => vec!(build_field_clear_fn(cx, path, reg, field),
-                             build_field_get_fn(cx, path, reg, field)),
+    node::Access::SetToClear | node::Access::ZeroToClear =>
+      vec!(build_field_clear_fn(cx, path, reg, field),
+           build_field_get_fn(cx, path, reg, field)),
+    node::Access::ReadToClear => vec!(build_field_get_fn(cx, path, reg, field)),
   };
 
   let access_tag = match field.access {
@@ -77,6 +83,8 @@ fn build_field_accessors(cx: &ExtCtxt, path: &Vec<String>,
     node::Access::ReadOnly  => "read-only",
     node::Access::WriteOnly => "write-only",
     node::Access::SetToClear => "set-to-clear",
+    node::Access::ZeroToClear => "zero-to-clear",
+    node::Access::ReadToClear => "read-to-clear",
   };
 
   let field_doc = match field.docstring {
@@ -148,6 +156,30 @@ fn build_ignoring_state_setter_fn(cx: &ExtCtxt, path: &Vec<String>, reg: &node::
   item.unwrap()
 }
 
+fn build_reset_fn(cx: &ExtCtxt, path: &Vec<String>, reg: &node::Reg)
+                  -> P<ast::Item>
+{
+  let reg_ty: P<ast::Ty> =
+    cx.ty_ident(reg.name.span, utils::path_ident(cx, path));
+  let setter_ty = utils::setter_name(cx, path);
+
+  let docstring = format!("Write the reset value to the `{}` register",
+                          reg.name.node);
+  let doc_attr = utils::doc_attribute(cx, utils::intern_string(cx, docstring));
+
+  let item = quote_item!(cx,
+    impl $reg_ty {
+      $doc_attr
+      #[allow(dead_code)]
+      #[inline(always)]
+      pub fn reset(&self) {
+        $setter_ty::from_reset(self).done()
+      }
+    }
+    );
+  item.unwrap()
+}
+
 fn build_field_set_fn(cx: &ExtCtxt, path: &Vec<String>,
                       reg: &node::Reg, field: &node::Field)
                       -> P<ast::ImplItem>
@@ -0,0 +1,175 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Ben Gamari <bgamari@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use syntax::ast;
+use syntax::ptr::P;
+use syntax::ext::base::ExtCtxt;
+use syntax::ext::build::AstBuilder;
+
+use super::Builder;
+use super::utils;
+use super::super::node;
+
+/// A visitor to build bit-band accessors for single bit fields of `reg32`
+/// registers
+pub struct BuildBitband<'a, 'b> where 'b : 'a {
+  builder: &'a mut Builder,
+  cx: &'a ExtCtxt<'b>,
+}
+
+impl<'a, 'b> BuildBitband<'a, 'b> {
+  pub fn new(builder: &'a mut Builder, cx: &'a ExtCtxt<'b>)
+             -> BuildBitband<'a, 'b> {
+    BuildBitband {builder: builder, cx: cx}
+  }
+}
+
+impl<'a, 'b> node::RegVisitor for BuildBitband<'a, 'b> {
+  fn visit_prim_reg(&mut self, path: &Vec<String>, reg: &node::Reg,
+                    fields: &Vec<node::Field>) {
+    match reg.ty {
+      node::RegType::RegPrim(ref width, _) => match width.node {
+        node::RegWidth::Reg32 => (),
+        _ => return,
+      },
+      _ => return,
+    }
+
+    // A bit-band write is a read-modify-write of the whole word done by the
+    // bus, so it would clear any other pending flags in the register
+    if fields.iter().any(|f| f.access == node::Access::SetToClear ||
+                             f.access == node::Access::ReadToClear) {
+      return;
+    }
+
+    let item = build_alias_fn(self.cx, path, reg);
+    self.builder.push_item(item);
+
+    for field in fields.iter() {
+      if field.width != 1 {
+        continue;
+      }
+      match field.access {
+        node::Access::ReadWrite | node::Access::ZeroToClear => {
+          let item = build_field_fn(self.cx, path, reg, field);
+          self.builder.push_item(item);
+        },
+        _ => (),
+      }
+    }
+  }
+}
+
+fn build_alias_fn(cx: &ExtCtxt, path: &Vec<String>, reg: &node::Reg)
+                  -> P<ast::Item>
+{
+  let reg_ty: P<ast::Ty> =
+    cx.ty_ident(reg.name.span, utils::path_ident(cx, path));
+
+  let docstring = format!("Bit-band alias of a bit of the `{}` register",
+                          reg.name.node);
+  let doc_attr = utils::doc_attribute(cx, utils::intern_string(cx, docstring));
+
+  let item = quote_item!(cx,
+    impl $reg_ty {
+      $doc_attr
+      #[allow(dead_code)]
+      #[inline(always)]
+      pub fn bitband_alias(&self, bit: usize) -> &VolatileCell<u32> {
+        let addr = &self.value as *const VolatileCell<u32> as usize;
+        let alias = 0x4200_0000usize
+          .wrapping_add(addr.wrapping_sub(0x4000_0000) << 5)
+          .wrapping_add(bit << 2);
+        unsafe { &*(alias as *const VolatileCell<u32>) }
+      }
+    }
+  );
+  item.unwrap()
+}
+
+fn build_field_fn(cx: &ExtCtxt, path: &Vec<String>, reg: &node::Reg,
+                  field: &node::Field) -> P<ast::Item>
+{
+  let reg_ty: P<ast::Ty> =
+    cx.ty_ident(reg.name.span, utils::path_ident(cx, path));
+
+  let clear = field.access == node::Access::ZeroToClear;
+  let (prefix, doc) = if clear {
+    ("clear_", "Atomically clear")
+  } else {
+    ("set_", "Atomically set")
+  };
+  let fn_name = cx.ident_of(
+    (String::from(prefix) + field.name.node.as_str() + "_bitband").as_str());
+  let docstring = format!("{} `{}` field through the bit-band alias",
+                          doc, field.name.node);
+  let doc_attr = utils::doc_attribute(cx, utils::intern_string(cx, docstring));
+
+  let item = match (field.count.node == 1, clear) {
+    (true, false) => {
+      let shift = utils::shift(cx, None, field);
+      quote_item!(cx,
+        impl $reg_ty {
+          $doc_attr
+          #[allow(dead_code)]
+          #[inline(always)]
+          pub fn $fn_name(&self, new_value: bool) {
+            self.bitband_alias($shift).set(new_value as u32);
+          }
+        }
+      )
+    },
+    (true, true) => {
+      let shift = utils::shift(cx, None, field);
+      quote_item!(cx,
+        impl $reg_ty {
+          $doc_attr
+          #[allow(dead_code)]
+          #[inline(always)]
+          pub fn $fn_name(&self) {
+            self.bitband_alias($shift).set(0);
+          }
+        }
+      )
+    },
+    (false, false) => {
+      let shift = utils::shift(cx, Some(quote_expr!(cx, idx)), field);
+      quote_item!(cx,
+        impl $reg_ty {
+          $doc_attr
+          #[allow(dead_code)]
+          #[inline(always)]
+          pub fn $fn_name(&self, idx: usize, new_value: bool) {
+            self.bitband_alias($shift).set(new_value as u32);
+          }
+        }
+      )
+    },
+    (false, true) => {
+      let shift = utils::shift(cx, Some(quote_expr!(cx, idx)), field);
+      quote_item!(cx,
+        impl $reg_ty {
+          $doc_attr
+          #[allow(dead_code)]
+          #[inline(always)]
+          pub fn $fn_name(&self, idx: usize) {
+            self.bitband_alias($shift).set(0);
+          }
+        }
+      )
+    },
+  };
+  item.unwrap()
+}
@@ -43,7 +43,7 @@ impl<'a, 'b> node::RegVisitor for BuildGetters<'a, 'b> {
   fn visit_prim_reg(&mut self, path: &Vec<String>,
                     reg: &node::Reg,
                     fields: &Vec<node::Field>) {
-    if fields.iter().any(|f| f.access != node::Access::WriteOnly) {
+    if fields.iter().any(|f| f.access.is_readable()) {
       let it = build_type(self.cx, path, reg);
       self.builder.push_item(it);
 
@@ -55,6 +55,9 @@ impl<'a, 'b> node::RegVisitor for BuildGetters<'a, 'b> {
       let it = quote_item!(self.cx,
                            impl ::core::marker::Copy for $ty_name {});
       self.builder.push_item(it.unwrap());
+
+      let it = build_debug(self.cx, path, fields);
+      self.builder.push_item(it);
     }
   }
 }
@@ -252,3 +255,83 @@ fn build_field_get_fn(cx: &ExtCtxt, path: &Vec<String>, reg: &node::Reg,
     ).unwrap())
   }
 }
+
+/// Build a `Debug` implementation printing all readable fields
+fn build_debug(cx: &ExtCtxt, path: &Vec<String>,
+               fields: &Vec<node::Field>) -> P<ast::Item> {
+  let getter_ty = utils::getter_name(cx, path);
+  let header = format!("{} {{", path.join("."));
+  let header = header.as_str();
+
+  let mut body = quote_expr!(cx, try!(f.write_str($header)));
+  let readable = fields.iter().filter(|f| f.access.is_readable());
+  for (i, field) in readable.enumerate() {
+    let label = format!("{} {}: ", if i == 0 { "" } else { "," },
+                        field.name.node);
+    let label = label.as_str();
+    let value = if field.count.node == 1 {
+      build_field_fmt(cx, field, utils::shift(cx, None, field))
+    } else {
+      let count = utils::expr_int(cx, respan(field.name.span,
+                                             field.count.node as u64));
+      let value = build_field_fmt(
+        cx, field, utils::shift(cx, Some(quote_expr!(cx, idx)), field));
+      quote_expr!(cx, {
+        try!(f.write_str("["));
+        for idx in 0..$count {
+          if idx != 0 {
+            try!(f.write_str(", "));
+          }
+          $value;
+        }
+        try!(f.write_str("]"));
+      })
+    };
+    body = quote_expr!(cx, {
+      $body;
+      try!(f.write_str($label));
+      $value;
+    });
+  }
+
+  let it = quote_item!(cx,
+    impl ::core::fmt::Debug for $getter_ty {
+      fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        $body;
+        f.write_str(" }")
+      }
+    }
+  );
+  it.unwrap()
+}
+
+/// Build an expression writing the value of a field to the formatter `f`,
+/// enum fields are written by variant name
+fn build_field_fmt(cx: &ExtCtxt, field: &node::Field,
+                   shift: P<ast::Expr>) -> P<ast::Expr> {
+  let mask = utils::mask(cx, field);
+  let raw = quote_expr!(cx, (self.value >> $shift) & $mask);
+  match field.ty.node {
+    node::FieldType::UIntField =>
+      quote_expr!(cx, try!(write!(f, "{}", $raw))),
+    node::FieldType::BoolField =>
+      quote_expr!(cx, try!(write!(f, "{}", $raw != 0))),
+    node::FieldType::EnumField { ref variants, .. } => {
+      let mut arms: Vec<ast::Arm> = Vec::new();
+      for v in variants.iter() {
+        let lit = cx.expr_lit(
+          v.value.span,
+          ast::LitKind::Int(v.value.node, ast::LitIntType::Unsuffixed));
+        let name = v.name.node.as_str();
+        arms.push(cx.arm(v.name.span, vec!(cx.pat_lit(v.value.span, lit)),
+                         quote_expr!(cx, try!(f.write_str($name)))));
+      }
+      // values without a variant are printed as numbers
+      let unknown = cx.ident_of("unknown");
+      arms.push(cx.arm(field.name.span,
+                       vec!(cx.pat_ident(field.name.span, unknown)),
+                       quote_expr!(cx, try!(write!(f, "{:#x}", $unknown)))));
+      cx.expr_match(field.name.span, raw, arms)
+    },
+  }
+}
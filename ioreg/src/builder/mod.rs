@@ -26,6 +26,7 @@ mod getter;
 mod union;
 mod register;
 mod accessors;
+mod bitband;
 
 pub struct Builder {
   items: Vec<P<ast::Item>>,
@@ -43,6 +44,9 @@ impl Builder {
     node::visit_reg(&*reg, &mut register::BuildRegStructs::new(self, cx));
     node::visit_reg(&*reg, &mut union::BuildUnionTypes::new(self, cx));
     node::visit_reg(&*reg, &mut accessors::BuildAccessors::new(self, cx));
+    if reg.bitband {
+      node::visit_reg(&*reg, &mut bitband::BuildBitband::new(self, cx));
+    }
     self.items.clone()
   }
 
@@ -74,7 +74,7 @@ fn build_field_type(cx: &ExtCtxt, path: &Vec<String>,
       };
       let mut attrs: Vec<ast::Attribute> = vec!(
         utils::list_attribute(cx, "derive",
-                              vec!("PartialEq"),
+                              vec!("PartialEq", "Debug"),
                               field.name.span),
         utils::list_attribute(cx, "allow",
                               vec!("dead_code",
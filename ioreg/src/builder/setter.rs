@@ -17,6 +17,7 @@ use std::iter::FromIterator;
 use std::ops::Deref;
 
 use syntax::ast;
+use syntax::codemap::{respan, Spanned};
 use syntax::ptr::P;
 use syntax::ext::base::ExtCtxt;
 use syntax::ext::build::AstBuilder;
@@ -42,7 +43,7 @@ impl<'a, 'c> node::RegVisitor for BuildSetters<'a, 'c> {
   fn visit_prim_reg<'b>(&'b mut self, path: &Vec<String>,
       reg: &'b node::Reg, fields: &Vec<node::Field>)
   {
-    if fields.iter().any(|f| f.access != node::Access::ReadOnly) {
+    if fields.iter().any(|f| f.access.is_writable()) {
       let it = build_type(self.cx, path, reg, fields);
       self.builder.push_item(it);
 
@@ -129,6 +130,44 @@ fn build_new_ignoring_state<'a>(cx: &'a ExtCtxt, path: &Vec<String>,
   ).unwrap())
 }
 
+fn build_new_from_reset<'a>(cx: &'a ExtCtxt, path: &Vec<String>,
+    reg: &node::Reg, fields: &Vec<node::Field>,
+    reset_value: Spanned<u64>) -> P<ast::ImplItem> {
+  let reg_ty: P<ast::Ty> =
+    cx.ty_ident(reg.name.span, utils::path_ident(cx, path));
+  let setter_ident = utils::setter_name(cx, path);
+
+  // writing the reset value back must not clear any flags
+  let zeros = access_bits(fields, node::Access::SetToClear);
+  let ones = access_bits(fields, node::Access::ZeroToClear);
+  let value = utils::expr_int(cx, respan(reset_value.span,
+                                         reset_value.node & !zeros | ones));
+  utils::unwrap_impl_item(quote_item!(cx,
+    impl<'a> $setter_ident<'a> {
+      #[doc="Create a new updater starting from the register's reset value"]
+      #[inline(always)]
+      pub fn from_reset(reg: &'a $reg_ty) -> $setter_ident<'a> {
+        $setter_ident {
+          value: $value,
+          mask: !0,
+          write_only: true,
+          reg: reg,
+        }
+      }
+    }
+  ).unwrap())
+}
+
+/// The bits owned by fields with the given access
+fn access_bits(fields: &Vec<node::Field>, access: node::Access) -> u64 {
+  fields.iter()
+    .filter(|f| f.access == access)
+    .fold(0, |bits, f| {
+      let width = (f.width * f.count.node) as u64;
+      bits | ((1u64 << width) - 1) << f.low_bit as u64
+    })
+}
+
 fn build_drop(cx: &ExtCtxt, path: &Vec<String>,
     reg: &node::Reg, fields: &Vec<node::Field>) -> P<ast::Item>
 {
@@ -136,22 +175,20 @@ fn build_drop(cx: &ExtCtxt, path: &Vec<String>,
   let unpacked_ty = utils::reg_primitive_type(cx, reg)
     .expect("Unexpected non-primitive register");
 
-  // ensure we don't unintentionally clear a set-to-clear flag
-  let mut clear: u32 = 0;
-  for f in fields.iter() {
-    match f.access {
-      node::Access::SetToClear => {
-        let mask = 1 << (f.count.node * f.width) - 1;
-        clear |= mask;
-      },
-      _ => {},
-    }
-  }
+  // ensure we don't unintentionally clear a flag: set-to-clear flags are
+  // written as zeros and zero-to-clear flags as ones unless they are
+  // explicitly cleared by this update
+  let zeros = utils::expr_int(cx, respan(reg.name.span,
+      access_bits(fields, node::Access::SetToClear)));
+  let ones = utils::expr_int(cx, respan(reg.name.span,
+      access_bits(fields, node::Access::ZeroToClear)));
 
-  // no need to read write-only registers
+  // no need to read write-only registers, and reading a register with
+  // read-to-clear flags would clear them as a side effect
   let wo_reg: bool = fields.iter().all(|f| f.access == node::Access::WriteOnly);
+  let rc_reg: bool = fields.iter().any(|f| f.access == node::Access::ReadToClear);
   let initial_value =
-    if wo_reg {
+    if wo_reg || rc_reg {
       quote_expr!(cx, 0)
     } else {
       quote_expr!(cx, if self.write_only { 0 } else { self.reg.value.get() })
@@ -162,9 +199,10 @@ fn build_drop(cx: &ExtCtxt, path: &Vec<String>,
     impl<'a> Drop for $setter_ident<'a> {
       #[inline(always)]
       fn drop(&mut self) {
-        let clear_mask: $unpacked_ty = $clear as $unpacked_ty;
+        let zeros_mask: $unpacked_ty = $zeros;
+        let ones_mask: $unpacked_ty = $ones;
         if self.mask != 0 {
-          let v: $unpacked_ty = $initial_value & ! clear_mask & ! self.mask;
+          let v: $unpacked_ty = ($initial_value & ! zeros_mask | ones_mask) & ! self.mask;
           self.reg.value.set(self.value | v);
         }
       }
@@ -189,6 +227,9 @@ fn build_impl(cx: &ExtCtxt, path: &Vec<String>, reg: &node::Reg,
 {
   let new = build_new(cx, path, reg);
   let new_is = build_new_ignoring_state(cx, path, reg);
+  let from_reset: Vec<P<ast::ImplItem>> = reg.reset_value.iter()
+    .map(|v| build_new_from_reset(cx, path, reg, fields, *v))
+    .collect();
   let setter_ident = utils::setter_name(cx, path);
   let methods: Vec<P<ast::ImplItem>> =
     FromIterator::from_iter(
@@ -200,6 +241,7 @@ fn build_impl(cx: &ExtCtxt, path: &Vec<String>, reg: &node::Reg,
     impl<'a> $setter_ident<'a> {
       $new
       $new_is
+      $from_reset
       $methods
       $done
     }
@@ -210,8 +252,10 @@ fn build_field_fn(cx: &ExtCtxt, path: &Vec<String>, reg: &node::Reg,
                   field: &node::Field) -> Option<P<ast::ImplItem>>
 {
   match field.access {
-    node::Access::ReadOnly => None,
+    node::Access::ReadOnly | node::Access::ReadToClear => None,
     node::Access::SetToClear => Some(build_field_clear_fn(cx, path, reg, field)),
+    node::Access::ZeroToClear =>
+      Some(build_field_zero_clear_fn(cx, path, reg, field)),
     _ => Some(build_field_set_fn(cx, path, reg, field)),
   }
 }
@@ -261,7 +305,7 @@ fn build_field_set_fn(cx: &ExtCtxt, path: &Vec<String>, reg: &node::Reg,
         pub fn $fn_name<'b>(&'b mut self,
                             idx: usize,
                             new_value: $field_ty) -> &'b mut $setter_ty<'a> {
-          self.value |= (self.value & ! $mask) | ((new_value as $unpacked_ty) & $mask) << $shift;
+          self.value = (self.value & !($mask << $shift)) | ((new_value as $unpacked_ty) & $mask) << $shift;
           self.mask |= $mask << $shift;
           self
         }
@@ -315,3 +359,51 @@ fn build_field_clear_fn(cx: &ExtCtxt, path: &Vec<String>,
     ).unwrap())
   }
 }
+
+/// Build a clear method for a zero-to-clear field, these are cleared by
+/// writing zeros
+fn build_field_zero_clear_fn(cx: &ExtCtxt, path: &Vec<String>,
+    _: &node::Reg, field: &node::Field) -> P<ast::ImplItem>
+{
+  let setter_ty = utils::setter_name(cx, path);
+  let fn_name =
+    cx.ident_of((String::from("clear_")+field.name.node.as_str()).as_str());
+  let mask = utils::mask(cx, field);
+
+  let field_doc = match field.docstring {
+    Some(d) => d.node.name.to_string(),
+    None => "no documentation".to_string(),
+  };
+  let docstring = format!("Clear `{}` flag: {}",
+                          field.name.node,
+                          field_doc);
+  let doc_attr = utils::doc_attribute(cx, utils::intern_string(cx, docstring));
+
+  if field.count.node == 1 {
+    let shift = utils::shift(cx, None, field);
+    utils::unwrap_impl_item(quote_item!(cx,
+      impl<'a> $setter_ty<'a> {
+        $doc_attr
+        #[inline(always)]
+        pub fn $fn_name<'b>(&'b mut self) -> &'b mut $setter_ty<'a> {
+          self.value &= !($mask << $shift);
+          self.mask |= $mask << $shift;
+          self
+        }
+      }
+    ).unwrap())
+  } else {
+    let shift = utils::shift(cx, Some(quote_expr!(cx, idx)), field);
+    utils::unwrap_impl_item(quote_item!(cx,
+      impl<'a> $setter_ty<'a> {
+        $doc_attr
+        #[inline(always)]
+        pub fn $fn_name<'b>(&'b mut self, idx: usize) -> &'b mut $setter_ty<'a> {
+          self.value &= !($mask << $shift);
+          self.mask |= $mask << $shift;
+          self
+        }
+      }
+    ).unwrap())
+  }
+}
@@ -0,0 +1,263 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Ben Gamari <bgamari@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON export of register block descriptions.
+//!
+//! When the `IOREGS_EXPORT_DIR` environment variable is set at build time
+//! every `ioregs!` block is also written to `$IOREGS_EXPORT_DIR`, so that host
+//! tools can decode raw register values by name. Many blocks share a name
+//! (e.g. `TIMER`), so the file name is qualified with the module path of the
+//! block, e.g. `zinc.hal.lpc17xx.timer.reg.TIMER.json`.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
+use std::env;
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serialize::json::{self, Json};
+use syntax::ast;
+use syntax::codemap::Spanned;
+use syntax::ext::base::ExtCtxt;
+
+use node;
+
+/// Name of the environment variable selecting the export directory.
+pub const EXPORT_DIR_VAR: &'static str = "IOREGS_EXPORT_DIR";
+
+thread_local!(static EXPORTED: RefCell<HashSet<PathBuf>> =
+    RefCell::new(HashSet::new()));
+
+/// Writes the description of the block if exporting is enabled.
+///
+/// Reports an error if another block of the crate was already exported to the
+/// same file.
+pub fn export(cx: &ExtCtxt, reg: &node::Reg) {
+  let dir = match env::var(EXPORT_DIR_VAR) {
+    Ok(dir) => dir,
+    Err(_) => return,
+  };
+  let mod_path: Vec<String> = cx.mod_path().iter()
+    .map(|i| i.name.as_str().to_string())
+    .collect();
+  let path = Path::new(&dir).join(export_file_name(&mod_path, &reg.name.node));
+
+  let is_new = EXPORTED.with(|exported| {
+    exported.borrow_mut().insert(path.clone())
+  });
+  if !is_new {
+    cx.span_err(reg.name.span, format!(
+        "register description is already exported to {} by another block",
+        path.display()).as_str());
+    return;
+  }
+
+  let json = format!("{}\n", json::as_pretty_json(&reg_to_json(reg)));
+
+  let res = fs::create_dir_all(&dir)
+    .and_then(|_| File::create(&path))
+    .and_then(|mut f| f.write_all(json.as_bytes()));
+  match res {
+    Ok(_) => (),
+    Err(e) => {
+      cx.span_warn(reg.name.span, format!(
+          "failed to export register description to {}: {}",
+          path.display(), e).as_str());
+    },
+  }
+}
+
+/// Returns the export file name of block `name` defined in module `mod_path`.
+pub fn export_file_name(mod_path: &[String], name: &str) -> String {
+  let mut parts = mod_path.to_vec();
+  parts.push(name.to_string());
+  format!("{}.json", parts.join("."))
+}
+
+/// Builds the JSON description of a register or register group.
+pub fn reg_to_json(reg: &node::Reg) -> Json {
+  let mut obj = BTreeMap::new();
+  obj.insert("name".to_string(), Json::String(reg.name.node.clone()));
+  obj.insert("offset".to_string(), Json::U64(reg.offset));
+  obj.insert("count".to_string(), Json::U64(reg.count.node as u64));
+  insert_docstring(&mut obj, &reg.docstring);
+  if reg.address != 0 {
+    obj.insert("address".to_string(), Json::U64(reg.address as u64));
+  }
+
+  match reg.ty {
+    node::RegType::RegPrim(ref width, ref fields) => {
+      obj.insert("width".to_string(), Json::U64(8 * width.node.size()));
+      match reg.reset_value {
+        Some(v) => { obj.insert("reset_value".to_string(), Json::U64(v.node)); },
+        None => (),
+      }
+      obj.insert("fields".to_string(),
+                 Json::Array(fields.iter().map(field_to_json).collect()));
+    },
+    node::RegType::RegUnion(ref regs) => {
+      obj.insert("registers".to_string(),
+                 Json::Array(regs.iter().map(reg_to_json).collect()));
+    },
+  }
+  Json::Object(obj)
+}
+
+fn field_to_json(field: &node::Field) -> Json {
+  let mut obj = BTreeMap::new();
+  obj.insert("name".to_string(), Json::String(field.name.node.clone()));
+  obj.insert("low_bit".to_string(), Json::U64(field.low_bit as u64));
+  obj.insert("width".to_string(), Json::U64(field.width as u64));
+  obj.insert("count".to_string(), Json::U64(field.count.node as u64));
+  obj.insert("access".to_string(), Json::String(access_name(field.access).to_string()));
+  insert_docstring(&mut obj, &field.docstring);
+
+  let ty = match field.ty.node {
+    node::FieldType::UIntField => "uint",
+    node::FieldType::BoolField => "bool",
+    node::FieldType::EnumField { ref variants, .. } => {
+      let variants = variants.iter().map(|v| {
+        let mut obj = BTreeMap::new();
+        obj.insert("name".to_string(), Json::String(v.name.node.clone()));
+        obj.insert("value".to_string(), Json::U64(v.value.node));
+        insert_docstring(&mut obj, &v.docstring);
+        Json::Object(obj)
+      }).collect();
+      obj.insert("variants".to_string(), Json::Array(variants));
+      "enum"
+    },
+  };
+  obj.insert("type".to_string(), Json::String(ty.to_string()));
+  Json::Object(obj)
+}
+
+/// The modifier used for the access type in `ioregs!`.
+fn access_name(access: node::Access) -> &'static str {
+  match access {
+    node::Access::ReadWrite => "rw",
+    node::Access::ReadOnly => "ro",
+    node::Access::WriteOnly => "wo",
+    node::Access::SetToClear => "set_to_clear",
+    node::Access::ZeroToClear => "zero_to_clear",
+    node::Access::ReadToClear => "read_to_clear",
+  }
+}
+
+fn insert_docstring(obj: &mut BTreeMap<String, Json>,
+                    docstring: &Option<Spanned<ast::Ident>>) {
+  match *docstring {
+    Some(ref d) => {
+      obj.insert("docstring".to_string(),
+                 Json::String(d.node.name.as_str().to_string()));
+    },
+    None => (),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::rc::Rc;
+  use serialize::json::Json;
+  use syntax::codemap::{respan, DUMMY_SP};
+
+  use node;
+  use super::{export_file_name, reg_to_json};
+
+  fn field(name: &str, low_bit: u8, width: u8, count: u8,
+      access: node::Access, ty: node::FieldType) -> node::Field {
+    node::Field {
+      name: respan(DUMMY_SP, name.to_string()),
+      low_bit: low_bit,
+      width: width,
+      count: respan(DUMMY_SP, count),
+      bit_range_span: DUMMY_SP,
+      access: access,
+      ty: respan(DUMMY_SP, ty),
+      docstring: None,
+    }
+  }
+
+  fn variant(name: &str, value: u64) -> node::Variant {
+    node::Variant {
+      name: respan(DUMMY_SP, name.to_string()),
+      value: respan(DUMMY_SP, value),
+      docstring: None,
+    }
+  }
+
+  #[test]
+  fn exports_fields_arrays_and_enums() {
+    let fields = vec!(
+      field("enable", 0, 1, 1, node::Access::ReadWrite,
+          node::FieldType::BoolField),
+      field("mode", 4, 2, 2, node::Access::WriteOnly,
+          node::FieldType::UIntField),
+      field("speed", 8, 2, 1, node::Access::ZeroToClear,
+          node::FieldType::EnumField {
+            opt_name: None,
+            variants: vec!(variant("Slow", 0), variant("Fast", 1)),
+          }),
+    );
+    let cr = node::Reg {
+      offset: 4,
+      name: respan(DUMMY_SP, "cr".to_string()),
+      ty: node::RegType::RegPrim(respan(DUMMY_SP, node::RegWidth::Reg16),
+          fields),
+      count: respan(DUMMY_SP, 2),
+      docstring: None,
+      address: 0,
+      reset_value: Some(respan(DUMMY_SP, 0x100)),
+      bitband: false,
+    };
+    let block = node::Reg {
+      offset: 0,
+      name: respan(DUMMY_SP, "UART".to_string()),
+      ty: node::RegType::RegUnion(Rc::new(vec!(cr))),
+      count: respan(DUMMY_SP, 1),
+      docstring: None,
+      address: 0x4000c000,
+      reset_value: None,
+      bitband: false,
+    };
+
+    let expected = Json::from_str(r#"{
+      "name": "UART", "offset": 0, "count": 1, "address": 1073790976,
+      "registers": [{
+        "name": "cr", "offset": 4, "count": 2, "width": 16,
+        "reset_value": 256,
+        "fields": [
+          {"name": "enable", "low_bit": 0, "width": 1, "count": 1,
+           "access": "rw", "type": "bool"},
+          {"name": "mode", "low_bit": 4, "width": 2, "count": 2,
+           "access": "wo", "type": "uint"},
+          {"name": "speed", "low_bit": 8, "width": 2, "count": 1,
+           "access": "zero_to_clear", "type": "enum",
+           "variants": [{"name": "Slow", "value": 0},
+                        {"name": "Fast", "value": 1}]}
+        ]
+      }]
+    }"#).unwrap();
+    assert_eq!(reg_to_json(&block), expected);
+  }
+
+  #[test]
+  fn qualifies_file_name_with_module_path() {
+    let mod_path = vec!("zinc".to_string(), "hal".to_string(),
+        "timer".to_string());
+    assert_eq!(export_file_name(&mod_path, "TIMER"),
+        "zinc.hal.timer.TIMER.json".to_string());
+  }
+}
@@ -264,6 +264,13 @@ let cr: UART_cr_Get = UART.cr.get();
 format!("txe={}, rxe={}, br={}", cr.txe(), cr.rxe(), cr.br())
 ```
 
+`UART_cr_Get` also implements `Debug`, printing all readable fields
+with enum fields decoded to their variant names,
+
+```notrust
+UART.cr { rxe: true, txe: true, rxie: false, txie: false, br: 26, parity: EvenParity }
+```
+
 In the case of read-only (resp. write-only) fields the set (resp. get)
 method is omitted. In the case of `set_to_clear` fields a `clear`
 method is instead produced in place of `set`. For instance, in the
@@ -274,6 +281,77 @@ pub fn fe(self: &UART_sr_Getter) -> bool { ... }
 pub fn clear_fe(self: &UART_sr_Update) -> UART_sr_Update { ... }
 ```
 
+`zero_to_clear` fields get a similar `clear` method which writes zero
+instead. An update only clears the flags it was explicitly asked to:
+`set_to_clear` flags are written as zeros and `zero_to_clear` flags
+as ones otherwise, whatever their current state. Fields may also be
+marked `read_to_clear`, in which case only a get method is produced.
+Updates never read a register containing such fields, as that would
+clear the flags as a side effect, so any fields not explicitly set
+are written as zeros.
+
+A register may also be given a reset value, the value the hardware
+loads into it on reset,
+
+```
+0x0    => reg32 cr = 0x1a0 { ... }
+```
+
+For such registers `UART_cr` gets a `reset` method writing the reset
+value back to the register, and `UART_cr_Update` gets a `from_reset`
+constructor. Unlike updates created by the set methods, which start
+from the current register value, an update created with `from_reset`
+starts from the reset value, so any fields not explicitly set are
+written with their reset values,
+
+```
+UART_cr_Update::from_reset(&UART.cr).set_rxe(true).set_br(26);
+```
+
+Flags are never cleared by `reset` or `from_reset`, even if they are
+set in the reset value.
+
+### Bit-banding
+
+Cortex-M3 and M4 parts alias each bit of the peripheral region
+(`0x40000000` to `0x400fffff`) to a word in the bit-band region,
+allowing a single bit to be written without a software
+read-modify-write. Blocks declared with the `bitband` option,
+
+```
+ioregs!(GPIO : bitband = { ... })
+```
+
+get a `bitband_alias` method on each `reg32` register returning the
+alias word for a given bit, and a `set_FIELD_bitband` method for each
+single bit read/write field (`clear_FIELD_bitband` for `zero_to_clear`
+flags),
+
+```
+GPIO.odr.set_od_bitband(5, true);
+```
+
+These writes are atomic, so they can be safely used from interrupt
+handlers while other code updates the same register. As the bus
+still performs a read-modify-write of the whole word, registers with
+`set_to_clear` or `read_to_clear` fields don't get bit-band accessors.
+
+### Exporting register descriptions
+
+If the `IOREGS_EXPORT_DIR` environment variable is set at build time,
+a JSON description of each block (registers, offsets, fields, access
+modifiers, enum variants and reset values) is written to
+`$IOREGS_EXPORT_DIR/PATH.NAME.json`, where `PATH` is the module path of the
+block with `::` replaced by `.`. Exporting two blocks to the same file is an
+error. Host tools can use these to decode raw register values, e.g.
+`support/regdecode.py`,
+
+```notrust
+$ IOREGS_EXPORT_DIR=target/ioregs cargo build
+$ support/regdecode.py target/ioregs/app.uart.UART.json cr 0x81a3
+rxe=1 txe=1 rxie=0 txie=0 br=26 parity=EvenParity
+```
+
 ### Informal grammar
 
 In the below discussion `THING, ...` will denote a list of one or more
@@ -284,9 +362,12 @@ brackets.
 The `ioregs!` macro expects a definition of the form,
 
 ```
-ioregs!(IDENT = { REG, ... })
+ioregs!(IDENT ⟦@ ADDRESS⟧ ⟦: bitband⟧ = { REG, ... })
 ```
 
+where `ADDRESS` places the block at a fixed address and `bitband`
+enables bit-band accessors for the block.
+
 Where a `REG` is either a register group,
 
 ```notrust
@@ -296,12 +377,13 @@ OFFSET => group IDENT⟦[COUNT]⟧ { REG, ... }
 or a primitive register,
 
 ```notrust
-OFFSET => TYPE IDENT⟦[COUNT]⟧ { FIELD, ... }
+OFFSET => TYPE IDENT⟦[COUNT]⟧ ⟦= RESET⟧ { FIELD, ... }
 ```
 
 `COUNT` is an integer count and a register `TYPE` is one of `reg8` (a
 one byte wide register), `reg16` (two bytes wide), or `reg32` (four
-bytes wide).
+bytes wide). `RESET` is an integer giving the value of the register
+after reset; it must fit in the register's width.
 
 A field is given by
 
@@ -316,8 +398,10 @@ of type `bool` and wider fields unsigned integers (in particular, of
 the same width as the containing register).
 
 A `MODIFIER` is one of `rw` (read/write), `ro` (read-only), `wo`
-(write-only), or `set_to_clear` (a flag which can be cleared by
-setting to one).
+(write-only), `set_to_clear` (a flag which can be cleared by
+setting to one), `zero_to_clear` (a flag which can be cleared by
+writing zero, writing one has no effect), or `read_to_clear` (a flag
+which is cleared by reading the register).
 
 A `VALUE` is given by,
 
@@ -346,6 +430,7 @@ use syntax::print::pprust::item_to_string;
 pub mod node;
 pub mod parser;
 pub mod builder;
+pub mod export;
 
 #[plugin_registrar]
 pub fn plugin_registrar(reg: &mut Registry) {
@@ -357,6 +442,7 @@ pub fn macro_ioregs(cx: &mut ExtCtxt, _: Span, tts: &[tokenstream::TokenTree])
                     -> Box<MacResult+'static> {
   match parser::Parser::new(cx, tts).parse_ioregs() {
     Some(group) => {
+      export::export(cx, &*group);
       let mut builder = builder::Builder::new();
       let items = builder.emit_items(cx, group);
       MacItems::new(items)
@@ -371,6 +457,7 @@ pub fn macro_ioregs_debug(cx: &mut ExtCtxt, _: Span, tts: &[tokenstream::TokenTr
                     -> Box<MacResult+'static> {
   match parser::Parser::new(cx, tts).parse_ioregs() {
     Some(group) => {
+      export::export(cx, &*group);
       let mut builder = builder::Builder::new();
       let items = builder.emit_items(cx, group);
       for ref i in &items {
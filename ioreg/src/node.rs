@@ -47,6 +47,22 @@ pub enum Access {
   WriteOnly,
   /// A flag which can be set to clear
   SetToClear,
+  /// A flag which can be cleared by writing zero, writing one has no effect
+  ZeroToClear,
+  /// A flag which is cleared by reading the register
+  ReadToClear,
+}
+
+impl Access {
+  /// Whether the field can be read
+  pub fn is_readable(&self) -> bool {
+    *self != Access::WriteOnly
+  }
+
+  /// Whether the field can be written
+  pub fn is_writable(&self) -> bool {
+    *self != Access::ReadOnly && *self != Access::ReadToClear
+  }
 }
 
 #[derive(Clone, Debug)]
@@ -119,6 +135,10 @@ pub struct Reg {
   pub count: Spanned<u32>,
   pub docstring: Option<Spanned<ast::Ident>>,
   pub address: usize,
+  /// The value of a primitive register after reset, if known
+  pub reset_value: Option<Spanned<u64>>,
+  /// Whether to build bit-band accessors for the registers of this block
+  pub bitband: bool,
 }
 
 impl Reg {
@@ -28,6 +28,11 @@ use syntax::parse::lexer::Reader;
 use node;
 use node::RegType;
 
+/// Start of the Cortex-M peripheral bit-band region
+const BITBAND_PERIPH_START: usize = 0x4000_0000;
+/// End of the Cortex-M peripheral bit-band region
+const BITBAND_PERIPH_END: usize = 0x4010_0000;
+
 /// The scope of a doc comment
 enum Scope {
   /// Applies to the next item in the block (///)
@@ -90,6 +95,27 @@ impl<'a, 'b> Parser<'a, 'b> {
       };
     }
 
+    let mut bitband = false;
+    if self.token == token::Colon {
+      self.bump();
+      match self.expect_ident() {
+        Some(ref i) if i.eq(&"bitband") => bitband = true,
+        Some(i) => {
+          self.sess.span_diagnostic.span_err(self.last_span,
+            format!("Expected block option, saw `{}`", i).as_str());
+          return None;
+        },
+        None => return None,
+      }
+      if address != 0 && (address < BITBAND_PERIPH_START ||
+                          address >= BITBAND_PERIPH_END) {
+        self.sess.span_diagnostic.span_err(self.last_span,
+          format!("Block at 0x{:x} is outside of the bit-band peripheral region",
+                  address).as_str());
+        return None;
+      }
+    }
+
     if !self.expect(&token::Eq) {
       return None;
     }
@@ -113,6 +139,8 @@ impl<'a, 'b> Parser<'a, 'b> {
       count: respan(mk_sp(sp_lo, self.span.hi), 1),
       docstring: docstring,
       address: address,
+      reset_value: None,
+      bitband: bitband,
     };
 
     Some(Rc::new(group))
@@ -219,6 +247,10 @@ impl<'a, 'b> Parser<'a, 'b> {
       None => return None,
       Some(count) => count,
     };
+    let reset_value = match self.parse_reset_value(&ty) {
+      Err(()) => return None,
+      Ok(reset_value) => reset_value,
+    };
 
     // Potentially a trailing docstring before the block
     let docstring = docstring.or_else(|| self.parse_docstring(Scope::Trailing));
@@ -278,6 +310,8 @@ impl<'a, 'b> Parser<'a, 'b> {
       count: count,
       docstring: docstring,
       address: 0,
+      reset_value: reset_value,
+      bitband: false,
     })
   }
 
@@ -386,6 +420,8 @@ impl<'a, 'b> Parser<'a, 'b> {
               ref s if s.eq(&"ro") => { self.bump(); node::Access::ReadOnly  },
               ref s if s.eq(&"wo") => { self.bump(); node::Access::WriteOnly },
               ref s if s.eq(&"set_to_clear") => { self.bump(); node::Access::SetToClear },
+              ref s if s.eq(&"zero_to_clear") => { self.bump(); node::Access::ZeroToClear },
+              ref s if s.eq(&"read_to_clear") => { self.bump(); node::Access::ReadToClear },
               s => {
                 self.error(format!("Expected access type, saw `{}`", s));
                 return None;
@@ -591,6 +627,38 @@ impl<'a, 'b> Parser<'a, 'b> {
     }
   }
 
+  /// Parses an optional `= VALUE` reset value following a register name.
+  /// `Err` indicates parse failure.
+  fn parse_reset_value(&mut self, ty: &RegType)
+      -> Result<Option<Spanned<u64>>, ()> {
+    if self.token != token::Eq {
+      return Ok(None);
+    }
+    self.bump();
+
+    let value = match self.expect_usize() {
+      Some(value) => respan(self.last_span, value),
+      None => return Err(()),
+    };
+    match *ty {
+      RegType::RegPrim(ref width, _) => {
+        let bits = 8 * width.node.size();
+        if bits < 64 && value.node >> bits != 0 {
+          self.sess.span_diagnostic.span_err(value.span,
+            format!("Reset value {:#x} does not fit in a {}-bit register",
+                    value.node, bits).as_str());
+          return Err(());
+        }
+      },
+      RegType::RegUnion(_) => {
+        self.sess.span_diagnostic.span_err(value.span,
+          "Register groups cannot have a reset value");
+        return Err(());
+      },
+    }
+    Ok(Some(value))
+  }
+
   fn error(&self, m: String) {
     self.sess.span_diagnostic.span_err(self.span, m.as_str());
   }
@@ -82,6 +82,15 @@ mod test {
     assert_eq!(get_value(&test, 0), 1<<25);
   }
 
+  #[test]
+  fn no_write_back_set_to_clear_fields() {
+    let test: BASIC_TEST = zeroed_safe();
+
+    test.reg1.clear_field4();
+    test.reg1.set_field1(true);
+    assert_eq!(get_value(&test, 0), 1);
+  }
+
   #[test]
   fn no_read_writeonly_registers() {
     let test: BASIC_TEST = zeroed_safe();
@@ -143,6 +152,48 @@ mod test {
      );
      */
 
+  #[test]
+  fn formats_snapshots() {
+    let test: BASIC_TEST = zeroed_safe();
+
+    test.reg1.set_field1(true).set_field2(0b010).set_field3(0xde);
+    assert_eq!(format!("{:?}", test.reg1.get()),
+               "BASIC_TEST.reg1 { field1: true, field2: 2, field3: 222, field4: false }");
+  }
+
+  ioregs!(DEBUG_TEST @ 0 = {
+    0x0 => reg32 reg1 {
+      0..1   => mode {
+        0 => Off,
+        1 => On,
+      }
+      4..7   => flags[4],
+      8..15  => data: wo,
+    }
+  });
+
+  #[test]
+  fn formats_enum_and_array_fields() {
+    let test: DEBUG_TEST = zeroed_safe();
+
+    test.reg1.set_mode(DEBUG_TEST_reg1_mode::On).set_flags(2, true);
+    assert_eq!(format!("{:?}", test.reg1.get()),
+               "DEBUG_TEST.reg1 { mode: On, flags: [false, false, true, false] }");
+  }
+
+  #[test]
+  fn formats_unknown_enum_values() {
+    let test: DEBUG_TEST = zeroed_safe();
+
+    test.reg1.set_flags(0, true);
+    unsafe {
+      let ptr: *mut u32 = transmute(&test);
+      *ptr |= 0b11;
+    }
+    assert_eq!(format!("{:?}", test.reg1.get()),
+               "DEBUG_TEST.reg1 { mode: 0x3, flags: [true, false, false, false] }");
+  }
+
   ioregs!(GROUP_TEST @ 0 = {
     0x0 => group regs[5] {
       0x0 => reg32 reg1 {
@@ -208,6 +259,13 @@ mod test {
     assert_eq!(get_value(&test, 0), 0x1 | 0x3<<8);
   }
 
+  #[test]
+  fn set_field_array_element_twice() {
+    let test: FIELD_ARRAY_TEST = zeroed_safe();
+    test.reg1.set_field(1, 3).set_field(1, 1);
+    assert_eq!(get_value(&test, 0), 0x1<<2);
+  }
+
   /*
      describe!(
      before_each {
@@ -286,6 +344,101 @@ mod test {
      );
      */
 
+  ioregs!(RESET_TEST @ 0 = {
+    0x0 => reg32 reg1 = 0x0300_00a5 {
+      0..7   => field1,
+      8      => field2,
+      24..25 => field3: set_to_clear,
+    }
+    0x4 => reg32 reg2 {
+      0..31  => field1,
+    }
+  });
+
+  #[test]
+  fn resets_register() {
+    let test: RESET_TEST = zeroed_safe();
+    test.reg1.set_field1(0x12).set_field2(true);
+
+    test.reg1.reset();
+    assert_eq!(get_value(&test, 0), 0xa5);
+    assert_eq!(get_value(&test, 1), 0);
+  }
+
+  #[test]
+  fn updates_from_reset_value() {
+    let test: RESET_TEST = zeroed_safe();
+    test.reg1.set_field1(0x12);
+
+    RESET_TEST_reg1_Update::from_reset(&test.reg1).set_field2(true);
+    assert_eq!(get_value(&test, 0), 0xa5 | 1<<8);
+  }
+
+  ioregs!(FLAG_TEST @ 0 = {
+    0x0 => reg32 reg1 {
+      0      => field1,
+      1      => field2: zero_to_clear,
+      2      => field3: set_to_clear,
+    }
+    0x4 => reg32 reg2 {
+      0      => field1: read_to_clear,
+      16..23 => field2,
+      24     => field3,
+    }
+  });
+
+  #[test]
+  fn writes_ones_to_zero_to_clear_fields() {
+    let test: FLAG_TEST = zeroed_safe();
+
+    test.reg1.set_field1(true);
+    assert_eq!(get_value(&test, 0), 0b011);
+  }
+
+  #[test]
+  fn clears_zero_to_clear_fields() {
+    let test: FLAG_TEST = zeroed_safe();
+
+    test.reg1.set_field1(true);
+    test.reg1.clear_field2();
+    assert_eq!(get_value(&test, 0), 0b001);
+  }
+
+  #[test]
+  fn no_read_read_to_clear_registers() {
+    let test: FLAG_TEST = zeroed_safe();
+
+    test.reg2.set_field2(0x12);
+    test.reg2.set_field3(true);
+    assert_eq!(get_value(&test, 1), 1<<24);
+  }
+
+  ioregs!(BITBAND_TEST @ 0x4000_c000 : bitband = {
+    0x0 => reg32 reg1 {
+      0      => field1,
+      1      => field2: zero_to_clear,
+      8..15  => field3[8],
+    }
+    0x4 => reg32 reg2 {
+      0      => field1,
+    }
+  });
+
+  #[test]
+  fn computes_bitband_alias_addresses() {
+    let alias = BITBAND_TEST().reg1.bitband_alias(0) as *const VolatileCell<u32>;
+    assert_eq!(alias as usize, 0x4218_0000);
+
+    let alias = BITBAND_TEST().reg1.bitband_alias(9) as *const VolatileCell<u32>;
+    assert_eq!(alias as usize, 0x4218_0000 + 9*4);
+  }
+
+  #[test]
+  fn computes_bitband_alias_addresses_of_later_registers() {
+    let alias = BITBAND_TEST().reg2.bitband_alias(0) as *const VolatileCell<u32>;
+    assert_eq!(alias as usize, 0x4218_0000 + 4*32);
+  }
+
   ioregs!(MULTI_TEST @ 0 = {
     0x100 => reg32 reg1[8] {
       0..31 => field[32],
@@ -23,18 +23,20 @@ extern crate rustc_plugin;
 
 use std::clone::Clone;
 use std::ops::Deref;
+use std::path::PathBuf;
 
 use rustc_plugin::Registry;
 use syntax::ast;
 use syntax::tokenstream;
 use syntax::codemap::DUMMY_SP;
 use syntax::codemap::Span;
-use syntax::ext::base::{ExtCtxt, MacResult, MultiModifier, Annotatable};
+use syntax::ext::base::{ExtCtxt, MacResult, DummyResult, MultiModifier, Annotatable};
 use syntax::ext::build::AstBuilder;
 use syntax::print::pprust;
 use syntax::util::small_vector::SmallVector;
 use syntax::ptr::P;
 
+use platformtree::parser;
 use platformtree::parser::Parser;
 use platformtree::builder::Builder;
 use platformtree::builder::meta_args::ToTyHash;
@@ -47,24 +49,62 @@ pub fn plugin_registrar(reg: &mut Registry) {
       MultiModifier(Box::new(macro_zinc_task)));
 }
 
-pub fn macro_platformtree(cx: &mut ExtCtxt, _: Span, tts: &[tokenstream::TokenTree])
+/// Builds the platform tree from macro tokens or, for `file = "board.pt"`
+/// form, from a file relative to the invoking source file. Board includes are
+/// resolved relative to the invoking source file as well.
+///
+/// Returns None if the tree couldn't be loaded, after reporting an error.
+fn build_platformtree(cx: &mut ExtCtxt, sp: Span,
+    tts: &[tokenstream::TokenTree]) -> Option<Builder> {
+  let mut base_dir = PathBuf::from(cx.codemap().span_to_filename(sp));
+  base_dir.pop();
+  let pt = match parser::file_reference(tts) {
+    Some(file) => {
+      let pt = parser::parse_file(cx, base_dir.join(&file).as_path());
+      if pt.is_none() {
+        cx.span_err(sp, format!("failed to load platform tree from `{}`",
+            file).as_str());
+      }
+      pt
+    },
+    // Parse errors are reported at their own spans.
+    None => Parser::new(cx, tts).parse_platformtree().and_then(|pt| {
+      parser::resolve_board(cx, pt, base_dir.as_path())
+    }),
+  };
+  match pt {
+    Some(pt) => Builder::build(cx, pt),
+    None => None,
+  }
+}
+
+pub fn macro_platformtree(cx: &mut ExtCtxt, sp: Span, tts: &[tokenstream::TokenTree])
     -> Box<MacResult+'static> {
-  let pt = Parser::new(cx, tts).parse_platformtree();
-  let items = Builder::build(cx, pt.unwrap())
-    .expect(format!("Unexpected failure on {}", line!()).as_str())
-    .emit_items(cx);
-  MacItems::new(items)
+  match build_platformtree(cx, sp, tts) {
+    Some(builder) => MacItems::new(builder.emit_items(cx)),
+    None => DummyResult::any(sp),
+  }
 }
 
 pub fn macro_platformtree_verbose(cx: &mut ExtCtxt, sp: Span,
     tts: &[tokenstream::TokenTree]) -> Box<MacResult+'static> {
-  let result = macro_platformtree(cx, sp, tts);
+  let builder = match build_platformtree(cx, sp, tts) {
+    Some(builder) => builder,
+    None => return DummyResult::any(sp),
+  };
+  let items = builder.emit_items(cx);
+
+  println!("Platform Tree report:");
+  for line in builder.reports().iter() {
+    println!("  {}", line);
+  }
+
   println!("Platform Tree dump:");
-  for i in result.make_items().unwrap().as_slice().iter() {
+  for i in items.iter() {
     println!("{}", pprust::item_to_string(i.deref()));
   }
 
-  macro_platformtree(cx, sp, tts)
+  MacItems::new(items)
 }
 
 fn macro_zinc_task(cx: &mut ExtCtxt, _: Span, _: &ast::MetaItem,
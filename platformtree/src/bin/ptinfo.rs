@@ -0,0 +1,81 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validates a platform tree description file and prints the node graph,
+//! materialization order and generated code.
+//!
+//! Usage: `ptinfo board.pt`
+
+#![feature(rustc_private)]
+
+extern crate platformtree;
+extern crate syntax;
+
+use std::env;
+use std::ops::Deref;
+use std::path::Path;
+use std::process;
+use syntax::print::pprust;
+
+use platformtree::builder::Builder;
+use platformtree::host;
+use platformtree::parser;
+
+fn main() {
+  let args: Vec<String> = env::args().collect();
+  if args.len() != 2 {
+    println!("usage: {} <file.pt>", args[0]);
+    process::exit(2);
+  }
+
+  let ok = host::with_ext_ctxt(|cx| {
+    let pt = match parser::parse_file(cx, Path::new(&args[1])) {
+      Some(pt) => pt,
+      None => return false,
+    };
+    let builder = match Builder::build(cx, pt.clone()) {
+      Some(builder) => builder,
+      None => return false,
+    };
+    if cx.parse_sess().span_diagnostic.has_errors() {
+      return false;
+    }
+
+    println!("Node graph:");
+    for line in host::describe_graph(&pt).iter() {
+      println!("  {}", line);
+    }
+
+    println!("Materialization order:");
+    for path in builder.materialization_order().iter() {
+      println!("  {}", path);
+    }
+
+    println!("Platform Tree report:");
+    for line in builder.reports().iter() {
+      println!("  {}", line);
+    }
+
+    println!("Generated code:");
+    for item in builder.emit_items(cx).iter() {
+      println!("{}", pprust::item_to_string(item.deref()));
+    }
+    true
+  });
+
+  if !ok {
+    process::exit(1);
+  }
+}
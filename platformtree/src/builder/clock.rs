@@ -0,0 +1,219 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Clock tree solver.
+//!
+//! Most of the supported MCUs derive the core clock from a PLL in a similar
+//! way: the source clock is divided by an input divider, multiplied in the VCO
+//! and divided again to get the output frequency. MCU-specific `clock` nodes
+//! describe their PLL with `PllLimits` and use `solve_pll` to pick the
+//! factors for a requested `target_frequency`.
+
+/// A set of allowed values for one of the PLL factors.
+#[derive(Clone, Copy)]
+pub enum Factor {
+  /// Any value in the inclusive range.
+  Range(usize, usize),
+  /// Any value in the inclusive range with a given step, starting from the
+  /// lower bound.
+  Step(usize, usize, usize),
+  /// One of the listed values.
+  Set(&'static [usize]),
+}
+
+impl Factor {
+  fn values(&self) -> Vec<usize> {
+    match *self {
+      Factor::Range(from, to) => (from..to + 1).collect(),
+      Factor::Step(from, to, step) =>
+          (from..to + 1).filter(|v| (v - from) % step == 0).collect(),
+      Factor::Set(values) => values.to_vec(),
+    }
+  }
+}
+
+/// PLL description and electrical limits.
+///
+/// The PLL output frequency is calculated as
+///
+/// ```text
+/// F_in = F_source / input_div
+/// F_vco = F_in * mult * vco_scale
+/// F_out = F_vco / output_div
+/// ```
+#[derive(Clone, Copy)]
+pub struct PllLimits {
+  /// Allowed input divider values.
+  pub input_div: Factor,
+  /// Allowed multiplier values.
+  pub mult: Factor,
+  /// Allowed output divider values.
+  pub output_div: Factor,
+  /// Fixed multiplier of the VCO (e.g. 2 on LPC17xx).
+  pub vco_scale: usize,
+  /// Inclusive range of the PLL input frequency (after the input divider).
+  pub input_range: (usize, usize),
+  /// Inclusive range of the VCO frequency.
+  pub vco_range: (usize, usize),
+  /// Maximum output frequency.
+  pub max_output: usize,
+}
+
+/// PLL factors found by the solver.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PllSolution {
+  pub input_div: usize,
+  pub mult: usize,
+  pub output_div: usize,
+  /// Resulting VCO frequency.
+  pub vco: usize,
+  /// Resulting output frequency.
+  pub output: usize,
+}
+
+/// Finds PLL factors that produce the frequency closest to `target`.
+///
+/// Only frequencies that divide evenly are considered, so that the achieved
+/// frequency is exact. Returns the first exact match in order of increasing
+/// input divider, multiplier and output divider, or the closest frequency
+/// not exceeding `target` if there's no exact match. Returns None if `target`
+/// is above the PLL limits or no valid configuration exists.
+pub fn solve_pll(source: usize, target: usize, limits: &PllLimits)
+    -> Option<PllSolution> {
+  if target > limits.max_output {
+    return None;
+  }
+
+  let mut best: Option<PllSolution> = None;
+  let mults = limits.mult.values();
+  let output_divs = limits.output_div.values();
+
+  for input_div in limits.input_div.values().iter() {
+    if source % input_div != 0 {
+      continue;
+    }
+    let input = source / input_div;
+    if input < limits.input_range.0 || input > limits.input_range.1 {
+      continue;
+    }
+
+    for mult in mults.iter() {
+      let vco = input * mult * limits.vco_scale;
+      if vco < limits.vco_range.0 || vco > limits.vco_range.1 {
+        continue;
+      }
+
+      for output_div in output_divs.iter() {
+        if vco % output_div != 0 {
+          continue;
+        }
+        let output = vco / output_div;
+        if output > limits.max_output || output > target {
+          continue;
+        }
+
+        let solution = PllSolution {
+          input_div: *input_div,
+          mult: *mult,
+          output_div: *output_div,
+          vco: vco,
+          output: output,
+        };
+        if output == target {
+          return Some(solution);
+        }
+        let better = match best {
+          Some(ref b) => output > b.output,
+          None => true,
+        };
+        if better {
+          best = Some(solution);
+        }
+      }
+    }
+  }
+
+  best
+}
+
+/// Formats a frequency in Hz as a human-readable string.
+pub fn format_frequency(freq: usize) -> String {
+  if freq % 1_000_000 == 0 {
+    format!("{}MHz", freq / 1_000_000)
+  } else if freq % 1_000 == 0 {
+    format!("{}kHz", freq / 1_000)
+  } else {
+    format!("{}Hz", freq)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use hamcrest::{assert_that, is, equal_to};
+  use super::{Factor, PllLimits, PllSolution, solve_pll, format_frequency};
+
+  static LIMITS: PllLimits = PllLimits {
+    input_div: Factor::Range(1, 4),
+    mult: Factor::Range(2, 16),
+    output_div: Factor::Step(2, 8, 2),
+    vco_scale: 1,
+    input_range: (1_000_000, 8_000_000),
+    vco_range: (32_000_000, 128_000_000),
+    max_output: 48_000_000,
+  };
+
+  #[test]
+  fn finds_exact_solution() {
+    let solution = solve_pll(8_000_000, 48_000_000, &LIMITS).unwrap();
+    assert_that(solution, is(equal_to(PllSolution {
+      input_div: 1,
+      mult: 12,
+      output_div: 2,
+      vco: 96_000_000,
+      output: 48_000_000,
+    })));
+  }
+
+  #[test]
+  fn finds_closest_lower_solution() {
+    let solution = solve_pll(8_000_000, 47_000_000, &LIMITS).unwrap();
+    assert_that(solution.output, is(equal_to(44_000_000)));
+  }
+
+  #[test]
+  fn fails_when_target_is_above_limit() {
+    assert!(solve_pll(8_000_000, 100_000_000, &LIMITS).is_none());
+  }
+
+  #[test]
+  fn fails_when_input_is_out_of_range() {
+    assert!(solve_pll(40_000_000, 48_000_000, &LIMITS).is_none());
+  }
+
+  #[test]
+  fn expands_factors() {
+    assert_that(Factor::Step(2, 8, 2).values(), is(equal_to(vec!(2, 4, 6, 8))));
+    assert_that(Factor::Set(&[3, 5]).values(), is(equal_to(vec!(3, 5))));
+    assert_that(Factor::Range(1, 3).values(), is(equal_to(vec!(1, 2, 3))));
+  }
+
+  #[test]
+  fn formats_frequency() {
+    assert_that(format_frequency(100_000_000), is(equal_to("100MHz".to_string())));
+    assert_that(format_frequency(32_768), is(equal_to("32768Hz".to_string())));
+    assert_that(format_frequency(12_500), is(equal_to("12500Hz".to_string())));
+    assert_that(format_frequency(500_000), is(equal_to("500kHz".to_string())));
+  }
+}
@@ -17,6 +17,10 @@ use std::rc::Rc;
 use syntax::ext::base::ExtCtxt;
 
 use lpc17xx_pt;
+use stm32f1_pt;
+use stm32f4_pt;
+use stm32f7_pt;
+use stm32l1_pt;
 use tiva_c_pt;
 use node;
 
@@ -27,6 +31,10 @@ pub fn attach(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
     Some(ref name) => {
       match name.as_str() {
         "lpc17xx" => lpc17xx_pt::attach(builder, cx, node.clone()),
+        "stm32f1" => stm32f1_pt::attach(builder, cx, node.clone()),
+        "stm32f4" => stm32f4_pt::attach(builder, cx, node.clone()),
+        "stm32f7" => stm32f7_pt::attach(builder, cx, node.clone()),
+        "stm32l1" => stm32l1_pt::attach(builder, cx, node.clone()),
         "tiva_c"  => tiva_c_pt::attach(builder, cx, node.clone()),
         _ => node.materializer.set(Some(fail_build_mcu as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>))),
       }
@@ -26,15 +26,21 @@ use syntax::parse::token::InternedString;
 use syntax::ptr::P;
 
 use node;
+use self::resources::{Resource, ResourceMap};
 
 mod mcu;
 mod os;
+pub mod clock;
 pub mod meta_args;
+pub mod resources;
 
 pub struct Builder {
   main_stmts: Vec<ast::Stmt>,
   type_items: Vec<P<ast::Item>>,
   pt: Rc<node::PlatformTree>,
+  resources: ResourceMap,
+  reports: Vec<String>,
+  materialized: Vec<String>,
 }
 
 impl Builder {
@@ -73,6 +79,8 @@ impl Builder {
       }
     }
 
+    builder.resources.warn_unreferenced_peripherals(cx, &pt);
+
     Some(builder)
   }
 
@@ -90,6 +98,7 @@ impl Builder {
   fn walk_materialize(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
     let maybe_mat = node.materializer.get();
     if maybe_mat.is_some() {
+      builder.materialized.push(node.full_path());
       maybe_mat.unwrap()(builder, cx, node.clone());
     }
     let rev_depends = node.rev_depends_on.borrow();
@@ -127,6 +136,9 @@ impl Builder {
       main_stmts: vec!(),
       type_items: vec!(use_zinc),
       pt: pt,
+      resources: ResourceMap::new(),
+      reports: vec!(),
+      materialized: vec!(),
     }
   }
 
@@ -138,6 +150,30 @@ impl Builder {
     self.pt.clone()
   }
 
+  /// Claims a hardware resource for the node, see `ResourceMap::claim`.
+  pub fn claim_resource(&mut self, cx: &ExtCtxt, resource: Resource,
+      owner: &node::Node, span: Span) -> bool {
+    self.resources.claim(cx, resource, owner, span)
+  }
+
+  pub fn resources(&self) -> &ResourceMap {
+    &self.resources
+  }
+
+  /// Adds a line to the build report, printed by `platformtree_verbose!`.
+  pub fn add_report(&mut self, line: String) {
+    self.reports.push(line);
+  }
+
+  pub fn reports(&self) -> Vec<String> {
+    self.reports.clone()
+  }
+
+  /// Returns full paths of materialized nodes in the order they were built.
+  pub fn materialization_order(&self) -> Vec<String> {
+    self.materialized.clone()
+  }
+
   pub fn add_main_statement(&mut self, stmt: ast::Stmt) {
     self.main_stmts.push(stmt);
   }
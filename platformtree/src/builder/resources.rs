@@ -0,0 +1,256 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tracks hardware resources claimed by PlatformTree nodes.
+//!
+//! Most of the MCU resources can only be owned by one node: a pin can't be
+//! a GPIO and an UART TX at the same time, and a timer can't be configured
+//! twice. Node builders claim resources through the builder, which reports an
+//! error on double assignment. gpio nodes claim their pins when they set their
+//! own `direction`, before peripherals and drivers claim the pins they use.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+use syntax::codemap::Span;
+use syntax::ext::base::ExtCtxt;
+
+use node;
+
+/// A hardware resource that can be exclusively owned by a node.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum Resource {
+  /// A physical pin, identified by full path of its gpio node.
+  Pin(String),
+  /// A peripheral, e.g. `timer1` or `uart0`.
+  Peripheral(String),
+  /// A DMA channel, e.g. `dma0.3`.
+  DmaChannel(String),
+  /// An interrupt vector, e.g. `eint3`.
+  Irq(String),
+}
+
+impl Resource {
+  /// Returns a pin resource for given gpio node.
+  pub fn pin_for_node(node: &node::Node) -> Resource {
+    Resource::Pin(node.full_path())
+  }
+}
+
+impl fmt::Display for Resource {
+  fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      Resource::Pin(ref s)        => write!(fmt, "pin `{}`", s),
+      Resource::Peripheral(ref s) => write!(fmt, "peripheral `{}`", s),
+      Resource::DmaChannel(ref s) => write!(fmt, "DMA channel `{}`", s),
+      Resource::Irq(ref s)        => write!(fmt, "interrupt `{}`", s),
+    }
+  }
+}
+
+struct Claim {
+  owner: String,
+  owner_name: Option<String>,
+  span: Span,
+}
+
+/// A map of all resources claimed while building a PlatformTree.
+pub struct ResourceMap {
+  claims: HashMap<Resource, Claim>,
+  order: Vec<Resource>,
+}
+
+impl ResourceMap {
+  pub fn new() -> ResourceMap {
+    ResourceMap {
+      claims: HashMap::new(),
+      order: vec!(),
+    }
+  }
+
+  /// Claims a resource for the node.
+  ///
+  /// Returns true if the resource was free or already owned by the same node.
+  /// Reports an error at `span` and returns false otherwise.
+  pub fn claim(&mut self, cx: &ExtCtxt, resource: Resource,
+      owner: &node::Node, span: Span) -> bool {
+    let owner_path = owner.full_path();
+    match self.claims.get(&resource) {
+      Some(claim) => {
+        if claim.owner == owner_path {
+          return true;
+        }
+        cx.parse_sess().span_diagnostic.span_err(span,
+            format!("{} is already assigned to `{}`",
+                resource, claim.owner).as_str());
+        cx.parse_sess().span_diagnostic.span_warn(claim.span,
+            "previously assigned here");
+        return false;
+      },
+      None => (),
+    }
+
+    self.order.push(resource.clone());
+    self.claims.insert(resource, Claim {
+      owner: owner_path,
+      owner_name: owner.name.clone(),
+      span: span,
+    });
+    true
+  }
+
+  /// Returns the full path of the node owning the resource, if any.
+  pub fn owner_of(&self, resource: &Resource) -> Option<String> {
+    self.claims.get(resource).map(|c| c.owner.clone())
+  }
+
+  /// Reports a warning for each claimed peripheral that is never referenced
+  /// by other nodes.
+  ///
+  /// Materializing a peripheral node enables its clock, so a peripheral that
+  /// nothing refers to is most likely a leftover that wastes power.
+  pub fn warn_unreferenced_peripherals(&self, cx: &ExtCtxt,
+      pt: &node::PlatformTree) {
+    let mut referenced = vec!();
+    for root in pt.nodes().iter() {
+      collect_references(root, &mut referenced);
+    }
+
+    for resource in self.order.iter() {
+      match *resource {
+        Resource::Peripheral(_) => (),
+        _ => continue,
+      }
+      let claim = &self.claims[resource];
+      let is_referenced = match claim.owner_name {
+        Some(ref name) => referenced.contains(name),
+        None => false,
+      };
+      if !is_referenced {
+        cx.parse_sess().span_diagnostic.span_warn(claim.span,
+            format!("clock for {} is enabled but `{}` is never referenced",
+                resource, claim.owner).as_str());
+      }
+    }
+  }
+}
+
+fn collect_references(node: &Rc<node::Node>, referenced: &mut Vec<String>) {
+  for (_, attr) in node.attributes.borrow().iter() {
+    match attr.value {
+      node::RefValue(ref name) => referenced.push(name.clone()),
+      _ => (),
+    }
+  }
+  for sub in node.subnodes().iter() {
+    collect_references(sub, referenced);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::{Resource, ResourceMap};
+  use test_helpers::with_parsed;
+
+  #[test]
+  fn claims_free_resource() {
+    with_parsed("
+      timer {
+        tim@1;
+      }", |cx, failed, pt| {
+      let mut map = ResourceMap::new();
+      let node = pt.get_by_name("tim").unwrap();
+      assert!(map.claim(cx, Resource::Peripheral("timer1".to_string()),
+          &node, node.path_span));
+      assert!(map.owner_of(&Resource::Peripheral("timer1".to_string())) ==
+          Some("timer::1".to_string()));
+      assert!(unsafe{*failed} == false);
+    });
+  }
+
+  #[test]
+  fn allows_reclaim_by_same_owner() {
+    with_parsed("
+      p@1;", |cx, failed, pt| {
+      let mut map = ResourceMap::new();
+      let node = pt.get_by_name("p").unwrap();
+      let pin = Resource::pin_for_node(&node);
+      assert!(map.claim(cx, pin.clone(), &node, node.path_span));
+      assert!(map.claim(cx, pin, &node, node.path_span));
+      assert!(unsafe{*failed} == false);
+    });
+  }
+
+  #[test]
+  fn fails_on_double_assignment() {
+    with_parsed("
+      a@1;
+      b@2;", |cx, failed, pt| {
+      let mut map = ResourceMap::new();
+      let a = pt.get_by_name("a").unwrap();
+      let b = pt.get_by_name("b").unwrap();
+      let irq = Resource::Irq("isr_timer1".to_string());
+      assert!(map.claim(cx, irq.clone(), &a, a.path_span));
+      assert!(!map.claim(cx, irq, &b, b.path_span));
+      assert!(unsafe{*failed} == true);
+    });
+  }
+
+  #[test]
+  fn fails_on_double_dma_channel_assignment() {
+    with_parsed("
+      a@1;
+      b@2;", |cx, failed, pt| {
+      let mut map = ResourceMap::new();
+      let a = pt.get_by_name("a").unwrap();
+      let b = pt.get_by_name("b").unwrap();
+      let channel = Resource::DmaChannel("dma0.3".to_string());
+      assert!(map.claim(cx, channel.clone(), &a, a.path_span));
+      assert!(!map.claim(cx, channel.clone(), &b, b.path_span));
+      assert!(map.owner_of(&channel) == Some("1".to_string()));
+      assert!(unsafe{*failed} == true);
+    });
+  }
+
+  #[test]
+  fn warns_on_unreferenced_peripheral() {
+    with_parsed("
+      tim@1;", |cx, failed, pt| {
+      let mut map = ResourceMap::new();
+      let node = pt.get_by_name("tim").unwrap();
+      map.claim(cx, Resource::Peripheral("timer1".to_string()),
+          &node, node.path_span);
+      assert!(unsafe{*failed} == false);
+      map.warn_unreferenced_peripherals(cx, &pt);
+      assert!(unsafe{*failed} == true);
+    });
+  }
+
+  #[test]
+  fn does_not_warn_on_referenced_peripheral() {
+    with_parsed("
+      tim@1;
+      user@2 {
+        timer = &tim;
+      }", |cx, failed, pt| {
+      let mut map = ResourceMap::new();
+      let node = pt.get_by_name("tim").unwrap();
+      map.claim(cx, Resource::Peripheral("timer1".to_string()),
+          &node, node.path_span);
+      map.warn_unreferenced_peripherals(cx, &pt);
+      assert!(unsafe{*failed} == false);
+    });
+  }
+}
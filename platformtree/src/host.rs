@@ -0,0 +1,150 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for using platform trees outside of the compiler plugin.
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use syntax::ast;
+use syntax::codemap::{MacroBang, mk_sp, BytePos, ExpnInfo, NameAndSpan};
+use syntax::ext::base::{ExtCtxt, DummyResolver};
+use syntax::ext::expand::ExpansionConfig;
+use syntax::parse::ParseSess;
+
+use node;
+
+/// Yields an ExtCtxt that reports diagnostics to stderr.
+pub fn with_ext_ctxt<F, T>(block: F) -> T
+    where F: FnOnce(&mut ExtCtxt) -> T {
+  let parse_sess = ParseSess::new();
+  let ecfg = ExpansionConfig::default("platformtree".to_string());
+  let mut resolver = DummyResolver;
+  let mut cx = ExtCtxt::new(&parse_sess, Vec::new(), ecfg, &mut resolver);
+  cx.bt_push(ExpnInfo {
+    call_site: mk_sp(BytePos(0), BytePos(0)),
+    callee: NameAndSpan {
+      format: MacroBang(ast::Name(0u32)),
+      allow_internal_unstable: true,
+      span: None,
+    },
+  });
+  block(&mut cx)
+}
+
+/// Describes the node graph, one line per node, attribute and dependency list.
+///
+/// Should be called after the nodes are attached by `Builder`, as that's when
+/// the dependencies are known.
+pub fn describe_graph(pt: &node::PlatformTree) -> Vec<String> {
+  let mut roots = pt.nodes();
+  roots.sort_by(|a, b| a.path.cmp(&b.path));
+
+  let mut depends_on = HashMap::new();
+  for root in roots.iter() {
+    collect_dependencies(root, &mut depends_on);
+  }
+
+  let mut lines = vec!();
+  for root in roots.iter() {
+    describe_node(root, 0, &depends_on, &mut lines);
+  }
+  lines
+}
+
+// Materialization consumes `depends_on`, so the graph is reconstructed from
+// `rev_depends_on`.
+fn collect_dependencies(node: &Rc<node::Node>,
+    depends_on: &mut HashMap<String, Vec<String>>) {
+  for weak_dependant in node.rev_depends_on.borrow().iter() {
+    let dependant = weak_dependant.upgrade().unwrap();
+    depends_on.entry(dependant.full_path()).or_insert(vec!())
+        .push(node.full_path());
+  }
+  for sub in node.subnodes().iter() {
+    collect_dependencies(sub, depends_on);
+  }
+}
+
+fn describe_node(node: &Rc<node::Node>, depth: usize,
+    depends_on: &HashMap<String, Vec<String>>, lines: &mut Vec<String>) {
+  let indent: String = (0..depth).map(|_| "  ").collect();
+  let name = match node.name {
+    Some(ref name) => format!("{}@", name),
+    None => "".to_string(),
+  };
+  lines.push(format!("{}{}{}", indent, name, node.path));
+
+  let attributes = node.attributes.borrow();
+  let mut keys: Vec<&String> = attributes.keys().collect();
+  keys.sort();
+  for key in keys.iter() {
+    lines.push(format!("{}  {} = {}", indent, key, attributes[*key].value));
+  }
+
+  match depends_on.get(&node.full_path()) {
+    Some(deps) => {
+      let mut deps = deps.clone();
+      deps.sort();
+      lines.push(format!("{}  depends on: {}", indent, deps.join(", ")));
+    },
+    None => (),
+  }
+
+  for sub in node.subnodes().iter() {
+    describe_node(sub, depth + 1, depends_on, lines);
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use builder::Builder;
+  use test_helpers::with_parsed;
+
+  #[test]
+  fn describes_nodes_attributes_and_dependencies() {
+    with_parsed("
+      mcu@lpc17xx {
+        clock {
+          source = \"main-oscillator\";
+          source_frequency = 12_000_000;
+          target_frequency = 100_000_000;
+        }
+      }
+      os {
+        single_task {
+          loop = \"run\";
+        }
+      }", |cx, failed, pt| {
+      Builder::build(cx, pt.clone());
+      assert!(unsafe{*failed} == false);
+
+      let lines = super::describe_graph(&pt);
+      assert!(lines == vec!(
+          "lpc17xx@mcu".to_string(),
+          "  depends on: mcu::clock".to_string(),
+          "  clock".to_string(),
+          "    source = \"main-oscillator\"".to_string(),
+          "    source_frequency = 12000000".to_string(),
+          "    system_frequency = 100000000".to_string(),
+          "    target_frequency = 100000000".to_string(),
+          "os".to_string(),
+          "  depends on: os::single_task".to_string(),
+          "  single_task".to_string(),
+          "    loop = \"run\"".to_string(),
+          "    depends on: mcu".to_string(),
+      ));
+    });
+  }
+}
@@ -23,10 +23,16 @@ extern crate regex;
 #[cfg(test)] extern crate hamcrest;
 
 pub mod builder;
+pub mod host;
 pub mod node;
 pub mod parser;
 
+#[path="../../src/hal/gpio_pt.rs"] mod gpio_pt;
 #[path="../../src/hal/lpc17xx/platformtree.rs"] mod lpc17xx_pt;
+#[path="../../src/hal/stm32f1/platformtree.rs"] mod stm32f1_pt;
+#[path="../../src/hal/stm32f4/platformtree.rs"] mod stm32f4_pt;
+#[path="../../src/hal/stm32f7/platformtree.rs"] mod stm32f7_pt;
+#[path="../../src/hal/stm32l1/platformtree.rs"] mod stm32l1_pt;
 #[path="../../src/hal/tiva_c/platformtree.rs"] mod tiva_c_pt;
 #[path="../../src/drivers/drivers_pt.rs"] mod drivers_pt;
 
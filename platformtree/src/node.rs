@@ -20,6 +20,7 @@ use std::ops::DerefMut;
 use std::rc::{Rc, Weak};
 use syntax::codemap::{Span, DUMMY_SP};
 use syntax::ext::base::ExtCtxt;
+use syntax::parse::ParseSess;
 
 use builder::Builder;
 
@@ -29,7 +30,7 @@ pub use self::AttributeType::*;
 /// Holds a value for an attribute.
 ///
 /// The value can be an unsigned integer, string or reference.
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub enum AttributeValue {
   IntValue(usize),
   BoolValue(bool),
@@ -37,6 +38,17 @@ pub enum AttributeValue {
   RefValue(String),
 }
 
+impl fmt::Display for AttributeValue {
+  fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+    match *self {
+      IntValue(v) => write!(fmt, "{}", v),
+      BoolValue(v) => write!(fmt, "{}", v),
+      StrValue(ref v) => write!(fmt, "\"{}\"", v),
+      RefValue(ref v) => write!(fmt, "&{}", v),
+    }
+  }
+}
+
 /// Expected attribute type.
 ///
 /// Used in Node::expect_attributes to provide the expected type of the
@@ -482,6 +494,109 @@ impl PlatformTree {
     }
   }
 
+  /// Creates a platform tree from root nodes, indexing all the named nodes.
+  ///
+  /// Reports parser errors and returns None if node names are not unique.
+  pub fn from_nodes(sess: &ParseSess, nodes: HashMap<String, Rc<Node>>)
+      -> Option<PlatformTree> {
+    let mut named = HashMap::new();
+    if collect_node_names(sess, &mut named, &nodes) {
+      Some(PlatformTree::new(nodes, named))
+    } else {
+      None
+    }
+  }
+
+  /// Layers the application tree on top of a board tree.
+  ///
+  /// Root nodes of both trees are merged by path: the application may add new
+  /// nodes and override attributes of the board nodes, but can't rename them.
+  /// Named board nodes can also be overridden by name in the `board` root node
+  /// of the application:
+  ///
+  /// ```ignore
+  /// board {
+  ///   include = "board.pt";
+  ///   console_uart { baud_rate = 9600; }
+  /// }
+  /// ```
+  pub fn extend(cx: &ExtCtxt, board: &PlatformTree, app: &PlatformTree)
+      -> Option<Rc<PlatformTree>> {
+    let mut paths: Vec<String> = board.nodes.keys().chain(app.nodes.keys())
+        .filter(|path| path.as_str() != "board")
+        .cloned()
+        .collect();
+    paths.sort();
+    paths.dedup();
+
+    let mut app_attributes = HashMap::new();
+    let mut nodes = HashMap::new();
+    let mut ok = true;
+    for path in paths.iter() {
+      match merge_node(cx, board.nodes.get(path), app.nodes.get(path), None,
+          &mut app_attributes) {
+        Some(node) => { nodes.insert(path.clone(), node); },
+        None => ok = false,
+      }
+    }
+    if !ok {
+      return None;
+    }
+
+    let pt = match PlatformTree::from_nodes(cx.parse_sess(), nodes) {
+      Some(pt) => pt,
+      None => return None,
+    };
+    match app.nodes.get("board") {
+      Some(overrides) => {
+        if !pt.apply_named_overrides(cx, overrides, &app_attributes) {
+          return None;
+        }
+      },
+      None => (),
+    }
+    Some(Rc::new(pt))
+  }
+
+  fn apply_named_overrides(&self, cx: &ExtCtxt, overrides: &Rc<Node>,
+      app_attributes: &HashMap<String, HashMap<String, Rc<Attribute>>>)
+      -> bool {
+    let mut ok = true;
+    for sub in overrides.subnodes().iter() {
+      if !sub.expect_no_subnodes(cx) {
+        ok = false;
+        continue;
+      }
+      let target = match self.get_by_name(sub.path.as_str()) {
+        Some(target) => target,
+        None => {
+          cx.parse_sess().span_diagnostic.span_err(sub.path_span,
+              format!("board doesn't define node `{}`", sub.path).as_str());
+          ok = false;
+          continue;
+        },
+      };
+
+      let app = app_attributes.get(&target.full_path());
+      for (key, attr) in sub.attributes.borrow().iter() {
+        match app.and_then(|attrs| attrs.get(key)) {
+          Some(other) if other.value != attr.value => {
+            cx.parse_sess().span_diagnostic.span_err(attr.value_span,
+                format!("conflicting overrides of `{}` for `{}`",
+                    key, sub.path).as_str());
+            cx.parse_sess().span_diagnostic.span_warn(other.value_span,
+                "also overridden here");
+            ok = false;
+            continue;
+          },
+          _ => (),
+        }
+        target.attributes.borrow_mut().insert(key.clone(), attr.clone());
+      }
+    }
+    ok
+  }
+
   pub fn nodes(&self) -> Vec<Rc<Node>> {
     let mut v = vec!();
     for (_, sub) in self.nodes.iter() {
@@ -514,3 +629,108 @@ impl PlatformTree {
     ok
   }
 }
+
+fn collect_node_names(sess: &ParseSess, map: &mut HashMap<String, Weak<Node>>,
+    nodes: &HashMap<String, Rc<Node>>) -> bool {
+  for (_, n) in nodes.iter() {
+    let mut strongmap = HashMap::new();
+    n.with_subnodes_map(|sub|{
+      for (k, weak_node) in sub.iter() {
+        let node = weak_node.upgrade().unwrap();
+        strongmap.insert(k.clone(), node);
+      }
+    });
+
+    if !collect_node_names(sess, map, &strongmap) {
+      return false;
+    }
+
+    match n.name {
+      Some(ref name) => {
+        if map.contains_key(name) {
+          sess.span_diagnostic.span_err(n.name_span, format!(
+              "duplicate `{}` definition", name).as_str());
+
+          sess.span_diagnostic.span_warn(
+              (*map)[name].upgrade().unwrap().name_span,
+              "previously defined here");
+          return false;
+        } else {
+          map.insert(name.clone(), Rc::downgrade(n));
+        }
+      },
+      None => (),
+    }
+  }
+  true
+}
+
+/// Deep-copies the `base` node with attributes and subnodes of `over` layered
+/// on top of it. Attributes set by `over` are recorded in `app_attributes` by
+/// node full path.
+fn merge_node(cx: &ExtCtxt, base: Option<&Rc<Node>>, over: Option<&Rc<Node>>,
+    parent: Option<Weak<Node>>,
+    app_attributes: &mut HashMap<String, HashMap<String, Rc<Attribute>>>)
+    -> Option<Rc<Node>> {
+  let primary = over.or(base).unwrap();
+  let (name, name_span) = match (base, over) {
+    (Some(b), Some(o)) => match (&b.name, &o.name) {
+      (&Some(ref board_name), &Some(ref app_name)) if board_name != app_name => {
+        cx.parse_sess().span_diagnostic.span_err(o.name_span,
+            format!("node `{}` is named `{}` by the board, can't rename it to \
+                    `{}`", o.full_path(), board_name, app_name).as_str());
+        cx.parse_sess().span_diagnostic.span_warn(b.name_span,
+            "previously defined here");
+        return None;
+      },
+      (&Some(_), _) => (b.name.clone(), b.name_span),
+      _ => (o.name.clone(), o.name_span),
+    },
+    _ => (primary.name.clone(), primary.name_span),
+  };
+
+  let node = Rc::new(Node::new(name, name_span, primary.path.clone(),
+      primary.path_span, parent));
+  {
+    let mut attributes = node.attributes.borrow_mut();
+    for n in base.iter().chain(over.iter()) {
+      for (k, v) in n.attributes.borrow().iter() {
+        attributes.insert(k.clone(), v.clone());
+      }
+    }
+  }
+  match over {
+    Some(o) => {
+      app_attributes.insert(node.full_path(), o.attributes.borrow().clone());
+    },
+    None => (),
+  }
+
+  let mut paths: Vec<String> = vec!();
+  for n in base.iter().chain(over.iter()) {
+    for sub in n.subnodes().iter() {
+      if !paths.contains(&sub.path) {
+        paths.push(sub.path.clone());
+      }
+    }
+  }
+
+  let mut subnodes = Subnodes::new();
+  let mut ok = true;
+  for path in paths.iter() {
+    let board_sub = base.and_then(|n| n.get_by_path(path.as_str()));
+    let app_sub = over.and_then(|n| n.get_by_path(path.as_str()));
+    match merge_node(cx, board_sub.as_ref(), app_sub.as_ref(),
+        Some(Rc::downgrade(&node)), app_attributes) {
+      Some(sub) => subnodes.push(sub),
+      None => ok = false,
+    }
+  }
+  node.set_subnodes(subnodes);
+
+  if ok {
+    Some(node)
+  } else {
+    None
+  }
+}
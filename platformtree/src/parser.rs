@@ -15,17 +15,98 @@
 
 use std::ops::Deref;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::rc::{Rc, Weak};
 use syntax::ast::{LitKind, LitIntType};
 use syntax::tokenstream::TokenTree;
 use syntax::codemap::{Span, mk_sp};
 use syntax::ext::base::ExtCtxt;
-use syntax::parse::{token, ParseSess, lexer, integer_lit};
+use syntax::parse::{token, ParseSess, lexer, integer_lit, filemap_to_tts};
 use syntax::parse::lexer::Reader;
 use syntax::print::pprust;
 
 use node;
 
+/// Parses a standalone platform tree description file.
+///
+/// The file has the same syntax as the body of `platformtree!` macro. Board
+/// includes are resolved relative to the file's directory.
+pub fn parse_file(cx: &ExtCtxt, path: &Path) -> Option<Rc<node::PlatformTree>> {
+  parse_included_file(cx, path, &mut vec!())
+}
+
+/// Resolves the `board` root node, layering the tree on top of the board file
+/// from its `include` attribute, see `PlatformTree::extend`.
+///
+/// The include path is relative to `base_dir`. Board files may include other
+/// board files.
+pub fn resolve_board(cx: &ExtCtxt, pt: Rc<node::PlatformTree>,
+    base_dir: &Path) -> Option<Rc<node::PlatformTree>> {
+  resolve_included_board(cx, pt, base_dir, &mut vec!())
+}
+
+fn parse_included_file(cx: &ExtCtxt, path: &Path,
+    included: &mut Vec<PathBuf>) -> Option<Rc<node::PlatformTree>> {
+  let filemap = match cx.codemap().load_file(path) {
+    Ok(filemap) => filemap,
+    Err(e) => {
+      cx.parse_sess().span_diagnostic.err(
+          format!("couldn't read `{}`: {}", path.display(), e).as_str());
+      return None;
+    },
+  };
+  let tts = filemap_to_tts(cx.parse_sess(), filemap);
+  let pt = match Parser::new(cx, tts.as_slice()).parse_platformtree() {
+    Some(pt) => pt,
+    None => return None,
+  };
+
+  included.push(path.to_path_buf());
+  let base_dir = path.parent().unwrap_or(Path::new(""));
+  let resolved = resolve_included_board(cx, pt, base_dir, included);
+  included.pop();
+  resolved
+}
+
+fn resolve_included_board(cx: &ExtCtxt, pt: Rc<node::PlatformTree>,
+    base_dir: &Path, included: &mut Vec<PathBuf>)
+    -> Option<Rc<node::PlatformTree>> {
+  let board = match pt.get_by_path("board") {
+    Some(board) => board,
+    None => return Some(pt),
+  };
+  if !board.expect_attributes(cx, &[("include", node::StrAttribute)]) {
+    return None;
+  }
+
+  let path = base_dir.join(board.get_string_attr("include").unwrap());
+  if included.contains(&path) {
+    cx.parse_sess().span_diagnostic.span_err(
+        board.get_attr("include").value_span,
+        format!("board file `{}` includes itself", path.display()).as_str());
+    return None;
+  }
+  match parse_included_file(cx, path.as_path(), included) {
+    Some(base) => node::PlatformTree::extend(cx, &base, &pt),
+    None => None,
+  }
+}
+
+/// Returns the file name if tokens are in `file = "board.pt"` form.
+pub fn file_reference(tts: &[TokenTree]) -> Option<String> {
+  if tts.len() != 3 {
+    return None;
+  }
+  match (&tts[0], &tts[1], &tts[2]) {
+    (&TokenTree::Token(_, token::Ident(ref key)),
+     &TokenTree::Token(_, token::Eq),
+     &TokenTree::Token(_, token::Literal(token::Lit::Str_(ref name), None)))
+        if key.name.as_str().deref() == "file" =>
+      Some(name.as_str().to_string()),
+    _ => None,
+  }
+}
+
 pub struct Parser<'a> {
   pub sess: &'a ParseSess,
   reader: lexer::TtReader<'a>,
@@ -92,48 +173,8 @@ impl<'a> Parser<'a> {
     if failed {
       None
     } else {
-      let mut map = HashMap::new();
-      if self.collect_node_names(&mut map, &nodes) {
-        Some(Rc::new(node::PlatformTree::new(nodes, map)))
-      } else {
-        None
-      }
-    }
-  }
-
-  fn collect_node_names(&self, map: &mut HashMap<String, Weak<node::Node>>,
-      nodes: &HashMap<String, Rc<node::Node>>) -> bool {
-    for (_, n) in nodes.iter() {
-      let mut strongmap = HashMap::new();
-      n.with_subnodes_map(|sub|{
-        for (k, weak_node) in sub.iter() {
-          let node = weak_node.upgrade().unwrap();
-          strongmap.insert(k.clone(), node);
-        }
-      });
-
-      if !self.collect_node_names(map, &strongmap) {
-        return false;
-      }
-
-      match n.name {
-        Some(ref name) => {
-          if map.contains_key(name) {
-            self.sess.span_diagnostic.span_err(n.name_span, format!(
-                "duplicate `{}` definition", name).as_str());
-
-            self.sess.span_diagnostic.span_warn(
-                (*map)[name].upgrade().unwrap().name_span,
-                "previously defined here");
-            return false;
-          } else {
-            map.insert(name.clone(), Rc::downgrade(n));
-          }
-        },
-        None => (),
-      }
+      node::PlatformTree::from_nodes(self.sess, nodes).map(|pt| Rc::new(pt))
     }
-    true
   }
 
   fn parse_node(&mut self, parent: Option<Weak<node::Node>>)
@@ -13,7 +13,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use test_helpers::{fails_to_parse, with_parsed, with_parsed_node};
+use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use syntax::ext::quote::rt::ExtParseUtils;
+
+use parser::{file_reference, parse_file, resolve_board};
+use test_helpers::{fails_to_parse, with_parsed, with_parsed_node,
+    with_parsed_tts};
 
 #[test]
 fn parse_anonymous_node() {
@@ -153,3 +161,126 @@ fn tracks_nodes_by_name() {
 fn fails_to_parse_duplicate_node_names() {
   fails_to_parse("duplicate@root { duplicate@child; }");
 }
+
+#[test]
+fn parses_file_reference() {
+  with_parsed_tts("node {}", |cx, _, _| {
+    let tts = cx.parse_tts("file = \"board.pt\"".to_string());
+    assert!(file_reference(tts.as_slice()) == Some("board.pt".to_string()));
+
+    let tts = cx.parse_tts("node { file = \"board.pt\"; }".to_string());
+    assert!(file_reference(tts.as_slice()) == None);
+  });
+}
+
+#[test]
+fn parse_standalone_file() {
+  let path = env::temp_dir().join("platformtree_parse_standalone_file.pt");
+  File::create(&path).unwrap()
+      .write_all(b"mcu@lpc17xx { clock { source = \"rtc-oscillator\"; } }")
+      .unwrap();
+
+  with_parsed_tts("node {}", |cx, failed, _| {
+    let pt = parse_file(cx, path.as_path()).unwrap();
+    assert!(unsafe{*failed} == false);
+    assert!(pt.get_by_name("lpc17xx").is_some());
+    assert!(pt.get_by_path("mcu").unwrap().get_by_path("clock").is_some());
+  });
+}
+
+#[test]
+fn fails_to_parse_missing_file() {
+  with_parsed_tts("node {}", |cx, failed, _| {
+    let pt = parse_file(cx, Path::new("/nonexistent/board.pt"));
+    assert!(pt.is_none());
+    assert!(unsafe{*failed} == true);
+  });
+}
+
+fn write_board_file(name: &str, src: &str) -> PathBuf {
+  let path = env::temp_dir().join(name);
+  File::create(&path).unwrap().write_all(src.as_bytes()).unwrap();
+  path
+}
+
+static BOARD: &'static str = "
+  mcu@lpc17xx {
+    clock { source = \"main-oscillator\"; }
+    gpio { 1 { led1@18 { direction = \"out\"; } } }
+  }";
+
+#[test]
+fn extends_board_definition() {
+  let board = write_board_file("platformtree_extends_board.pt", BOARD);
+  let src = format!("
+    board {{
+      include = \"{}\";
+      led1 {{ direction = \"in\"; }}
+    }}
+    mcu {{
+      clock {{ source_frequency = 12_000_000; }}
+      gpio {{ 1 {{ led2@20 {{ direction = \"out\"; }} }} }}
+    }}", board.display());
+
+  with_parsed_tts(src.as_str(), |cx, failed, pt| {
+    let pt = resolve_board(cx, pt.unwrap(), Path::new("")).unwrap();
+    assert!(unsafe{*failed} == false);
+    assert!(pt.get_by_path("board").is_none());
+    assert!(pt.get_by_name("lpc17xx").is_some());
+
+    let clock = pt.get_by_path("mcu").unwrap().get_by_path("clock").unwrap();
+    assert!(clock.get_string_attr("source") ==
+        Some("main-oscillator".to_string()));
+    assert!(clock.get_int_attr("source_frequency") == Some(12_000_000));
+
+    let led1 = pt.get_by_name("led1").unwrap();
+    assert!(led1.full_path() == "mcu::gpio::1::18".to_string());
+    assert!(led1.get_string_attr("direction") == Some("in".to_string()));
+    assert!(pt.get_by_name("led2").is_some());
+  });
+}
+
+#[test]
+fn fails_to_extend_board_with_conflicting_overrides() {
+  let board = write_board_file("platformtree_conflicting_overrides.pt", BOARD);
+  let src = format!("
+    board {{
+      include = \"{}\";
+      led1 {{ direction = \"in\"; }}
+    }}
+    mcu {{ gpio {{ 1 {{ 18 {{ direction = \"out\"; }} }} }} }}",
+    board.display());
+
+  with_parsed_tts(src.as_str(), |cx, failed, pt| {
+    assert!(resolve_board(cx, pt.unwrap(), Path::new("")).is_none());
+    assert!(unsafe{*failed} == true);
+  });
+}
+
+#[test]
+fn fails_to_extend_board_with_renamed_node() {
+  let board = write_board_file("platformtree_renamed_node.pt", BOARD);
+  let src = format!("
+    board {{ include = \"{}\"; }}
+    mcu {{ gpio {{ 1 {{ status@18 {{}} }} }} }}", board.display());
+
+  with_parsed_tts(src.as_str(), |cx, failed, pt| {
+    assert!(resolve_board(cx, pt.unwrap(), Path::new("")).is_none());
+    assert!(unsafe{*failed} == true);
+  });
+}
+
+#[test]
+fn fails_to_override_unknown_board_node() {
+  let board = write_board_file("platformtree_unknown_override.pt", BOARD);
+  let src = format!("
+    board {{
+      include = \"{}\";
+      console_uart {{ baud_rate = 9600; }}
+    }}", board.display());
+
+  with_parsed_tts(src.as_str(), |cx, failed, pt| {
+    assert!(resolve_board(cx, pt.unwrap(), Path::new("")).is_none());
+    assert!(unsafe{*failed} == true);
+  });
+}
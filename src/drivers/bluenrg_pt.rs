@@ -0,0 +1,91 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::rc::Rc;
+use syntax::ext::base::ExtCtxt;
+
+use builder::{Builder, TokenString};
+use node;
+
+pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.materializer.set(Some(build_bluenrg as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  node.mutator.set(Some(mutate_pin as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  super::add_ref_dependencies(builder, &node, &["active", "spi"]);
+}
+
+fn mutate_pin(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
+  super::set_pins_direction_out(builder, cx, &node, &["active"]);
+}
+
+fn build_bluenrg(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
+  if !node.expect_no_subnodes(cx) {return}
+
+  if !node.expect_attributes(cx,
+      &[("active", node::RefAttribute), ("spi", node::RefAttribute)]) {
+    return
+  }
+
+  if node.name.is_none() {
+    cx.parse_sess().span_diagnostic.span_err(node.name_span,
+        "bluenrg node must have a name");
+    return
+  }
+
+  let active = TokenString(node.get_ref_attr("active").unwrap());
+  let spi = TokenString(node.get_ref_attr("spi").unwrap());
+  let name = TokenString(node.name.clone().unwrap());
+
+  // BlueNrg owns its pin and bus, so they are moved into the driver.
+  node.set_type_name("zinc::drivers::bluenrg::BlueNrg".to_string());
+  node.set_type_params(vec!(
+      "zinc::hal::pin::Gpio".to_string(),
+      "zinc::hal::spi::Spi".to_string()));
+
+  let st = quote_stmt!(&*cx,
+      let $name = zinc::drivers::bluenrg::BlueNrg::new($active, $spi);
+  ).unwrap();
+  builder.add_main_statement(st);
+}
+
+#[cfg(test)]
+mod test {
+  use builder::Builder;
+  use test_helpers::{assert_equal_source, with_parsed};
+  use hamcrest::{assert_that, is, equal_to};
+
+  #[test]
+  fn builds_bluenrg() {
+    with_parsed("
+      csn@csn;
+      spi@spi;
+      ble@bluenrg {
+        active = &csn;
+        spi = &spi;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::mutate_pin(&mut builder, cx, pt.get_by_name("ble").unwrap());
+      super::build_bluenrg(&mut builder, cx, pt.get_by_name("ble").unwrap());
+      assert_that(unsafe{*failed}, is(equal_to(false)));
+      assert_that(builder.main_stmts().len(), is(equal_to(1usize)));
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "let ble = zinc::drivers::bluenrg::BlueNrg::new(csn, spi);");
+
+      let pin_node = pt.get_by_name("csn").unwrap();
+      assert_that(pin_node.get_string_attr("direction").unwrap(),
+          is(equal_to("out".to_string())));
+    });
+  }
+}
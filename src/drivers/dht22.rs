@@ -23,6 +23,7 @@ use hal::pin::GpioLevel::High;
 use hal::pin::GpioDirection::In;
 use hal::pin::GpioDirection::Out;
 use hal::pin::GpioLevel;
+use hal::time::{Clock, Duration};
 use hal::timer::Timer;
 
 /// Basic DHT22 driver ported over from Arduino example.
@@ -40,7 +41,7 @@ pub struct Measurements {
 }
 
 impl<'a, T: Timer, P: Gpio> DHT22<'a, T, P> {
-  /// Creates a new DHT22 driver based on I/O GPIO and a timer with 10us resolution.
+  /// Creates a new DHT22 driver based on I/O GPIO and a microsecond timer.
   pub fn new(timer: &'a T, gpio: &'a P) -> DHT22<'a, T, P> {
     DHT22 {
       gpio: gpio,
@@ -53,30 +54,31 @@ impl<'a, T: Timer, P: Gpio> DHT22<'a, T, P> {
     let buffer: &mut [u8; 5] = &mut [0; 5];
     let mut idx: usize = 0;
     let mut mask: u8 = 128;
+    let clock = Clock::new(self.timer);
 
     self.gpio.set_direction(Out);
     self.gpio.set_low();
-    self.timer.wait_ms(20);
+    clock.wait(Duration::from_ms(20));
     self.gpio.set_high();
-    self.timer.wait_us(40);
+    clock.wait(Duration::from_us(40));
     self.gpio.set_direction(In);
 
-    if !self.wait_sync() {
+    if !self.wait_sync(&clock) {
       return None
     }
 
     for _ in 0..40 {
-      if !self.wait_while(Low, 80) {
+      if !self.wait_while(&clock, Low, 80) {
         return None
       }
 
-      let t = self.timer.get_counter();
+      let t = clock.now();
 
-      if !self.wait_while(High, 80) {
+      if !self.wait_while(&clock, High, 80) {
         return None
       }
 
-      if self.timer.get_counter() - t > 40 {
+      if clock.elapsed(t) > Duration::from_us(40) {
         buffer[idx] |= mask;
       }
 
@@ -93,7 +95,8 @@ impl<'a, T: Timer, P: Gpio> DHT22<'a, T, P> {
     } else {
       0.1 * (((buffer[2] as u16) << 8) | buffer[3] as u16) as f32
     };
-    let checksum: u8 = buffer[0] + buffer[1] + buffer[2] + buffer[3];
+    let checksum: u8 = buffer[0].wrapping_add(buffer[1])
+      .wrapping_add(buffer[2]).wrapping_add(buffer[3]);
 
     if checksum != buffer[4] {
       None
@@ -105,24 +108,156 @@ impl<'a, T: Timer, P: Gpio> DHT22<'a, T, P> {
     }
   }
 
-  fn wait_sync(&self) -> bool {
-    if !self.wait_while(Low, 80) {
+  fn wait_sync(&self, clock: &Clock<T>) -> bool {
+    if !self.wait_while(clock, Low, 80) {
       false
-    } else if !self.wait_while(High, 100) {
+    } else if !self.wait_while(clock, High, 100) {
       false
     } else {
       true
     }
   }
 
-  fn wait_while(&self, level: GpioLevel, timeout: usize) -> bool {
-    for _ in 0..(timeout / 10) {
-      self.timer.wait_us(10);
-      if self.gpio.level() != level {
-        return true;
+  /// Waits while the line is at `level`, for at most `timeout` microseconds.
+  fn wait_while(&self, clock: &Clock<T>, level: GpioLevel, timeout: u64)
+      -> bool {
+    let deadline = clock.now() + Duration::from_us(timeout);
+    clock.wait_while(deadline, || self.gpio.level() == level)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::cell::RefCell;
+  use std::rc::Rc;
+  use std::vec::Vec;
+  use volatile_cell::{VolatileCell, VolatileCellReplayer, set_replayer};
+  use volatile_cell::models::{Clock, CounterModel, GpioModel, GpioRegister,
+                              RegisterFile};
+  use expectest::prelude::*;
+  use expectest;
+
+  use hal::pin::{Gpio, GpioDirection, GpioLevel};
+  use hal::timer::Timer;
+  use super::DHT22;
+
+  const PIN: usize = 4;
+
+  struct TestPin {
+    dir: VolatileCell<u32>,
+    pin: VolatileCell<u32>,
+    set: VolatileCell<u32>,
+    clr: VolatileCell<u32>,
+  }
+
+  impl Gpio for TestPin {
+    fn set_high(&self) {
+      self.set.set(1 << PIN);
+    }
+
+    fn set_low(&self) {
+      self.clr.set(1 << PIN);
+    }
+
+    fn level(&self) -> GpioLevel {
+      match self.pin.get() & (1 << PIN) {
+        0 => GpioLevel::Low,
+        _ => GpioLevel::High,
       }
     }
 
-    false
+    fn set_direction(&self, new_mode: GpioDirection) {
+      self.dir.set(match new_mode {
+        GpioDirection::In  => 0,
+        GpioDirection::Out => 1 << PIN,
+      });
+    }
+  }
+
+  struct TestTimer {
+    counter: VolatileCell<u32>,
+  }
+
+  impl Timer for TestTimer {
+    fn get_counter(&self) -> u32 {
+      self.counter.get()
+    }
+  }
+
+  fn address_of(cell: &VolatileCell<u32>) -> usize {
+    cell as *const VolatileCell<u32> as usize
+  }
+
+  /// Sensor response to the start signal, in microseconds.
+  fn response(data: &[u8; 5]) -> Vec<(u64, bool)> {
+    let mut waveform = Vec::new();
+    waveform.push((50, false));
+    waveform.push((50, true));
+    for byte in data.iter() {
+      for bit in (0..8).rev() {
+        let high = if byte & (1 << bit) != 0 { 70 } else { 25 };
+        waveform.push((50, false));
+        waveform.push((high, true));
+      }
+    }
+    waveform.push((50, false));
+    waveform
+  }
+
+  fn new_pin() -> TestPin {
+    TestPin {
+      dir: VolatileCell::new(0),
+      pin: VolatileCell::new(0),
+      set: VolatileCell::new(0),
+      clr: VolatileCell::new(0),
+    }
+  }
+
+  /// Connects a sensor sending `data`, returns the changes of the pin latch.
+  fn simulate(pin: &TestPin, timer: &TestTimer, data: &[u8; 5])
+      -> Rc<RefCell<Vec<(u64, bool)>>> {
+    let clock = Clock::new();
+    let gpio = GpioModel::new(clock.clone(), PIN)
+        .with_register(address_of(&pin.pin), GpioRegister::Pin)
+        .with_register(address_of(&pin.set), GpioRegister::Set)
+        .with_register(address_of(&pin.clr), GpioRegister::Clear)
+        .with_waveform(response(data));
+    let latches = gpio.latches();
+
+    add_volatile_model!(CounterModel::new(address_of(&timer.counter), clock.clone(), 1));
+    add_volatile_model!(RegisterFile::new(address_of(&pin.dir), 4));
+    add_volatile_model!(gpio);
+
+    latches
+  }
+
+  #[test]
+  fn reads_measurements() {
+    init_replayer!();
+    // 65.2% humidity, -10.1C
+    let (pin, timer) = (new_pin(), TestTimer { counter: VolatileCell::new(0) });
+    let latches = simulate(&pin, &timer, &[0x02, 0x8c, 0x80, 0x65, 0x73]);
+
+    let m = DHT22::new(&timer, &pin).read().unwrap();
+
+    // start signal is at least 20ms low
+    let latches = latches.borrow();
+    expect!(latches.len()).to(be_equal_to(2));
+    expect!(latches[0].1 == false && latches[1].1 == true).to(be_true());
+    expect!(latches[1].0 - latches[0].0 >= 20_000).to(be_true());
+
+    expect!(m.humidity > 65.15 && m.humidity < 65.25).to(be_true());
+    expect!(m.temperature > -10.15 && m.temperature < -10.05).to(be_true());
+    expect_replayer_valid!();
+  }
+
+  #[test]
+  fn fails_on_checksum_mismatch() {
+    init_replayer!();
+    let (pin, timer) = (new_pin(), TestTimer { counter: VolatileCell::new(0) });
+    simulate(&pin, &timer, &[0x02, 0x8c, 0x80, 0x65, 0x74]);
+
+    expect!(DHT22::new(&timer, &pin).read().is_none()).to(be_true());
+    expect_replayer_valid!();
   }
 }
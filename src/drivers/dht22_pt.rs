@@ -17,6 +17,7 @@ use std::rc::Rc;
 use syntax::ext::base::ExtCtxt;
 
 use builder::{Builder, TokenString, add_node_dependency};
+use builder::resources::Resource;
 use node;
 
 pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
@@ -32,9 +33,13 @@ pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
   add_node_dependency(&node, &timer_node);
 }
 
-fn mutate_pin(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
+fn mutate_pin(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
   let pin_node_name = node.get_ref_attr("pin").unwrap();
   let pin_node = builder.pt().get_by_name(pin_node_name.as_str()).unwrap();
+  if !builder.claim_resource(cx, Resource::pin_for_node(&pin_node), &node,
+      node.get_attr("pin").value_span) {
+    return
+  }
   pin_node.attributes.borrow_mut().insert("direction".to_string(),
         Rc::new(node::Attribute::new_nosp(node::StrValue("out".to_string()))));
 }
@@ -17,9 +17,12 @@ use std::rc::Rc;
 use syntax::ext::base::ExtCtxt;
 
 use builder::{Builder, add_node_dependency};
+use builder::resources::Resource;
 use node;
 
+mod bluenrg_pt;
 mod dht22_pt;
+mod lcd_pt;
 
 pub fn attach(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
   node.materializer.set(Some(verify as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
@@ -27,7 +30,11 @@ pub fn attach(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
     add_node_dependency(&node, sub);
 
     match sub.path.as_str() {
-      "dht22" => dht22_pt::attach(builder, cx, sub.clone()),
+      "dht22"    => dht22_pt::attach(builder, cx, sub.clone()),
+      "c12332"   => lcd_pt::attach_c12332(builder, cx, sub.clone()),
+      "ili9341"  => lcd_pt::attach_ili9341(builder, cx, sub.clone()),
+      "hd44780u" => lcd_pt::attach_hd44780u(builder, cx, sub.clone()),
+      "bluenrg"  => bluenrg_pt::attach(builder, cx, sub.clone()),
       _ => (),
     }
   }
@@ -35,5 +42,48 @@ pub fn attach(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
 
 fn verify(_: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
   node.expect_no_attributes(cx);
-  node.expect_subnodes(cx, &["dht22"]);
+  node.expect_subnodes(cx,
+      &["dht22", "c12332", "ili9341", "hd44780u", "bluenrg"]);
+}
+
+/// Adds dependencies on all the nodes referenced by given attributes.
+///
+/// Missing attributes are skipped, they are reported by the materializer.
+pub fn add_ref_dependencies(builder: &Builder, node: &Rc<node::Node>,
+    attrs: &[&str]) {
+  for attr in attrs.iter() {
+    let ref_node = node.get_ref_attr(attr).and_then(|name| {
+      builder.pt().get_by_name(name.as_str())
+    });
+    match ref_node {
+      Some(ref_node) => add_node_dependency(node, &ref_node),
+      None => (),
+    }
+  }
+}
+
+/// Forces the `direction` of gpio nodes referenced by given attributes to
+/// `out`.
+///
+/// The pins are claimed by the driver node, so that they can't be used by any
+/// other driver or peripheral.
+pub fn set_pins_direction_out(builder: &mut Builder, cx: &ExtCtxt,
+    node: &Rc<node::Node>, attrs: &[&str]) {
+  for attr in attrs.iter() {
+    let pin_node = node.get_ref_attr(attr).and_then(|name| {
+      builder.pt().get_by_name(name.as_str())
+    });
+    match pin_node {
+      Some(pin_node) => {
+        if !builder.claim_resource(cx, Resource::pin_for_node(&pin_node),
+            node, node.get_attr(attr).value_span) {
+          continue;
+        }
+        pin_node.attributes.borrow_mut().insert("direction".to_string(),
+            Rc::new(node::Attribute::new_nosp(
+                node::StrValue("out".to_string()))));
+      },
+      None => (),
+    }
+  }
 }
@@ -0,0 +1,266 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::rc::Rc;
+use syntax::ext::base::ExtCtxt;
+
+use builder::{Builder, TokenString};
+use node;
+
+static SPI_LCD_ATTRS: &'static [&'static str] =
+    &["spi", "timer", "dc", "cs", "reset"];
+static SPI_LCD_PINS: &'static [&'static str] = &["dc", "cs", "reset"];
+
+static HD44780U_ATTRS: &'static [&'static str] =
+    &["timer", "rs", "en", "d4", "d5", "d6", "d7"];
+static HD44780U_PINS: &'static [&'static str] =
+    &["rs", "en", "d4", "d5", "d6", "d7"];
+
+pub fn attach_c12332(builder: &mut Builder, _: &mut ExtCtxt,
+    node: Rc<node::Node>) {
+  node.materializer.set(Some(build_c12332 as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  node.mutator.set(Some(mutate_spi_lcd_pins as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  super::add_ref_dependencies(builder, &node, SPI_LCD_ATTRS);
+}
+
+pub fn attach_ili9341(builder: &mut Builder, _: &mut ExtCtxt,
+    node: Rc<node::Node>) {
+  node.materializer.set(Some(build_ili9341 as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  node.mutator.set(Some(mutate_spi_lcd_pins as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  super::add_ref_dependencies(builder, &node, SPI_LCD_ATTRS);
+}
+
+pub fn attach_hd44780u(builder: &mut Builder, _: &mut ExtCtxt,
+    node: Rc<node::Node>) {
+  node.materializer.set(Some(build_hd44780u as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  node.mutator.set(Some(mutate_hd44780u_pins as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  super::add_ref_dependencies(builder, &node, HD44780U_ATTRS);
+}
+
+fn mutate_spi_lcd_pins(builder: &mut Builder, cx: &mut ExtCtxt,
+    node: Rc<node::Node>) {
+  super::set_pins_direction_out(builder, cx, &node, SPI_LCD_PINS);
+}
+
+fn mutate_hd44780u_pins(builder: &mut Builder, cx: &mut ExtCtxt,
+    node: Rc<node::Node>) {
+  super::set_pins_direction_out(builder, cx, &node, HD44780U_PINS);
+}
+
+fn build_c12332(builder: &mut Builder, cx: &mut ExtCtxt,
+    node: Rc<node::Node>) {
+  build_spi_lcd(builder, cx, node, "zinc::drivers::lcd::c12332::C12332");
+}
+
+fn build_ili9341(builder: &mut Builder, cx: &mut ExtCtxt,
+    node: Rc<node::Node>) {
+  build_spi_lcd(builder, cx, node, "zinc::drivers::lcd::ili9341::ILI9341");
+}
+
+/// C12332 and ILI9341 share the same constructor signature of
+/// `new(spi, timer, dc, cs, reset)`.
+fn build_spi_lcd(builder: &mut Builder, cx: &mut ExtCtxt,
+    node: Rc<node::Node>, typename: &str) {
+  if !node.expect_no_subnodes(cx) {return}
+
+  let expectations: Vec<(&str, node::AttributeType)> = SPI_LCD_ATTRS.iter()
+      .map(|a| (*a, node::RefAttribute)).collect();
+  if !node.expect_attributes(cx, expectations.as_slice()) {
+    return
+  }
+
+  if node.name.is_none() {
+    cx.parse_sess().span_diagnostic.span_err(node.name_span,
+        "LCD node must have a name");
+    return
+  }
+
+  let spi = TokenString(node.get_ref_attr("spi").unwrap());
+  let timer = TokenString(node.get_ref_attr("timer").unwrap());
+  let dc = TokenString(node.get_ref_attr("dc").unwrap());
+  let cs = TokenString(node.get_ref_attr("cs").unwrap());
+  let reset = TokenString(node.get_ref_attr("reset").unwrap());
+  let name = TokenString(node.name.clone().unwrap());
+  let ctor = TokenString(format!("{}::new", typename));
+
+  node.set_type_name(typename.to_string());
+  node.set_type_params(vec!(
+      "'a".to_string(),
+      "zinc::hal::spi::Spi".to_string(),
+      "zinc::hal::timer::Timer".to_string(),
+      "zinc::hal::pin::Gpio".to_string()));
+
+  let st = quote_stmt!(&*cx,
+      let $name = $ctor(&$spi, &$timer, &$dc, &$cs, &$reset);
+  ).unwrap();
+  builder.add_main_statement(st);
+}
+
+fn build_hd44780u(builder: &mut Builder, cx: &mut ExtCtxt,
+    node: Rc<node::Node>) {
+  if !node.expect_no_subnodes(cx) {return}
+
+  let expectations: Vec<(&str, node::AttributeType)> = HD44780U_ATTRS.iter()
+      .map(|a| (*a, node::RefAttribute)).collect();
+  if !node.expect_attributes(cx, expectations.as_slice()) {
+    return
+  }
+
+  if node.name.is_none() {
+    cx.parse_sess().span_diagnostic.span_err(node.name_span,
+        "LCD node must have a name");
+    return
+  }
+
+  let timer = TokenString(node.get_ref_attr("timer").unwrap());
+  let rs = TokenString(node.get_ref_attr("rs").unwrap());
+  let en = TokenString(node.get_ref_attr("en").unwrap());
+  let d4 = TokenString(node.get_ref_attr("d4").unwrap());
+  let d5 = TokenString(node.get_ref_attr("d5").unwrap());
+  let d6 = TokenString(node.get_ref_attr("d6").unwrap());
+  let d7 = TokenString(node.get_ref_attr("d7").unwrap());
+  let name = TokenString(node.name.clone().unwrap());
+
+  node.set_type_name("zinc::drivers::lcd::hd44780u::Hd44780u".to_string());
+  node.set_type_params(vec!("'a".to_string()));
+
+  let st = quote_stmt!(&*cx,
+      let $name = zinc::drivers::lcd::hd44780u::Hd44780u::new(
+          &$timer, &$rs, &$en, [&$d4, &$d5, &$d6, &$d7]);
+  ).unwrap();
+  builder.add_main_statement(st);
+}
+
+#[cfg(test)]
+mod test {
+  use builder::Builder;
+  use test_helpers::{assert_equal_source, with_parsed};
+  use hamcrest::{assert_that, is, equal_to};
+
+  #[test]
+  fn builds_c12332() {
+    with_parsed("
+      timer@timer;
+      spi@spi;
+      dc@dc;
+      cs@cs;
+      reset@reset;
+      lcd@c12332 {
+        spi = &spi;
+        timer = &timer;
+        dc = &dc;
+        cs = &cs;
+        reset = &reset;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::mutate_spi_lcd_pins(&mut builder, cx, pt.get_by_name("lcd").unwrap());
+      super::build_c12332(&mut builder, cx, pt.get_by_name("lcd").unwrap());
+      assert_that(unsafe{*failed}, is(equal_to(false)));
+      assert_that(builder.main_stmts().len(), is(equal_to(1usize)));
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "let lcd = zinc::drivers::lcd::c12332::C12332::new(
+              &spi, &timer, &dc, &cs, &reset);");
+
+      for pin in ["dc", "cs", "reset"].iter() {
+        let pin_node = pt.get_by_name(pin).unwrap();
+        assert_that(pin_node.get_string_attr("direction").unwrap(),
+            is(equal_to("out".to_string())));
+      }
+      assert!(pt.get_by_name("spi").unwrap()
+          .get_string_attr("direction").is_none());
+    });
+  }
+
+  #[test]
+  fn builds_ili9341() {
+    with_parsed("
+      timer@timer;
+      spi@spi;
+      dc@dc;
+      cs@cs;
+      reset@reset;
+      lcd@ili9341 {
+        spi = &spi;
+        timer = &timer;
+        dc = &dc;
+        cs = &cs;
+        reset = &reset;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::mutate_spi_lcd_pins(&mut builder, cx, pt.get_by_name("lcd").unwrap());
+      super::build_ili9341(&mut builder, cx, pt.get_by_name("lcd").unwrap());
+      assert_that(unsafe{*failed}, is(equal_to(false)));
+      assert_that(builder.main_stmts().len(), is(equal_to(1usize)));
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "let lcd = zinc::drivers::lcd::ili9341::ILI9341::new(
+              &spi, &timer, &dc, &cs, &reset);");
+    });
+  }
+
+  #[test]
+  fn fails_to_build_spi_lcd_without_reset() {
+    with_parsed("
+      lcd@c12332 {
+        spi = &spi;
+        timer = &timer;
+        dc = &dc;
+        cs = &cs;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_c12332(&mut builder, cx, pt.get_by_name("lcd").unwrap());
+      assert_that(unsafe{*failed}, is(equal_to(true)));
+      assert_that(builder.main_stmts().len(), is(equal_to(0usize)));
+    });
+  }
+
+  #[test]
+  fn builds_hd44780u() {
+    with_parsed("
+      timer@timer;
+      rs@rs;
+      en@en;
+      d4@d4;
+      d5@d5;
+      d6@d6;
+      d7@d7;
+      lcd@hd44780u {
+        timer = &timer;
+        rs = &rs;
+        en = &en;
+        d4 = &d4;
+        d5 = &d5;
+        d6 = &d6;
+        d7 = &d7;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::mutate_hd44780u_pins(&mut builder, cx, pt.get_by_name("lcd").unwrap());
+      super::build_hd44780u(&mut builder, cx, pt.get_by_name("lcd").unwrap());
+      assert_that(unsafe{*failed}, is(equal_to(false)));
+      assert_that(builder.main_stmts().len(), is(equal_to(1usize)));
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "let lcd = zinc::drivers::lcd::hd44780u::Hd44780u::new(
+              &timer, &rs, &en, [&d4, &d5, &d6, &d7]);");
+
+      for pin in ["rs", "en", "d4", "d5", "d6", "d7"].iter() {
+        let pin_node = pt.get_by_name(pin).unwrap();
+        assert_that(pin_node.get_string_attr("direction").unwrap(),
+            is(equal_to("out".to_string())));
+      }
+    });
+  }
+}
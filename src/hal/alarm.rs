@@ -0,0 +1,446 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/*!
+Software timers.
+
+`Alarms` keeps a list of one-shot and periodic alarms sorted by deadline and
+programs the compare interrupt of a `CompareTimer` for the earliest one. The
+timer ISR must call `Alarms::handle_interrupt`, which runs the handlers of
+expired alarms right away or queues them for `Alarms::run_deferred`, to be
+called from a task or the main loop.
+*/
+
+use core::option::Option;
+
+use hal::time::{Clock, Duration, Instant};
+use hal::timer::CompareTimer;
+use util::shared::{NoInterrupts, Shared};
+
+/// Maximum number of scheduled alarms.
+pub const MAX_ALARMS: usize = 8;
+
+/// Function called when an alarm expires.
+pub type AlarmHandler = fn();
+
+/// Where the handler of an alarm runs.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Delivery {
+  /// The handler runs in the timer ISR.
+  Interrupt,
+  /// The handler runs from `Alarms::run_deferred`.
+  Deferred,
+}
+
+/// Identifies a scheduled alarm.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct AlarmId(u32);
+
+#[derive(Clone, Copy)]
+struct Alarm {
+  id: AlarmId,
+  deadline: Instant,
+  period: Option<Duration>,
+  handler: AlarmHandler,
+  delivery: Delivery,
+}
+
+struct AlarmQueue {
+  /// Scheduled alarms sorted by deadline, free slots at the end.
+  alarms: [Option<Alarm>; MAX_ALARMS],
+  /// Handlers waiting for `run_deferred`.
+  deferred: [Option<AlarmHandler>; MAX_ALARMS],
+  next_id: u32,
+}
+
+impl AlarmQueue {
+  fn insert(&mut self, alarm: Alarm) -> bool {
+    if self.alarms[MAX_ALARMS - 1].is_some() {
+      return false;
+    }
+    let mut i = MAX_ALARMS - 1;
+    while i > 0 {
+      match self.alarms[i - 1] {
+        Some(a) if a.deadline > alarm.deadline => self.alarms[i] = Some(a),
+        Some(_) => break,
+        None => (),
+      }
+      i -= 1;
+    }
+    self.alarms[i] = Some(alarm);
+    true
+  }
+
+  fn remove(&mut self, id: AlarmId) -> bool {
+    let pos = match self.alarms.iter().position(|a| match *a {
+      Some(a) => a.id == id,
+      None => false,
+    }) {
+      Some(pos) => pos,
+      None => return false,
+    };
+    for i in pos..(MAX_ALARMS - 1) {
+      self.alarms[i] = self.alarms[i + 1];
+    }
+    self.alarms[MAX_ALARMS - 1] = None;
+    true
+  }
+
+  fn pop_expired(&mut self, now: Instant) -> Option<Alarm> {
+    match self.alarms[0] {
+      Some(a) if a.deadline <= now => {
+        self.remove(a.id);
+        Some(a)
+      },
+      _ => None,
+    }
+  }
+
+  /// Queues a deferred handler, a handler already waiting runs only once.
+  fn defer(&mut self, handler: AlarmHandler) {
+    for slot in self.deferred.iter_mut() {
+      match *slot {
+        Some(h) if h as usize == handler as usize => return,
+        Some(_) => (),
+        None => {
+          *slot = Some(handler);
+          return;
+        },
+      }
+    }
+  }
+
+  fn take_deferred(&mut self) -> Option<AlarmHandler> {
+    let handler = self.deferred[0];
+    for i in 0..(MAX_ALARMS - 1) {
+      self.deferred[i] = self.deferred[i + 1];
+    }
+    self.deferred[MAX_ALARMS - 1] = None;
+    handler
+  }
+}
+
+/// Software timers driven by the compare interrupt of a timer.
+pub struct Alarms<'a, T: 'a> {
+  timer: &'a T,
+  clock: Clock<'a, T>,
+  queue: Shared<AlarmQueue>,
+}
+
+impl<'a, T: CompareTimer> Alarms<'a, T> {
+  /// Creates an alarm service on `timer`, which must count microseconds.
+  pub fn new(timer: &'a T) -> Alarms<'a, T> {
+    Alarms {
+      timer: timer,
+      clock: Clock::new(timer),
+      queue: Shared::new(AlarmQueue {
+        alarms: [None; MAX_ALARMS],
+        deferred: [None; MAX_ALARMS],
+        next_id: 0,
+      }),
+    }
+  }
+
+  /// Returns the clock the alarms are scheduled on.
+  pub fn clock(&self) -> &Clock<'a, T> {
+    &self.clock
+  }
+
+  /// Calls `handler` once after `delay`. Returns None if `MAX_ALARMS` alarms
+  /// are already scheduled.
+  pub fn after(&self, delay: Duration, handler: AlarmHandler,
+      delivery: Delivery) -> Option<AlarmId> {
+    self.schedule(delay, None, handler, delivery)
+  }
+
+  /// Calls `handler` every `period`, starting one period from now. Returns
+  /// None if `MAX_ALARMS` alarms are already scheduled.
+  pub fn every(&self, period: Duration, handler: AlarmHandler,
+      delivery: Delivery) -> Option<AlarmId> {
+    self.schedule(period, Some(period), handler, delivery)
+  }
+
+  /// Cancels a scheduled alarm. Returns false if it already expired or was
+  /// cancelled.
+  pub fn cancel(&self, id: AlarmId) -> bool {
+    let removed = {
+      let crit = NoInterrupts::new();
+      let mut queue = self.queue.borrow(&crit);
+      queue.remove(id)
+    };
+    self.dispatch();
+    removed
+  }
+
+  /// Runs expired alarms and programs the compare interrupt for the next
+  /// one. To be called from the timer ISR.
+  pub fn handle_interrupt(&self) {
+    self.timer.clear_compare();
+    self.dispatch();
+  }
+
+  /// Runs the handlers of expired `Deferred` alarms.
+  pub fn run_deferred(&self) {
+    loop {
+      let handler = {
+        let crit = NoInterrupts::new();
+        let mut queue = self.queue.borrow(&crit);
+        queue.take_deferred()
+      };
+      match handler {
+        Some(handler) => handler(),
+        None => break,
+      }
+    }
+  }
+
+  fn schedule(&self, delay: Duration, period: Option<Duration>,
+      handler: AlarmHandler, delivery: Delivery) -> Option<AlarmId> {
+    // The clock has its own critical section, which can't be nested
+    let deadline = self.clock.now() + delay;
+    let id = {
+      let crit = NoInterrupts::new();
+      let mut queue = self.queue.borrow(&crit);
+      let id = AlarmId(queue.next_id);
+      let alarm = Alarm {
+        id: id,
+        deadline: deadline,
+        period: period,
+        handler: handler,
+        delivery: delivery,
+      };
+      if !queue.insert(alarm) {
+        return None;
+      }
+      queue.next_id = queue.next_id.wrapping_add(1);
+      id
+    };
+    self.dispatch();
+    Some(id)
+  }
+
+  /// Runs expired alarms until the earliest one is in the future, then
+  /// programs the compare interrupt for it.
+  fn dispatch(&self) {
+    loop {
+      let now = self.clock.now();
+      let mut expired: [Option<AlarmHandler>; MAX_ALARMS] = [None; MAX_ALARMS];
+      let mut count = 0;
+      let next = {
+        let crit = NoInterrupts::new();
+        let mut queue = self.queue.borrow(&crit);
+
+        while count < MAX_ALARMS {
+          let alarm = match queue.pop_expired(now) {
+            Some(alarm) => alarm,
+            None => break,
+          };
+          match alarm.delivery {
+            Delivery::Interrupt => {
+              expired[count] = Some(alarm.handler);
+              count += 1;
+            },
+            Delivery::Deferred => queue.defer(alarm.handler),
+          }
+          match alarm.period {
+            Some(period) => {
+              queue.insert(Alarm { deadline: alarm.deadline + period, ..alarm });
+            },
+            None => (),
+          }
+        }
+
+        match queue.alarms[0] {
+          // The compare only matches the low bits, an interrupt for an alarm
+          // further than a counter period away just re-arms it.
+          Some(next) => self.timer.set_compare(next.deadline.as_us() as u32),
+          None => self.timer.disable_compare(),
+        }
+        queue.alarms[0].map(|a| a.deadline)
+      };
+
+      if count == 0 {
+        match next {
+          // The deadline may have passed while the compare was programmed
+          Some(deadline) if self.clock.now() >= deadline => continue,
+          _ => return,
+        }
+      }
+
+      for handler in expired.iter() {
+        match *handler {
+          Some(handler) => handler(),
+          None => (),
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use core::cell::Cell;
+  use super::{Alarms, Delivery};
+  use hal::time::Duration;
+  use hal::timer::{CompareTimer, Timer};
+  use volatile_cell::{VolatileCell, VolatileCellReplayer, set_replayer};
+  use volatile_cell::models::{Clock, CounterModel};
+  use expectest::prelude::*;
+  use expectest;
+
+  struct CompareCounter {
+    counter: VolatileCell<u32>,
+    compare: Cell<Option<u32>>,
+  }
+
+  impl Timer for CompareCounter {
+    fn get_counter(&self) -> u32 {
+      self.counter.get()
+    }
+  }
+
+  impl CompareTimer for CompareCounter {
+    fn set_compare(&self, counter: u32) {
+      self.compare.set(Some(counter));
+    }
+
+    fn disable_compare(&self) {
+      self.compare.set(None);
+    }
+
+    fn clear_compare(&self) {
+    }
+  }
+
+  fn new_timer() -> CompareCounter {
+    CompareCounter {
+      counter: VolatileCell::new(0),
+      compare: Cell::new(None),
+    }
+  }
+
+  fn simulate(timer: &CompareCounter) -> Clock {
+    let clock = Clock::new();
+    let counter = &timer.counter as *const VolatileCell<u32> as usize;
+    add_volatile_model!(CounterModel::new(counter, clock.clone(), 0));
+    clock
+  }
+
+  static mut ORDER: [u8; 4] = [0; 4];
+  static mut FIRED: usize = 0;
+
+  fn record(id: u8) {
+    unsafe {
+      ORDER[FIRED] = id;
+      FIRED += 1;
+    }
+  }
+
+  fn first() { record(1) }
+  fn second() { record(2) }
+
+  #[test]
+  fn runs_alarms_in_deadline_order() {
+    init_replayer!();
+    let timer = new_timer();
+    let clock = simulate(&timer);
+    let alarms = Alarms::new(&timer);
+
+    alarms.after(Duration::from_us(100), second, Delivery::Interrupt).unwrap();
+    alarms.after(Duration::from_us(50), first, Delivery::Interrupt).unwrap();
+    expect!(timer.compare.get()).to(be_equal_to(Some(50)));
+
+    clock.advance(60);
+    alarms.handle_interrupt();
+    expect!(timer.compare.get()).to(be_equal_to(Some(100)));
+
+    clock.advance(60);
+    alarms.handle_interrupt();
+    expect!(timer.compare.get()).to(be_equal_to(None));
+    expect!(unsafe { FIRED }).to(be_equal_to(2));
+    expect!(unsafe { ORDER[0..2] == [1, 2] }).to(be_true());
+  }
+
+  static mut TICKS: usize = 0;
+
+  fn tick() {
+    unsafe { TICKS += 1 };
+  }
+
+  #[test]
+  fn defers_periodic_alarms() {
+    init_replayer!();
+    let timer = new_timer();
+    let clock = simulate(&timer);
+    let alarms = Alarms::new(&timer);
+
+    alarms.every(Duration::from_ms(1), tick, Delivery::Deferred).unwrap();
+
+    clock.advance(1000);
+    alarms.handle_interrupt();
+    expect!(unsafe { TICKS }).to(be_equal_to(0));
+    expect!(timer.compare.get()).to(be_equal_to(Some(2000)));
+
+    alarms.run_deferred();
+    expect!(unsafe { TICKS }).to(be_equal_to(1));
+
+    clock.advance(1000);
+    alarms.handle_interrupt();
+    alarms.run_deferred();
+    alarms.run_deferred();
+    expect!(unsafe { TICKS }).to(be_equal_to(2));
+  }
+
+  static mut CANCELLED_FIRED: bool = false;
+
+  fn cancelled() {
+    unsafe { CANCELLED_FIRED = true };
+  }
+
+  #[test]
+  fn cancels_alarms() {
+    init_replayer!();
+    let timer = new_timer();
+    let clock = simulate(&timer);
+    let alarms = Alarms::new(&timer);
+
+    let id = alarms.after(Duration::from_us(10), cancelled,
+        Delivery::Interrupt).unwrap();
+    expect!(alarms.cancel(id)).to(be_true());
+    expect!(alarms.cancel(id)).to(be_equal_to(false));
+    expect!(timer.compare.get()).to(be_equal_to(None));
+
+    clock.advance(20);
+    alarms.handle_interrupt();
+    expect!(unsafe { CANCELLED_FIRED }).to(be_equal_to(false));
+  }
+
+  fn nothing() {}
+
+  #[test]
+  fn refuses_alarms_when_full() {
+    init_replayer!();
+    let timer = new_timer();
+    simulate(&timer);
+    let alarms = Alarms::new(&timer);
+
+    for _ in 0..super::MAX_ALARMS {
+      expect!(alarms.after(Duration::from_ms(1), nothing,
+          Delivery::Deferred).is_some()).to(be_true());
+    }
+    expect!(alarms.after(Duration::from_ms(1), nothing,
+        Delivery::Deferred).is_none()).to(be_true());
+  }
+}
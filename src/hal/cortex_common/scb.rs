@@ -19,6 +19,8 @@
 //! System Control Block ACTLR memory location is 0xE000_E008;
 //  Link: http://infocenter.arm.com/help/topic/com.arm.doc.dui0552a/CIHFDJCA.html
 
+use util::support::dsb;
+
 #[inline(always)]
 fn get_reg() -> &'static reg::SCB {
   unsafe { &*(0xE000_ED00 as *mut reg::SCB) }
@@ -39,6 +41,27 @@ pub fn set_pendsv(val: bool) {
   }
 }
 
+/// Selects deep sleep instead of sleep for `wfi`.
+pub fn set_sleep_deep(val: bool) {
+  get_reg().scr.set_sleepdeep(val);
+}
+
+/// Enters sleep automatically on return from the last active interrupt
+/// handler.
+pub fn set_sleep_on_exit(val: bool) {
+  get_reg().scr.set_sleeponexit(val);
+}
+
+/// Requests a system reset and waits for it.
+pub fn system_reset() -> ! {
+  // Writes to AIRCR are ignored without the key
+  get_reg().aircr
+    .set_vectkey(0x05fa)
+    .set_sysresetreq(true);
+  dsb();
+  loop {}
+}
+
 mod reg {
   use volatile_cell::VolatileCell;
   use core::ops::Drop;
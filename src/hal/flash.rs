@@ -0,0 +1,707 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/*!
+Internal flash programming and a key/value store on top of it.
+
+A `Flash` is a set of equally sized pages, the unit of erase. Erased flash
+reads as 0xff and can be programmed once, in units of `write_size` bytes, until
+the page is erased again.
+
+`Store` keeps small values under 16-bit keys in a log of records spread over
+two or more pages. New values are appended to the active page; when it fills
+up, the live records are copied to the next page in turn, so that all pages
+wear evenly. A record torn by a reset is detected by its checksum and ignored;
+if its header is torn, the rest of the page is skipped and the next write
+copies the live records to the next page.
+*/
+
+use core::cell::Cell;
+use core::option::Option;
+use core::result::Result;
+use core::result::Result::{Ok, Err};
+
+/// Flash and store errors.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Error {
+  /// Flash is locked, `unlock` wasn't called.
+  Locked,
+  /// Page is write protected.
+  Protected,
+  /// Offset or length isn't a multiple of the write size.
+  Alignment,
+  /// Page or offset is out of the flash.
+  OutOfRange,
+  /// The flash controller reported a failure.
+  Failed,
+  /// Store has no room left for the value.
+  Full,
+  /// Value is longer than `MAX_VALUE_LEN`.
+  TooLong,
+}
+
+/// Internal flash memory.
+pub trait Flash {
+  /// Returns the size of a page in bytes.
+  fn page_size(&self) -> usize;
+
+  /// Returns the number of pages.
+  fn page_count(&self) -> usize;
+
+  /// Returns the programming unit in bytes, a power of two of at most
+  /// `MAX_WRITE_SIZE`.
+  fn write_size(&self) -> usize;
+
+  /// Enables erasing and programming.
+  fn unlock(&self);
+
+  /// Disables erasing and programming.
+  fn lock(&self);
+
+  /// Returns true if `page` is write protected.
+  fn is_protected(&self, page: usize) -> bool;
+
+  /// Erases `page` to 0xff.
+  fn erase(&self, page: usize) -> Result<(), Error>;
+
+  /// Programs `data` at `offset` in `page`. Both the offset and the length
+  /// of the data must be multiples of `write_size`, and the flash must be
+  /// erased.
+  fn program(&self, page: usize, offset: usize, data: &[u8])
+      -> Result<(), Error>;
+
+  /// Reads `data.len()` bytes at `offset` in `page`.
+  fn read(&self, page: usize, offset: usize, data: &mut [u8]);
+}
+
+/// Largest `Flash::write_size` supported by `Store`.
+pub const MAX_WRITE_SIZE: usize = 256;
+
+/// Largest value that can be stored.
+pub const MAX_VALUE_LEN: usize = MAX_RECORD_LEN - HEADER_LEN;
+
+/// Largest record, header included.
+const MAX_RECORD_LEN: usize = 256;
+
+/// Record and page header length.
+const HEADER_LEN: usize = 8;
+
+/// Marks a page header.
+const PAGE_MAGIC: u32 = 0x5a4b_5653;
+
+/// Key of an erased record header, can't be used.
+const ERASED_KEY: u16 = 0xffff;
+
+/// Length of a record removing its key.
+const REMOVED_LEN: u16 = 0xffff;
+
+#[derive(Clone, Copy)]
+struct Record {
+  offset: usize,
+  key: u16,
+  len: u16,
+  checksum: u32,
+}
+
+impl Record {
+  fn data_len(&self) -> usize {
+    if self.len == REMOVED_LEN { 0 } else { self.len as usize }
+  }
+}
+
+/// Wear-leveled key/value store, using pages `first_page` to
+/// `first_page + pages - 1` of a `Flash`.
+pub struct Store<'a, F: 'a> {
+  flash: &'a F,
+  first_page: usize,
+  pages: usize,
+  /// Active page, relative to `first_page`.
+  active: Cell<usize>,
+  /// Offset of the first free record in the active page.
+  end: Cell<usize>,
+  sequence: Cell<u32>,
+}
+
+impl<'a, F: Flash> Store<'a, F> {
+  /// Opens the store, formatting it if no page holds one. `pages` must be at
+  /// least 2.
+  pub fn new(flash: &'a F, first_page: usize, pages: usize)
+      -> Result<Store<'a, F>, Error> {
+    if pages < 2 || first_page + pages > flash.page_count() ||
+        flash.write_size() > MAX_WRITE_SIZE {
+      return Err(Error::OutOfRange);
+    }
+
+    let store = Store {
+      flash: flash,
+      first_page: first_page,
+      pages: pages,
+      active: Cell::new(0),
+      end: Cell::new(0),
+      sequence: Cell::new(0),
+    };
+
+    let mut found = false;
+    for page in 0..pages {
+      match store.page_sequence(page) {
+        Some(sequence) if !found || sequence > store.sequence.get() => {
+          found = true;
+          store.active.set(page);
+          store.sequence.set(sequence);
+        },
+        _ => (),
+      }
+    }
+
+    if found {
+      let mut end = store.header_len();
+      store.each_record(|record| {
+        end = record.offset + store.record_len(record.data_len());
+        false
+      });
+      // A torn header hides the records after it, so nothing more can be
+      // appended to the page
+      if !store.is_erased_from(end) {
+        end = flash.page_size();
+      }
+      store.end.set(end);
+    } else {
+      try!(store.start_page(0, 1));
+      try!(store.finish_page());
+    }
+    Ok(store)
+  }
+
+  /// Reads the value of `key` into `value` and returns its length, or None if
+  /// the key isn't set. Only the first `value.len()` bytes are read if the
+  /// value is longer.
+  pub fn get(&self, key: u16, value: &mut [u8]) -> Option<usize> {
+    let mut latest = None;
+    self.each_record(|record| {
+      if record.key == key && self.is_valid(&record) {
+        latest = Some(record);
+      }
+      false
+    });
+
+    match latest {
+      Some(record) if record.len != REMOVED_LEN => {
+        let len = record.data_len();
+        let n = if len < value.len() { len } else { value.len() };
+        self.flash.read(self.page(), record.offset + HEADER_LEN,
+            &mut value[..n]);
+        Some(len)
+      },
+      _ => None,
+    }
+  }
+
+  /// Sets `key` to `value`. Key 0xffff is reserved.
+  pub fn set(&self, key: u16, value: &[u8]) -> Result<(), Error> {
+    if value.len() > MAX_VALUE_LEN {
+      return Err(Error::TooLong);
+    }
+    if key == ERASED_KEY {
+      return Err(Error::OutOfRange);
+    }
+    self.append(key, value.len() as u16, value)
+  }
+
+  /// Removes `key`.
+  pub fn remove(&self, key: u16) -> Result<(), Error> {
+    if key == ERASED_KEY {
+      return Err(Error::OutOfRange);
+    }
+    self.append(key, REMOVED_LEN, &[])
+  }
+
+  fn append(&self, key: u16, len: u16, value: &[u8]) -> Result<(), Error> {
+    let record_len = self.record_len(value.len());
+    if self.end.get() + record_len > self.flash.page_size() {
+      try!(self.compact());
+      if self.end.get() + record_len > self.flash.page_size() {
+        return Err(Error::Full);
+      }
+    }
+
+    let mut buf = [0xffu8; MAX_RECORD_LEN];
+    write_header(&mut buf, key, len, checksum(key, len, value));
+    for i in 0..value.len() {
+      buf[HEADER_LEN + i] = value[i];
+    }
+
+    self.flash.unlock();
+    let result = self.flash.program(self.page(), self.end.get(),
+        &buf[..record_len]);
+    self.flash.lock();
+    // Skip the rest of the page if it failed, a partly programmed header
+    // would hide the records after it
+    if result.is_ok() {
+      self.end.set(self.end.get() + record_len);
+    } else {
+      self.end.set(self.flash.page_size());
+    }
+    result
+  }
+
+  /// Copies the live records to the next page and makes it active. If that
+  /// fails, the old page stays active, full so that the next append tries
+  /// again.
+  fn compact(&self) -> Result<(), Error> {
+    let old = self.active.get();
+    let sequence = self.sequence.get();
+    let result = self.copy_to_next_page(old);
+    if result.is_err() {
+      // The next page has no header yet, it would be ignored on reopen
+      self.active.set(old);
+      self.sequence.set(sequence);
+      self.end.set(self.flash.page_size());
+    }
+    result
+  }
+
+  fn copy_to_next_page(&self, old: usize) -> Result<(), Error> {
+    try!(self.start_page((old + 1) % self.pages, self.sequence.get() + 1));
+
+    let mut result = Ok(());
+    let mut buf = [0xffu8; MAX_RECORD_LEN];
+    self.each_record_in(old, |record| {
+      if record.len == REMOVED_LEN || !self.is_latest(old, &record) ||
+          !self.is_valid_in(old, &record) {
+        return false;
+      }
+      let len = self.record_len(record.data_len());
+      self.flash.read(self.first_page + old, record.offset, &mut buf[..len]);
+      self.flash.unlock();
+      result = self.flash.program(self.page(), self.end.get(), &buf[..len]);
+      self.flash.lock();
+      self.end.set(self.end.get() + len);
+      result.is_err()
+    });
+    try!(result);
+
+    self.finish_page()
+  }
+
+  /// Erases `page` and makes it active, its header is written by
+  /// `finish_page` once the records are in place.
+  fn start_page(&self, page: usize, sequence: u32) -> Result<(), Error> {
+    self.flash.unlock();
+    let result = self.flash.erase(self.first_page + page);
+    self.flash.lock();
+    try!(result);
+
+    self.active.set(page);
+    self.sequence.set(sequence);
+    self.end.set(self.header_len());
+    Ok(())
+  }
+
+  fn finish_page(&self) -> Result<(), Error> {
+    let mut buf = [0xffu8; MAX_WRITE_SIZE];
+    write_u32(&mut buf[0..4], PAGE_MAGIC);
+    write_u32(&mut buf[4..8], self.sequence.get());
+
+    self.flash.unlock();
+    let result = self.flash.program(self.page(), 0,
+        &buf[..self.header_len()]);
+    self.flash.lock();
+    result
+  }
+
+  fn page_sequence(&self, page: usize) -> Option<u32> {
+    let mut buf = [0u8; HEADER_LEN];
+    self.flash.read(self.first_page + page, 0, &mut buf);
+    if read_u32(&buf[0..4]) == PAGE_MAGIC {
+      Some(read_u32(&buf[4..8]))
+    } else {
+      None
+    }
+  }
+
+  /// Returns true if the active page is erased from `offset` to its end.
+  fn is_erased_from(&self, offset: usize) -> bool {
+    let page_size = self.flash.page_size();
+    let mut offset = offset;
+    let mut buf = [0u8; HEADER_LEN];
+
+    while offset < page_size {
+      let len = if page_size - offset < HEADER_LEN {
+        page_size - offset
+      } else {
+        HEADER_LEN
+      };
+      self.flash.read(self.page(), offset, &mut buf[..len]);
+      if buf[..len].iter().any(|byte| *byte != 0xff) {
+        return false;
+      }
+      offset += len;
+    }
+    true
+  }
+
+  fn page(&self) -> usize {
+    self.first_page + self.active.get()
+  }
+
+  fn header_len(&self) -> usize {
+    self.record_len(0)
+  }
+
+  /// Returns the space taken by a record with `data_len` bytes of data.
+  fn record_len(&self, data_len: usize) -> usize {
+    let unit = self.flash.write_size();
+    (HEADER_LEN + data_len + unit - 1) / unit * unit
+  }
+
+  fn each_record<C: FnMut(Record) -> bool>(&self, f: C) {
+    self.each_record_in(self.active.get(), f)
+  }
+
+  /// Calls `f` with the records of `page` in order, until it returns true.
+  /// The scan stops at an erased header, and at a header torn by a reset
+  /// since the records after it can't be found.
+  fn each_record_in<C: FnMut(Record) -> bool>(&self, page: usize, mut f: C) {
+    let page_size = self.flash.page_size();
+    let mut offset = self.header_len();
+    let mut buf = [0u8; HEADER_LEN];
+
+    while offset + HEADER_LEN <= page_size {
+      self.flash.read(self.first_page + page, offset, &mut buf);
+      let record = Record {
+        offset: offset,
+        key: read_u16(&buf[0..2]),
+        len: read_u16(&buf[2..4]),
+        checksum: read_u32(&buf[4..8]),
+      };
+      if record.key == ERASED_KEY || record.data_len() > MAX_VALUE_LEN {
+        break;
+      }
+      let next = offset + self.record_len(record.data_len());
+      if next > page_size || f(record) {
+        break;
+      }
+      offset = next;
+    }
+  }
+
+  fn is_valid(&self, record: &Record) -> bool {
+    self.is_valid_in(self.active.get(), record)
+  }
+
+  fn is_valid_in(&self, page: usize, record: &Record) -> bool {
+    let mut buf = [0u8; MAX_VALUE_LEN];
+    let data = &mut buf[..record.data_len()];
+    self.flash.read(self.first_page + page, record.offset + HEADER_LEN, data);
+    checksum(record.key, record.len, data) == record.checksum
+  }
+
+  /// Returns true if no valid record after `record` has the same key.
+  fn is_latest(&self, page: usize, record: &Record) -> bool {
+    let mut latest = true;
+    self.each_record_in(page, |other| {
+      if other.offset > record.offset && other.key == record.key &&
+          self.is_valid_in(page, &other) {
+        latest = false;
+      }
+      !latest
+    });
+    latest
+  }
+}
+
+/// FNV-1a hash of a record.
+fn checksum(key: u16, len: u16, data: &[u8]) -> u32 {
+  let mut hash: u32 = 0x811c_9dc5;
+  let header = [key as u8, (key >> 8) as u8, len as u8, (len >> 8) as u8];
+  for byte in header.iter().chain(data.iter()) {
+    hash = (hash ^ *byte as u32).wrapping_mul(0x0100_0193);
+  }
+  hash
+}
+
+fn write_header(buf: &mut [u8], key: u16, len: u16, checksum: u32) {
+  buf[0] = key as u8;
+  buf[1] = (key >> 8) as u8;
+  buf[2] = len as u8;
+  buf[3] = (len >> 8) as u8;
+  write_u32(&mut buf[4..8], checksum);
+}
+
+fn write_u32(buf: &mut [u8], value: u32) {
+  for i in 0..4 {
+    buf[i] = (value >> (8 * i)) as u8;
+  }
+}
+
+fn read_u16(buf: &[u8]) -> u16 {
+  buf[0] as u16 | (buf[1] as u16) << 8
+}
+
+fn read_u32(buf: &[u8]) -> u32 {
+  (0..4).fold(0, |value, i| value | (buf[i] as u32) << (8 * i))
+}
+
+#[cfg(test)]
+mod test {
+  use core::cell::{Cell, RefCell};
+  use super::{Error, Flash, Store, MAX_VALUE_LEN};
+  use expectest::prelude::*;
+
+  const PAGE_SIZE: usize = 256;
+  const PAGES: usize = 4;
+
+  /// Flash in RAM, which fails programming already programmed bytes and can
+  /// lose power after programming a number of bytes.
+  struct RamFlash {
+    data: RefCell<[u8; PAGE_SIZE * PAGES]>,
+    erases: RefCell<[u32; PAGES]>,
+    unlocked: Cell<bool>,
+    power_left: Cell<Option<usize>>,
+  }
+
+  impl RamFlash {
+    fn new() -> RamFlash {
+      RamFlash {
+        data: RefCell::new([0xff; PAGE_SIZE * PAGES]),
+        erases: RefCell::new([0; PAGES]),
+        unlocked: Cell::new(false),
+        power_left: Cell::new(None),
+      }
+    }
+  }
+
+  impl Flash for RamFlash {
+    fn page_size(&self) -> usize {
+      PAGE_SIZE
+    }
+
+    fn page_count(&self) -> usize {
+      PAGES
+    }
+
+    fn write_size(&self) -> usize {
+      4
+    }
+
+    fn unlock(&self) {
+      self.unlocked.set(true);
+    }
+
+    fn lock(&self) {
+      self.unlocked.set(false);
+    }
+
+    fn is_protected(&self, _: usize) -> bool {
+      false
+    }
+
+    fn erase(&self, page: usize) -> Result<(), Error> {
+      if !self.unlocked.get() {
+        return Err(Error::Locked);
+      }
+      for byte in self.data.borrow_mut()[page * PAGE_SIZE..]
+          .iter_mut().take(PAGE_SIZE) {
+        *byte = 0xff;
+      }
+      self.erases.borrow_mut()[page] += 1;
+      Ok(())
+    }
+
+    fn program(&self, page: usize, offset: usize, data: &[u8])
+        -> Result<(), Error> {
+      if !self.unlocked.get() {
+        return Err(Error::Locked);
+      }
+      if offset % 4 != 0 || data.len() % 4 != 0 {
+        return Err(Error::Alignment);
+      }
+      let mut flash = self.data.borrow_mut();
+      for i in 0..data.len() {
+        match self.power_left.get() {
+          Some(0) => return Err(Error::Failed),
+          Some(n) => self.power_left.set(Some(n - 1)),
+          None => (),
+        }
+        let byte = &mut flash[page * PAGE_SIZE + offset + i];
+        if *byte != 0xff {
+          return Err(Error::Failed);
+        }
+        *byte = data[i];
+      }
+      Ok(())
+    }
+
+    fn read(&self, page: usize, offset: usize, data: &mut [u8]) {
+      let flash = self.data.borrow();
+      for i in 0..data.len() {
+        data[i] = flash[page * PAGE_SIZE + offset + i];
+      }
+    }
+  }
+
+  fn get(store: &Store<RamFlash>, key: u16) -> Option<u32> {
+    let mut buf = [0u8; 4];
+    store.get(key, &mut buf).map(|_| super::read_u32(&buf))
+  }
+
+  fn set(store: &Store<RamFlash>, key: u16, value: u32) -> Result<(), Error> {
+    let mut buf = [0u8; 4];
+    super::write_u32(&mut buf, value);
+    store.set(key, &buf)
+  }
+
+  #[test]
+  fn stores_values() {
+    let flash = RamFlash::new();
+    let store = Store::new(&flash, 0, PAGES).unwrap();
+
+    expect!(get(&store, 1)).to(be_equal_to(None));
+    set(&store, 1, 10).unwrap();
+    set(&store, 2, 20).unwrap();
+    set(&store, 1, 11).unwrap();
+    expect!(get(&store, 1)).to(be_equal_to(Some(11)));
+    expect!(get(&store, 2)).to(be_equal_to(Some(20)));
+
+    store.remove(2).unwrap();
+    expect!(get(&store, 2)).to(be_equal_to(None));
+  }
+
+  #[test]
+  fn reopens_store() {
+    let flash = RamFlash::new();
+    {
+      let store = Store::new(&flash, 1, 3).unwrap();
+      set(&store, 7, 70).unwrap();
+    }
+    let store = Store::new(&flash, 1, 3).unwrap();
+    expect!(get(&store, 7)).to(be_equal_to(Some(70)));
+    set(&store, 8, 80).unwrap();
+    expect!(get(&store, 8)).to(be_equal_to(Some(80)));
+  }
+
+  #[test]
+  fn levels_wear_when_compacting() {
+    let flash = RamFlash::new();
+    let store = Store::new(&flash, 0, PAGES).unwrap();
+    set(&store, 1, 1).unwrap();
+    store.remove(1).unwrap();
+
+    for i in 0..400 {
+      set(&store, 2, i).unwrap();
+      set(&store, 3, i * 2).unwrap();
+    }
+
+    expect!(get(&store, 1)).to(be_equal_to(None));
+    expect!(get(&store, 2)).to(be_equal_to(Some(399)));
+    expect!(get(&store, 3)).to(be_equal_to(Some(798)));
+
+    let erases = flash.erases.borrow();
+    let min = erases.iter().min().unwrap();
+    let max = erases.iter().max().unwrap();
+    expect!(*min > 0).to(be_true());
+    expect!(max - min <= 1).to(be_true());
+  }
+
+  #[test]
+  fn ignores_torn_records() {
+    let flash = RamFlash::new();
+    {
+      let store = Store::new(&flash, 0, PAGES).unwrap();
+      set(&store, 1, 10).unwrap();
+      flash.power_left.set(Some(10));
+      expect!(set(&store, 1, 11)).to(be_equal_to(Err(Error::Failed)));
+      flash.power_left.set(None);
+    }
+
+    let store = Store::new(&flash, 0, PAGES).unwrap();
+    expect!(get(&store, 1)).to(be_equal_to(Some(10)));
+    set(&store, 1, 12).unwrap();
+    expect!(get(&store, 1)).to(be_equal_to(Some(12)));
+  }
+
+  #[test]
+  fn skips_page_after_torn_header() {
+    let flash = RamFlash::new();
+    {
+      let store = Store::new(&flash, 0, PAGES).unwrap();
+      set(&store, 1, 10).unwrap();
+      flash.power_left.set(Some(3));
+      expect!(set(&store, 1, 11)).to(be_equal_to(Err(Error::Failed)));
+      flash.power_left.set(None);
+      set(&store, 3, 30).unwrap();
+      expect!(get(&store, 3)).to(be_equal_to(Some(30)));
+      flash.power_left.set(Some(3));
+      expect!(set(&store, 3, 31)).to(be_equal_to(Err(Error::Failed)));
+      flash.power_left.set(None);
+    }
+
+    let store = Store::new(&flash, 0, PAGES).unwrap();
+    expect!(get(&store, 1)).to(be_equal_to(Some(10)));
+    expect!(get(&store, 3)).to(be_equal_to(Some(30)));
+    set(&store, 1, 12).unwrap();
+    set(&store, 2, 20).unwrap();
+    expect!(get(&store, 1)).to(be_equal_to(Some(12)));
+    expect!(get(&store, 2)).to(be_equal_to(Some(20)));
+    expect!(get(&store, 3)).to(be_equal_to(Some(30)));
+  }
+
+  #[test]
+  fn keeps_old_page_when_compaction_fails() {
+    let flash = RamFlash::new();
+    let store = Store::new(&flash, 0, 2).unwrap();
+    set(&store, 1, 10).unwrap();
+    set(&store, 2, 20).unwrap();
+    for i in 0..18 {
+      set(&store, 3, i).unwrap();
+    }
+
+    // The page is full, the first record is copied and the second one torn
+    flash.power_left.set(Some(20));
+    expect!(set(&store, 4, 40)).to(be_equal_to(Err(Error::Failed)));
+    flash.power_left.set(None);
+    expect!(get(&store, 1)).to(be_equal_to(Some(10)));
+    expect!(get(&store, 2)).to(be_equal_to(Some(20)));
+    expect!(get(&store, 3)).to(be_equal_to(Some(17)));
+    {
+      let reopened = Store::new(&flash, 0, 2).unwrap();
+      expect!(get(&reopened, 2)).to(be_equal_to(Some(20)));
+      expect!(get(&reopened, 3)).to(be_equal_to(Some(17)));
+    }
+
+    set(&store, 4, 40).unwrap();
+    let reopened = Store::new(&flash, 0, 2).unwrap();
+    expect!(get(&reopened, 1)).to(be_equal_to(Some(10)));
+    expect!(get(&reopened, 2)).to(be_equal_to(Some(20)));
+    expect!(get(&reopened, 3)).to(be_equal_to(Some(17)));
+    expect!(get(&reopened, 4)).to(be_equal_to(Some(40)));
+  }
+
+  #[test]
+  fn reports_full_store() {
+    let flash = RamFlash::new();
+    let store = Store::new(&flash, 0, 2).unwrap();
+    let value = [0u8; MAX_VALUE_LEN];
+
+    expect!(store.set(1, &[0u8; MAX_VALUE_LEN + 1]))
+        .to(be_equal_to(Err(Error::TooLong)));
+    expect!(store.set(1, &value)).to(be_equal_to(Err(Error::Full)));
+    expect!(store.set(1, &value[..120])).to(be_equal_to(Ok(())));
+    expect!(store.set(2, &value[..120])).to(be_equal_to(Err(Error::Full)));
+
+    let mut buf = [0xffu8; 120];
+    expect!(store.get(1, &mut buf)).to(be_equal_to(Some(120)));
+  }
+}
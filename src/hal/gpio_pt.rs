@@ -0,0 +1,97 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Platform tree attributes shared by the gpio nodes of all MCUs.
+
+use std::rc::Rc;
+use syntax::ext::base::ExtCtxt;
+
+use builder::{Builder, TokenString};
+use builder::resources::Resource;
+use node;
+
+/// Claims the pin of a gpio node that sets its own `direction`, i.e. that the
+/// application uses as a plain GPIO.
+///
+/// Peripherals and drivers set the `direction` of the pins they take in their
+/// mutators, so this must be called from `attach`, which runs before them.
+pub fn claim_gpio_pin(builder: &mut Builder, cx: &ExtCtxt,
+    node: &Rc<node::Node>) {
+  if node.get_string_attr("direction").is_none() ||
+      node.attributes.borrow().contains_key("function") {
+    return;
+  }
+  let span = node.get_attr("direction").key_span;
+  builder.claim_resource(cx, Resource::pin_for_node(node), node, span);
+}
+
+/// Adds statements setting up the pin interrupt from the `interrupt` and
+/// `interrupt_handler` attributes of a gpio node, if present.
+///
+/// `interrupt` is one of `rising`, `falling`, `both`, `low` or `high`, and
+/// `interrupt_handler` names a `fn()` called by `dispatch_interrupts()`.
+///
+/// The pin interrupts are dispatched from the `irq` vector, which is claimed
+/// for `irq_owner`, the gpio node sharing it between its pins.
+pub fn build_interrupt(builder: &mut Builder, cx: &ExtCtxt,
+    node: &Rc<node::Node>, irq_owner: &node::Node, irq: &str) {
+  let trigger_str = match node.get_string_attr("interrupt") {
+    None => {
+      if node.get_string_attr("interrupt_handler").is_some() {
+        cx.parse_sess().span_diagnostic.span_err(
+            node.get_attr("interrupt_handler").key_span,
+            "`interrupt_handler` requires the `interrupt` attribute");
+      }
+      return;
+    },
+    Some(trigger) => match trigger.as_str() {
+      "rising"  => "RisingEdge",
+      "falling" => "FallingEdge",
+      "both"    => "BothEdges",
+      "low"     => "LowLevel",
+      "high"    => "HighLevel",
+      other     => {
+        cx.parse_sess().span_diagnostic.span_err(
+            node.get_attr("interrupt").value_span,
+            format!("unknown interrupt trigger `{}`, allowed values: `rising`, \
+                `falling`, `both`, `low`, `high`", other).as_str());
+        return;
+      },
+    },
+  };
+  if !builder.claim_resource(cx, Resource::Irq(irq.to_string()), irq_owner,
+      node.get_attr("interrupt").key_span) {
+    return;
+  }
+
+  let trigger = TokenString(format!("zinc::hal::pin::GpioTrigger::{}", trigger_str));
+  let pin_name = TokenString(node.name.clone().unwrap());
+
+  match node.get_string_attr("interrupt_handler") {
+    Some(handler) => {
+      let handler = TokenString(handler);
+      let st = quote_stmt!(cx,
+          zinc::hal::pin::InterruptPin::set_interrupt_handler(&$pin_name, $handler);
+      ).unwrap();
+      builder.add_main_statement(st);
+    },
+    None => (),
+  }
+
+  let st = quote_stmt!(cx,
+      zinc::hal::pin::InterruptPin::enable_interrupt(&$pin_name, $trigger);
+  ).unwrap();
+  builder.add_main_statement(st);
+}
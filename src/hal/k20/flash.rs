@@ -0,0 +1,247 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/*!
+Flash programming for Kinetis K20, through the Flash Memory Module (FTFL).
+
+Pages are the 1 KB program flash sectors. The program flash can't be read
+while a command runs, so commands are launched from RAM with interrupts
+disabled.
+*/
+
+use core::intrinsics::{abort, volatile_load, volatile_store};
+use core::result::Result;
+use core::result::Result::{Ok, Err};
+
+use hal::flash::{self, Error};
+use util::shared::NoInterrupts;
+use super::sim;
+
+/// Size of a page.
+const SECTOR_SIZE: usize = 1024;
+
+/// Address of the status register, for `launch`.
+const FSTAT: usize = 0x4002_0000;
+
+/// Command complete flag.
+const FSTAT_CCIF: u8 = 0x80;
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+enum Command {
+  ProgramLongword = 0x06,
+  EraseSector = 0x09,
+}
+
+/// Flash memory module.
+#[derive(Clone, Copy)]
+pub struct Ftfl {
+  first_sector: usize,
+  pages: usize,
+}
+
+impl Ftfl {
+  /// Creates the interface to `pages` sectors from `first_sector`, which
+  /// must be above the program.
+  pub fn new(first_sector: usize, pages: usize) -> Ftfl {
+    Ftfl {
+      first_sector: first_sector,
+      pages: pages,
+    }
+  }
+
+  fn address(&self, page: usize, offset: usize) -> usize {
+    (self.first_sector + page) * SECTOR_SIZE + offset
+  }
+
+  /// Runs a command on `address` with `data`, for commands which take it.
+  fn run(&self, command: Command, address: usize, data: u32)
+      -> Result<(), Error> {
+    let r = &reg::FTFL;
+
+    wait_for_ccif();
+    // Errors of the previous command block new ones until cleared
+    r.fstat
+      .clear_accerr()
+      .clear_fpviol();
+
+    r.fccob0.set_value(command as u8);
+    r.fccob1.set_value((address >> 16) as u8);
+    r.fccob2.set_value((address >> 8) as u8);
+    r.fccob3.set_value(address as u8);
+    r.fccob4.set_value((data >> 24) as u8);
+    r.fccob5.set_value((data >> 16) as u8);
+    r.fccob6.set_value((data >> 8) as u8);
+    r.fccob7.set_value(data as u8);
+
+    {
+      let _crit = NoInterrupts::new();
+      launch();
+    }
+
+    let fstat = r.fstat.get();
+    if fstat.fpviol() {
+      Err(Error::Protected)
+    } else if fstat.accerr() {
+      Err(Error::Alignment)
+    } else if fstat.mgstat0() {
+      Err(Error::Failed)
+    } else {
+      Ok(())
+    }
+  }
+
+  /// Returns the size of a protection region, 1/32 of the program flash.
+  fn protection_region_size(&self) -> usize {
+    let flash_size = match sim::reg::SIM.fcfg1.pfsize() {
+      3 => 32 * 1024,
+      5 => 64 * 1024,
+      7 => 128 * 1024,
+      9 => 256 * 1024,
+      _ => unsafe { abort() },
+    };
+    flash_size / 32
+  }
+}
+
+fn wait_for_ccif() {
+  while unsafe { volatile_load(FSTAT as *const u8) } & FSTAT_CCIF == 0 {}
+}
+
+/// Starts the loaded command and waits for it to complete. Runs from RAM and
+/// touches the status register directly, as no code can be fetched from
+/// flash meanwhile.
+#[inline(never)]
+#[link_section=".data.ftfl_launch"]
+fn launch() {
+  unsafe {
+    volatile_store(FSTAT as *mut u8, FSTAT_CCIF);
+    while volatile_load(FSTAT as *const u8) & FSTAT_CCIF == 0 {}
+  }
+}
+
+impl flash::Flash for Ftfl {
+  fn page_size(&self) -> usize {
+    SECTOR_SIZE
+  }
+
+  fn page_count(&self) -> usize {
+    self.pages
+  }
+
+  fn write_size(&self) -> usize {
+    4
+  }
+
+  /// Does nothing, the FTFL has no lock.
+  fn unlock(&self) {
+  }
+
+  /// Does nothing, the FTFL has no lock.
+  fn lock(&self) {
+  }
+
+  fn is_protected(&self, page: usize) -> bool {
+    let region = self.address(page, 0) / self.protection_region_size();
+    // FPROT0, covering regions 0 to 7, is the last byte of the register
+    let bit = (3 - region / 8) * 8 + region % 8;
+    // Regions are protected when their bit is cleared
+    !reg::FTFL.fprot.prot(bit)
+  }
+
+  fn erase(&self, page: usize) -> Result<(), Error> {
+    if page >= self.pages {
+      return Err(Error::OutOfRange);
+    }
+    self.run(Command::EraseSector, self.address(page, 0), 0)
+  }
+
+  fn program(&self, page: usize, offset: usize, data: &[u8])
+      -> Result<(), Error> {
+    if offset % 4 != 0 || data.len() % 4 != 0 {
+      return Err(Error::Alignment);
+    }
+    if page >= self.pages || offset + data.len() > SECTOR_SIZE {
+      return Err(Error::OutOfRange);
+    }
+
+    for (i, word) in data.chunks(4).enumerate() {
+      let value = word[0] as u32 | (word[1] as u32) << 8 |
+          (word[2] as u32) << 16 | (word[3] as u32) << 24;
+      try!(self.run(Command::ProgramLongword,
+          self.address(page, offset + i * 4), value));
+    }
+    Ok(())
+  }
+
+  fn read(&self, page: usize, offset: usize, data: &mut [u8]) {
+    let address = self.address(page, offset);
+    for i in 0..data.len() {
+      data[i] = unsafe { volatile_load((address + i) as *const u8) };
+    }
+  }
+}
+
+#[allow(dead_code)]
+mod reg {
+  use volatile_cell::VolatileCell;
+  use core::ops::Drop;
+
+  ioregs!(FTFL = {
+    /// Flash Status Register
+    0x0 => reg8 fstat {
+      0 => mgstat0: ro,     //= Memory controller command completion status
+      4 => fpviol: set_to_clear, //= Flash protection violation
+      5 => accerr: set_to_clear, //= Flash access error
+      6 => rdcolerr: set_to_clear, //= Flash read collision error
+      7 => ccif: set_to_clear, //= Command complete, writing launches a command
+    },
+
+    /// Flash Common Command Object Registers, in address order
+    0x4 => reg8 fccob3 {
+      0..7 => value,
+    },
+    0x5 => reg8 fccob2 {
+      0..7 => value,
+    },
+    0x6 => reg8 fccob1 {
+      0..7 => value,
+    },
+    0x7 => reg8 fccob0 {
+      0..7 => value,
+    },
+    0x8 => reg8 fccob7 {
+      0..7 => value,
+    },
+    0x9 => reg8 fccob6 {
+      0..7 => value,
+    },
+    0xa => reg8 fccob5 {
+      0..7 => value,
+    },
+    0xb => reg8 fccob4 {
+      0..7 => value,
+    },
+
+    /// Program Flash Protection Registers, FPROT3 at 0x10 to FPROT0 at 0x13
+    0x10 => reg32 fprot {
+      0..31 => prot[32],    //= Region protection, cleared when protected
+    },
+  });
+
+  extern {
+    #[link_name="k20_iomem_FLASH"] pub static FTFL: FTFL;
+  }
+}
@@ -15,8 +15,11 @@
 
 //! HAL for Freescale Kinetis K20.
 
+pub mod flash;
 pub mod sim;
 pub mod pin;
+pub mod reset;
+pub mod rtc;
 pub mod uart;
 pub mod watchdog;
 pub mod timer;
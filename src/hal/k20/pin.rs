@@ -24,6 +24,8 @@ use core::option::Option;
 use core::marker::Copy;
 
 use super::sim;
+use hal::cortex_m4::nvic;
+use hal::pin::{GpioTrigger, InterruptHandler};
 
 use self::Port::*;
 use self::Function::*;
@@ -31,6 +33,9 @@ use self::PullConf::*;
 use self::DriveStrength::*;
 use self::SlewRate::*;
 
+/// Interrupt handlers of the pins of ports A to E.
+static mut INTERRUPT_HANDLERS: [Option<InterruptHandler>; 160] = [None; 160];
+
 /// A pin.
 #[allow(missing_docs)]
 #[derive(Clone, Copy)]
@@ -156,15 +161,22 @@ impl Pin {
     }
   }
 
-  fn pcr(&self) -> &'static reg::Port_pcr {
-    let port: &reg::Port = match self.port {
+  fn portreg(&self) -> &'static reg::Port {
+    match self.port {
       PortA => &reg::PORT_A,
       PortB => &reg::PORT_B,
       PortC => &reg::PORT_C,
       PortD => &reg::PORT_D,
       PortE => &reg::PORT_E,
-    };
-    return &port.pcr[self.pin as usize];
+    }
+  }
+
+  fn pcr(&self) -> &'static reg::Port_pcr {
+    return &self.portreg().pcr[self.pin as usize];
+  }
+
+  fn handler_slot(&self) -> usize {
+    (self.port as usize - 1) * 32 + self.pin as usize
   }
 }
 
@@ -201,6 +213,90 @@ impl ::hal::pin::Gpio for Pin {
 }
 
 /// Register definitions
+impl ::hal::pin::PinConfig for Pin {
+  fn set_pull(&self, pull: ::hal::pin::GpioPull) {
+    use hal::pin::GpioPull;
+    use self::reg::Port_pcr_ps as ps;
+
+    let (pe, ps) = match pull {
+      GpioPull::Floating => (false, ps::PULL_DOWN),
+      GpioPull::PullDown => (true,  ps::PULL_DOWN),
+      GpioPull::PullUp   => (true,  ps::PULL_UP),
+    };
+    self.pcr().set_pe(pe).set_ps(ps);
+  }
+
+  fn set_open_drain(&self, enabled: bool) {
+    self.pcr().set_ode(enabled);
+  }
+
+  /// Sets the slew rate and drive strength: `Low` is slow with low drive,
+  /// `Medium` slow with high drive and `High` fast with high drive.
+  fn set_speed(&self, speed: ::hal::pin::GpioSpeed) {
+    use hal::pin::GpioSpeed;
+    use self::reg::Port_pcr_sre as sre;
+    use self::reg::Port_pcr_dse as dse;
+
+    let (sre, dse) = match speed {
+      GpioSpeed::Low    => (sre::SLOW, dse::LOW_DRIVE),
+      GpioSpeed::Medium => (sre::SLOW, dse::HIGH_DRIVE),
+      GpioSpeed::High   => (sre::FAST, dse::HIGH_DRIVE),
+    };
+    self.pcr().set_sre(sre).set_dse(dse);
+  }
+}
+
+impl ::hal::pin::InterruptPin for Pin {
+  fn enable_interrupt(&self, trigger: GpioTrigger) {
+    use self::reg::Port_pcr_irqc as irqc;
+    self.pcr().set_irqc(match trigger {
+      GpioTrigger::RisingEdge  => irqc::IRQ_RISING,
+      GpioTrigger::FallingEdge => irqc::IRQ_FALLING,
+      GpioTrigger::BothEdges   => irqc::IRQ_EITHER,
+      GpioTrigger::LowLevel    => irqc::IRQ_ZERO,
+      GpioTrigger::HighLevel   => irqc::IRQ_ONE,
+    });
+    // PORTA..PORTE interrupts are IRQs 87..91
+    nvic::enable_irq(86 + self.port as usize);
+  }
+
+  fn disable_interrupt(&self) {
+    self.pcr().set_irqc(reg::Port_pcr_irqc::IRQ_NONE);
+  }
+
+  fn is_interrupt_pending(&self) -> bool {
+    self.portreg().isfr.isf(self.pin as usize)
+  }
+
+  fn clear_interrupt(&self) {
+    self.portreg().isfr.clear_isf(self.pin as usize);
+  }
+
+  fn set_interrupt_handler(&self, handler: InterruptHandler) {
+    unsafe { INTERRUPT_HANDLERS[self.handler_slot()] = Some(handler) };
+  }
+}
+
+/// Calls the handlers of pins with pending interrupts and clears them. To be
+/// called from the `isr_port_*` interrupt service routines.
+pub fn dispatch_interrupts() {
+  let ports = [&reg::PORT_A, &reg::PORT_B, &reg::PORT_C, &reg::PORT_D,
+               &reg::PORT_E];
+  for (port, reg) in ports.iter().enumerate() {
+    let pending = reg.isfr.get().raw();
+    for pin in 0..32 {
+      if pending & (1 << pin) == 0 {
+        continue;
+      }
+      reg.isfr.clear_isf(pin);
+      match unsafe { INTERRUPT_HANDLERS[port * 32 + pin] } {
+        Some(handler) => handler(),
+        None => (),
+      }
+    }
+  }
+}
+
 pub mod reg {
   use volatile_cell::VolatileCell;
   use core::ops::Drop;
@@ -238,6 +334,7 @@ pub mod reg {
         11 => IRQ_EITHER,
         12 => IRQ_ONE,
       }
+      24     => isf: set_to_clear, //= Interrupt status flag
     }
 
     0x80   => reg32 gpclr {   //= Global pin control low
@@ -250,8 +347,8 @@ pub mod reg {
       16..31 => gpwe,
     }
 
-    0x88   => reg32 isfr {    //= Interrupt status
-      0..31  => isf
+    0xa0   => reg32 isfr {    //= Interrupt status
+      0..31  => isf[32]: set_to_clear
     }
   });
 
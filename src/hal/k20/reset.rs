@@ -0,0 +1,70 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/*!
+Reset cause from the Reset Control Module.
+*/
+
+use hal::reset::{ResetCause, ResetFlags};
+
+/// Returns the cause of the last reset. The flags are only set by the last
+/// reset, so they don't need to be cleared.
+pub fn reset_cause() -> ResetCause {
+  let srs0 = reg::RCM.srs0.get();
+  let srs1 = reg::RCM.srs1.get();
+  ResetFlags {
+    power_on: srs0.por(),
+    brown_out: srs0.lvd(),
+    watchdog: srs0.wdog(),
+    software: srs1.sw(),
+    pin: srs0.pin(),
+    lockup: srs1.lockup(),
+  }.cause()
+}
+
+/// Does nothing, the reset flags are cleared by the next reset.
+pub fn clear_reset_cause() {
+}
+
+#[allow(dead_code)]
+mod reg {
+  use volatile_cell::VolatileCell;
+  use core::ops::Drop;
+
+  ioregs!(RCM = {
+    /// System Reset Status Register 0
+    0x0 => reg8 srs0 {
+      0 => wakeup: ro,     //= Low leakage wakeup reset
+      1 => lvd: ro,        //= Low-voltage detect reset
+      2 => loc: ro,        //= Loss of clock reset
+      5 => wdog: ro,       //= Watchdog reset
+      6 => pin: ro,        //= External reset pin
+      7 => por: ro,        //= Power-on reset
+    },
+
+    /// System Reset Status Register 1
+    0x1 => reg8 srs1 {
+      0 => jtag: ro,       //= JTAG generated reset
+      1 => lockup: ro,     //= Core lockup reset
+      2 => sw: ro,         //= Software reset
+      3 => mdm_ap: ro,     //= MDM-AP system reset request
+      4 => ezpt: ro,       //= EzPort reset
+    },
+  });
+
+  extern {
+    #[link_name="k20_iomem_RCM"] pub static RCM: RCM;
+  }
+}
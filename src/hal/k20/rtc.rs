@@ -0,0 +1,168 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/*!
+Real-time clock for Kinetis K20.
+
+The RTC counts seconds from the 32.768 kHz oscillator, on the battery supply
+along with the VBAT register file. The alarm interrupt wakes the MCU up from
+all low-power modes.
+*/
+
+use core::result::Result;
+use core::result::Result::Ok;
+
+use hal::rtc::{self, DateTime};
+use super::sim;
+
+/// Number of 32-bit words in the VBAT register file.
+const BACKUP_REGISTERS: usize = 8;
+
+/// Real-time clock.
+#[derive(Clone, Copy)]
+pub struct Rtc;
+
+impl Rtc {
+  /// Enables the RTC registers and starts the oscillator and the counter,
+  /// unless they are already running. The oscillator takes a while to start,
+  /// the first seconds may be counted late.
+  pub fn new() -> Rtc {
+    sim::reg::SIM.scgc6.set_rtc(true);
+
+    if !reg::RTC.cr.osce() {
+      reg::RTC.cr.set_osce(true);
+    }
+    // An invalid time after power-up stops the counter until it is set
+    if reg::RTC.sr.tif() {
+      reg::RTC.sr.set_tce(false);
+      reg::RTC.tsr.set_seconds(0);
+    }
+    reg::RTC.sr.set_tce(true);
+    Rtc
+  }
+}
+
+impl rtc::Rtc for Rtc {
+  fn set_time(&self, time: &DateTime) -> Result<(), rtc::Error> {
+    reg::RTC.sr.set_tce(false);
+    reg::RTC.tpr.set_prescaler(0);
+    reg::RTC.tsr.set_seconds(time.timestamp());
+    reg::RTC.sr.set_tce(true);
+    Ok(())
+  }
+
+  fn time(&self) -> DateTime {
+    // The counter is read twice, in case it changes during the read
+    let mut seconds = reg::RTC.tsr.seconds();
+    loop {
+      let again = reg::RTC.tsr.seconds();
+      if again == seconds {
+        return DateTime::from_timestamp(seconds);
+      }
+      seconds = again;
+    }
+  }
+
+  fn set_alarm(&self, time: &DateTime) -> Result<(), rtc::Error> {
+    // The alarm flag is set when the counter increments past the alarm
+    reg::RTC.tar.set_seconds(time.timestamp().wrapping_sub(1));
+    reg::RTC.ier.set_taie(true);
+    Ok(())
+  }
+
+  fn disable_alarm(&self) {
+    reg::RTC.ier.set_taie(false);
+  }
+
+  fn alarm_pending(&self) -> bool {
+    reg::RTC.sr.taf()
+  }
+
+  /// Clears the alarm flag by moving the alarm past the current time.
+  fn clear_alarm(&self) {
+    reg::RTC.tar.set_seconds(reg::RTC.tsr.seconds().wrapping_sub(1));
+  }
+}
+
+impl rtc::BackupRegisters for Rtc {
+  fn backup_len(&self) -> usize {
+    BACKUP_REGISTERS
+  }
+
+  fn read_backup(&self, index: usize) -> u32 {
+    reg::VBAT.data[index].value()
+  }
+
+  fn write_backup(&self, index: usize, value: u32) {
+    reg::VBAT.data[index].set_value(value);
+  }
+}
+
+#[allow(dead_code)]
+mod reg {
+  use volatile_cell::VolatileCell;
+  use core::ops::Drop;
+
+  ioregs!(RTC = {
+    /// Time Seconds Register
+    0x0 => reg32 tsr {
+      0..31 => seconds,     //= Seconds counter
+    },
+
+    /// Time Prescaler Register
+    0x4 => reg32 tpr {
+      0..15 => prescaler,   //= Counts oscillator cycles up to a second
+    },
+
+    /// Time Alarm Register
+    0x8 => reg32 tar {
+      0..31 => seconds,     //= Alarm, writing clears the alarm flag
+    },
+
+    /// Control Register
+    0x10 => reg32 cr {
+      0 => swr,             //= Software reset
+      8 => osce,            //= Oscillator enable
+    },
+
+    /// Status Register
+    0x14 => reg32 sr {
+      0 => tif: ro,         //= Time invalid flag
+      1 => tof: ro,         //= Time overflow flag
+      2 => taf: ro,         //= Time alarm flag
+      4 => tce,             //= Time counter enable
+    },
+
+    /// Interrupt Enable Register
+    0x1c => reg32 ier {
+      0 => tiie,            //= Time invalid interrupt enable
+      1 => toie,            //= Time overflow interrupt enable
+      2 => taie,            //= Time alarm interrupt enable
+      4 => tsie,            //= Time seconds interrupt enable
+    },
+  });
+
+  ioregs!(VBAT = {
+    /// VBAT register file
+    0x0 => reg32 data[8] {
+      0..31 => value,
+    },
+  });
+
+  extern {
+    #[link_name="k20_iomem_RTC"] pub static RTC: RTC;
+    #[link_name="k20_iomem_VBAT"] pub static VBAT: VBAT;
+  }
+}
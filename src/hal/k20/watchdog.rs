@@ -15,10 +15,10 @@
 
 //! Watchdog for Kinetis SIM module.
 
+use hal::reset::ResetCause;
+use hal::watchdog;
 use util::support::nop;
 
-#[path="../../util/ioreg.rs"] mod ioreg;
-
 /// Watchdog state
 #[allow(missing_docs)]
 #[derive(Clone, Copy)]
@@ -57,6 +57,33 @@ pub fn refresh() {
   reg::WDOG.refresh.set_refresh(RefreshSeq2);
 }
 
+/// Watchdog, running from the 1kHz low power oscillator.
+#[derive(Clone, Copy)]
+pub struct Wdog;
+
+impl watchdog::Watchdog for Wdog {
+  /// Starts the watchdog. It must be called soon after reset, as the
+  /// watchdog configuration is write-once outside of the configuration window.
+  fn start(&self, timeout_ms: u32) {
+    unlock();
+    // The prescaler resets to divide by 5, count every LPO tick instead
+    reg::WDOG.presc.set_prescval(0);
+    reg::WDOG.tovalh.set_toval((timeout_ms >> 16) as u16);
+    reg::WDOG.tovall.set_toval(timeout_ms as u16);
+    reg::WDOG.stctrlh
+      .set_clksrc(false)
+      .set_en(true);
+  }
+
+  fn feed(&self) {
+    refresh();
+  }
+
+  fn caused_reset(&self) -> bool {
+    super::reset::reset_cause() == ResetCause::Watchdog
+  }
+}
+
 #[allow(dead_code)]
 mod reg {
   use volatile_cell::VolatileCell;
@@ -67,10 +94,21 @@ mod reg {
     0x0 => reg16 stctrlh
     {
       0 => en,             //= Watchdog enable
+      1 => clksrc,         //= Use the alternate clock instead of the LPO
       4 => allowupdate     //= Enables updates to watchdog write-once registers,
                            //= after the reset-triggered initial configuration window
     },
 
+    /// Time-out Value Register High
+    0x4 => reg16 tovalh {
+      0..15 => toval,      //= High half of the time-out, in watchdog clocks
+    },
+
+    /// Time-out Value Register Low
+    0x6 => reg16 tovall {
+      0..15 => toval,      //= Low half of the time-out, in watchdog clocks
+    },
+
     /// Refresh Register
     0xc => reg16 refresh {
       0..15 => refresh: wo
@@ -89,8 +127,12 @@ mod reg {
       },
     },
 
-  });
+    /// Prescaler Register
+    0x16 => reg16 presc {
+      8..10 => prescval,   //= Watchdog clock divided by prescval + 1
+    },
 
+  });
 
   extern {
     #[link_name="k20_iomem_WDOG"] pub static WDOG: WDOG;
@@ -95,10 +95,10 @@ pub unsafe fn reset_peripheral(peripheral: ResetPeripheral) {
 pub fn init_system_clock() {
   regs::SYSCON().pdruncfg
       .set_sysosc_pd(regs::SYSCON_pdruncfg_sysosc_pd::POWERED);
-  regs::SYSCON().sysoscctrl.ignoring_state()
+  regs::SYSCON_sysoscctrl_Update::from_reset(&regs::SYSCON().sysoscctrl)
       .set_bypass(regs::SYSCON_sysoscctrl_bypass::NOBYPASS)
       .set_freqrange(regs::SYSCON_sysoscctrl_freqrange::LOW);
-  regs::SYSCON().syspllclksel.ignoring_state()
+  regs::SYSCON_syspllclksel_Update::from_reset(&regs::SYSCON().syspllclksel)
       .set_sel(regs::SYSCON_syspllclksel_sel::SYSTEM_OSCILLATOR);
 
   regs::SYSCON().syspllclkuen.ignoring_state()
@@ -118,6 +118,7 @@ pub fn init_system_clock() {
 #[cfg(test)]
 mod test {
   use super::*;
+  use super::super::regs;
   use volatile_cell::{VolatileCellReplayer, set_replayer};
   use expectest::prelude::*;
   use expectest;
@@ -204,4 +205,41 @@ mod test {
 
     expect_replayer_valid!();
   }
+
+  #[test]
+  fn writes_clock_registers_from_reset_values() {
+    init_replayer!();
+
+    // read PDRUNCFG, returns a state left over by a bootloader
+    expect_volatile_read!( 0x4004_8238, 0x0000_0DA0);
+    // write PDRUNCFG, only SYSOSC_PD changes
+    expect_volatile_write!(0x4004_8238, 0x0000_0D80);
+
+    // SYSOSCCTRL and SYSPLLCLKSEL are not read, they are written starting
+    // from their reset values
+    expect_volatile_write!(0x4004_8020, 0x0000_0000);
+    expect_volatile_write!(0x4004_8040, 0x0000_0001);
+
+    expect_volatile_write!(0x4004_8044, 0x0000_0001);
+    expect_volatile_write!(0x4004_8044, 0x0000_0000);
+    expect_volatile_write!(0x4004_8044, 0x0000_0001);
+    expect_volatile_read!( 0x4004_8044, 0x0000_0001);
+
+    init_system_clock();
+
+    expect_replayer_valid!();
+  }
+
+  #[test]
+  fn resets_syscon_registers() {
+    init_replayer!();
+
+    expect_volatile_write!(0x4004_8000, 0x0000_0002);
+    expect_volatile_write!(0x4004_8238, 0x0000_EDF0);
+
+    regs::SYSCON().sysmemremap.reset();
+    regs::SYSCON().pdruncfg.reset();
+
+    expect_replayer_valid!();
+  }
 }
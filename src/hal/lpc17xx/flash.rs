@@ -0,0 +1,167 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Flash programming for LPC17xx, through the In-Application Programming
+//! routines of the boot ROM.
+//!
+//! Pages are the 32 KB sectors from sector 16, at 0x10000; the 4 KB sectors
+//! below usually hold the program. IAP uses the top 32 bytes of the on-chip
+//! RAM, which must be left free, and interrupts are disabled while it runs
+//! since the flash can't be read meanwhile.
+
+use core::intrinsics::{transmute, volatile_load};
+use core::result::Result;
+use core::result::Result::{Ok, Err};
+
+use hal::cortex_m3::irq::NoInterrupts;
+use hal::flash::{self, Error};
+use super::system_clock::system_clock;
+
+/// Address of the IAP entry point, in Thumb mode.
+const IAP_ENTRY: usize = 0x1fff_1ff1;
+
+/// Address of the first page.
+const BASE_ADDRESS: usize = 0x0001_0000;
+
+/// Sector number of the first page.
+const BASE_SECTOR: u32 = 16;
+
+/// Size of a page.
+const PAGE_SIZE: usize = 32 * 1024;
+
+/// Smallest block IAP programs.
+const WRITE_SIZE: usize = 256;
+
+#[repr(u32)]
+#[derive(Clone, Copy)]
+enum Command {
+  PrepareSectors = 50,
+  CopyRamToFlash = 51,
+  EraseSectors = 52,
+}
+
+/// Flash programming through IAP.
+#[derive(Clone, Copy)]
+pub struct Iap {
+  pages: usize,
+}
+
+impl Iap {
+  /// Creates the IAP interface to `pages` 32 KB sectors, 14 on 512 KB parts
+  /// and 6 on 256 KB parts.
+  pub fn new(pages: usize) -> Iap {
+    Iap { pages: pages }
+  }
+
+  fn address(&self, page: usize, offset: usize) -> usize {
+    BASE_ADDRESS + page * PAGE_SIZE + offset
+  }
+
+  /// Runs an IAP command and returns its status.
+  fn call(&self, command: Command, params: [u32; 4]) -> Result<(), Error> {
+    let input = [command as u32, params[0], params[1], params[2], params[3]];
+    let mut output = [0u32; 5];
+    {
+      let _crit = NoInterrupts::new();
+      let iap: extern "C" fn(*const u32, *mut u32) =
+          unsafe { transmute(IAP_ENTRY) };
+      iap(input.as_ptr(), output.as_mut_ptr());
+    }
+
+    match output[0] {
+      0 => Ok(()),
+      2...6 => Err(Error::Alignment),     // address, mapping or count error
+      7 => Err(Error::OutOfRange),        // invalid sector
+      9 => Err(Error::Locked),            // sector not prepared
+      _ => Err(Error::Failed),
+    }
+  }
+
+  /// Allows the next erase or program of `page`.
+  fn prepare(&self, page: usize) -> Result<(), Error> {
+    let sector = BASE_SECTOR + page as u32;
+    self.call(Command::PrepareSectors, [sector, sector, 0, 0])
+  }
+}
+
+impl flash::Flash for Iap {
+  fn page_size(&self) -> usize {
+    PAGE_SIZE
+  }
+
+  fn page_count(&self) -> usize {
+    self.pages
+  }
+
+  fn write_size(&self) -> usize {
+    WRITE_SIZE
+  }
+
+  /// Does nothing, each operation prepares its sector.
+  fn unlock(&self) {
+  }
+
+  /// Does nothing, sectors are locked again after each operation.
+  fn lock(&self) {
+  }
+
+  /// Returns false, code read protection covers the whole flash.
+  fn is_protected(&self, _: usize) -> bool {
+    false
+  }
+
+  fn erase(&self, page: usize) -> Result<(), Error> {
+    if page >= self.pages {
+      return Err(Error::OutOfRange);
+    }
+    let sector = BASE_SECTOR + page as u32;
+    try!(self.prepare(page));
+    self.call(Command::EraseSectors,
+        [sector, sector, system_clock() / 1000, 0])
+  }
+
+  fn program(&self, page: usize, offset: usize, data: &[u8])
+      -> Result<(), Error> {
+    if offset % WRITE_SIZE != 0 || data.len() % WRITE_SIZE != 0 {
+      return Err(Error::Alignment);
+    }
+    if page >= self.pages || offset + data.len() > PAGE_SIZE {
+      return Err(Error::OutOfRange);
+    }
+
+    // IAP copies from word aligned RAM
+    let mut buf = [0u32; WRITE_SIZE / 4];
+    for (i, block) in data.chunks(WRITE_SIZE).enumerate() {
+      for (word, bytes) in buf.iter_mut().zip(block.chunks(4)) {
+        *word = bytes[0] as u32 | (bytes[1] as u32) << 8 |
+            (bytes[2] as u32) << 16 | (bytes[3] as u32) << 24;
+      }
+      try!(self.prepare(page));
+      try!(self.call(Command::CopyRamToFlash, [
+          self.address(page, offset + i * WRITE_SIZE) as u32,
+          buf.as_ptr() as u32,
+          WRITE_SIZE as u32,
+          system_clock() / 1000]));
+    }
+    Ok(())
+  }
+
+  fn read(&self, page: usize, offset: usize, data: &mut [u8]) {
+    let address = self.address(page, offset);
+    for i in 0..data.len() {
+      data[i] = unsafe { volatile_load((address + i) as *const u8) };
+    }
+  }
+}
@@ -17,8 +17,12 @@
 
 pub mod system_clock;
 pub mod peripheral_clock;
+pub mod flash;
 pub mod pin;
+pub mod reset;
+pub mod rtc;
 pub mod pwm;
-// pub mod ssp;
+pub mod ssp;
 pub mod timer;
 pub mod uart;
+pub mod watchdog;
@@ -25,9 +25,6 @@ use super::system_clock::system_clock;
 use self::PeripheralClock::*;
 use self::PeripheralDivisor::*;
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
-
 /// Configures the state of peripheral clock.
 #[allow(missing_docs)]
 #[derive(Clone, Copy)]
@@ -108,16 +105,12 @@ pub enum PeripheralDivisor {
 impl PeripheralClock {
   /// Enables the given peripheral clock.
   pub fn enable(self) {
-    let bit: u32 = (1u32 << (self as usize)) as u32;
-    let val: u32 = reg::PCONP.value();
-    reg::PCONP.set_value(val | bit);
+    reg::PCONP.pconp.set_power_on(self as usize, true);
   }
 
   /// Disables the given peripheral clock.
   pub fn disable(self) {
-    let bit: u32 = !((1u32 << (self as usize)) as u32);
-    let val: u32 = reg::PCONP.value();
-    reg::PCONP.set_value(val & bit);
+    reg::PCONP.pconp.set_power_on(self as usize, false);
   }
 
   /// Returns the clock frequency based on active divisor.
@@ -127,36 +120,33 @@ impl PeripheralClock {
 
   /// Returns the given peripheral clock divisor.
   pub fn get_divisor(self) -> u8 {
-    let (reg, offset) = self.divisor_reg_and_offset();
-    let val = (reg.value() >> (offset as usize)) & 3;
-    match val {
-      1 => 1,
-      2 => 2,
-      0 => 4,
-      3 => match self {
+    use self::reg::PCLKSEL_pclksel_divisor::*;
+
+    let (reg, index) = self.divisor_reg_and_index();
+    match reg.pclksel.divisor(index) {
+      CclkDiv1 => 1,
+      CclkDiv2 => 2,
+      CclkDiv4 => 4,
+      CclkDiv8 => match self {
         CAN1Clock|CAN2Clock => 6,  // TODO(farcaller): wtf is CAN filter?
         _ => 8,
       },
-      _ => unsafe { abort() },
     }
   }
 
   /// Sets the given peripheral clock divisor.
   pub fn set_divisor(self, divisor: u8) {
+    use self::reg::PCLKSEL_pclksel_divisor::*;
+
     self.verify_divisor(divisor);
-    let (reg, offset) = self.divisor_reg_and_offset();
-    let divisor_value: u8 = match divisor {
-      1   => 1,
-      2   => 2,
-      4   => 0,
-      8|6 => 3,
+    let (reg, index) = self.divisor_reg_and_index();
+    reg.pclksel.set_divisor(index, match divisor {
+      1   => CclkDiv1,
+      2   => CclkDiv2,
+      4   => CclkDiv4,
+      8|6 => CclkDiv8,
       _   => unsafe { abort() },
-    };
-
-    let bits: u32 = (divisor_value as u32) << (offset as usize);
-    let mask: u32 = !((3u32 << (offset as usize)) as u32);
-    let val: u32 = reg.value();
-    reg.set_value(val & mask | bits);
+    });
   }
 
   fn verify_divisor(self, divisor: u8) {
@@ -202,16 +192,17 @@ impl PeripheralClock {
     }
   }
 
-  fn divisor_reg_and_offset(self) -> (&'static reg::PCLKSEL, u32) {
-    match self.to_divisor() {
+  fn divisor_reg_and_index(self) -> (&'static reg::PCLKSEL, usize) {
+    let divisor = self.to_divisor();
+    match divisor {
       WDTDivisor|TIMER0Divisor|TIMER1Divisor|UART0Divisor|UART1Divisor|
       PWM1Divisor|I2C0Divisor|SPIDivisor|SSP1Divisor|DACDivisor|ADCDivisor|
-      CAN1Divisor|CAN2Divisor|ACFDivisor => (&reg::PCLKSEL0, self as u32),
+      CAN1Divisor|CAN2Divisor|ACFDivisor => (&reg::PCLKSEL0, divisor as usize / 2),
 
       QEIDivisor|GPIOINTDivisor|PCBDivisor|I2C1Divisor|SSP0Divisor|
       TIMER2Divisor|TIMER3Divisor|UART2Divisor|UART3Divisor|I2C2Divisor|
       I2SDivisor|RITDivisor|SYSCONDivisor|
-      MCDivisor => (&reg::PCLKSEL1, self as u32 - 32),
+      MCDivisor => (&reg::PCLKSEL1, (divisor as usize - 32) / 2),
     }
   }
 }
@@ -219,11 +210,22 @@ impl PeripheralClock {
 mod reg {
   use volatile_cell::VolatileCell;
 
-  ioreg_old!(PCONP: u32, value);
-  reg_rw!(PCONP, u32, value, set_value, value);
-
-  ioreg_old!(PCLKSEL: u32, value);
-  reg_rw!(PCLKSEL, u32, value, set_value, value);
+  ioregs!(PCONP = {
+    0x0 => reg32 pconp {   //! Power control for peripherals
+      0..31 => power_on[32],  //= Peripheral power/clock enable, indexed by PeripheralClock
+    }
+  });
+
+  ioregs!(PCLKSEL = {
+    0x0 => reg32 pclksel { //! Peripheral clock selection
+      0..31 => divisor[16] {  //! Peripheral clock divisor, indexed by PeripheralDivisor / 2
+        0 => CclkDiv4,
+        1 => CclkDiv1,
+        2 => CclkDiv2,
+        3 => CclkDiv8,        //= CCLK / 6 for CAN peripherals
+      }
+    }
+  });
 
   extern {
     #[link_name="lpc17xx_iomem_PCONP"] pub static PCONP: PCONP;
@@ -23,10 +23,12 @@ on the package.
 use core::intrinsics::abort;
 use core::option::Option;
 
+use super::peripheral_clock::PeripheralClock;
+use hal::cortex_m3::nvic;
+use hal::pin::{GpioPull, GpioSpeed, GpioTrigger, InterruptHandler};
+
 use self::Port::*;
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
 #[path="../../util/wait_for.rs"]
 #[macro_use] mod wait_for;
 
@@ -61,6 +63,12 @@ pub enum Mode {
   PullDown = 3,
 }
 
+/// IRQ shared by GPIO interrupts and external interrupt 3.
+const EINT3_IRQ: usize = 21;
+
+/// Interrupt handlers of port 0 and port 2 pins.
+static mut INTERRUPT_HANDLERS: [Option<InterruptHandler>; 64] = [None; 64];
+
 /// Structure to describe the location of a pin
 #[derive(Clone, Copy)]
 pub struct Pin {
@@ -86,14 +94,8 @@ impl Pin {
 
   fn setup_regs(&self, function: Function,
       gpiodir: Option<::hal::pin::GpioDirection>) {
-    let (offset, reg) = self.get_pinsel_reg_and_offset();
-
-    let fun_bits: u32  = (function as u32) << ((offset as usize) * 2);
-    let mask_bits: u32 = !(3u32 << ((offset as usize) * 2));
-
-    let val: u32 = reg.value();
-    let new_val = (val & mask_bits) | fun_bits;
-    reg.set_value(new_val);
+    let (index, reg) = self.get_pinsel_reg_and_index();
+    reg.pinsel.set_function(index as usize, function as u32);
 
     match function {
       Function::Gpio => (self as &::hal::pin::Gpio).set_direction(gpiodir.unwrap()),
@@ -106,12 +108,11 @@ impl Pin {
   }
 
   fn set_mode(&self, mode: Mode) {
-    let (offset, reg) = self.get_pimode_reg_and_offset();
-    let value = reg.value() | (mode as u32) << offset;
-    reg.set_value(value)
+    let (index, reg) = self.get_pinmode_reg_and_index();
+    reg.pinmode.set_mode(index as usize, mode as u32);
   }
 
-  fn gpioreg(&self) -> &reg::Gpio {
+  fn gpioreg(&self) -> &reg::GPIO {
     match self.port {
       Port0 => &reg::GPIO_0,
       Port1 => &reg::GPIO_1,
@@ -121,7 +122,17 @@ impl Pin {
     }
   }
 
-  fn get_pinsel_reg_and_offset(&self) -> (u8, &reg::PINSEL) {
+  /// Returns the interrupt registers and handler slot, only ports 0 and 2
+  /// support interrupts.
+  fn intreg_and_slot(&self) -> (&reg::GPIOINT, usize) {
+    match self.port {
+      Port0 => (&reg::GPIOINT_0, self.pin as usize),
+      Port2 => (&reg::GPIOINT_2, 32 + self.pin as usize),
+      _     => unsafe { abort() },
+    }
+  }
+
+  fn get_pinsel_reg_and_index(&self) -> (u8, &reg::PINSEL) {
     match self.port {
       Port0 => match self.pin {
         0...15  => (self.pin,    &reg::PINSEL0),
@@ -148,39 +159,47 @@ impl Pin {
     }
   }
 
-  fn get_pimode_reg_and_offset(&self) -> (u8, &reg::PINMODE) {
+  fn get_pinmode_reg_and_index(&self) -> (u8, &reg::PINMODE) {
     match self.port {
       Port0 => match self.pin {
-        0...11  => (self.pin*2, &reg::PINMODE0),
-        15      => (self.pin*2, &reg::PINMODE0),
-        16...26 => ((self.pin-16)*2, &reg::PINMODE1),
+        0...11  => (self.pin,    &reg::PINMODE0),
+        15      => (self.pin,    &reg::PINMODE0),
+        16...26 => (self.pin-16, &reg::PINMODE1),
         _       => unsafe { abort() },
       },
       Port1 => match self.pin {
-        0...1   => (self.pin*2, &reg::PINMODE2),
-        4       => (self.pin*2, &reg::PINMODE2),
-        8...10  => (self.pin*2, &reg::PINMODE2),
-        14...15 => (self.pin*2, &reg::PINMODE2),
-        16...31 => ((self.pin-16)*2, &reg::PINMODE3),
+        0...1   => (self.pin,    &reg::PINMODE2),
+        4       => (self.pin,    &reg::PINMODE2),
+        8...10  => (self.pin,    &reg::PINMODE2),
+        14...15 => (self.pin,    &reg::PINMODE2),
+        16...31 => (self.pin-16, &reg::PINMODE3),
         _      => unsafe { abort() },
       },
       Port2 => match self.pin {
-        0...13 => (self.pin*2, &reg::PINMODE4),
+        0...13 => (self.pin, &reg::PINMODE4),
         _      => unsafe { abort() },
       },
       Port3 => match self.pin {
-        25 => (18, &reg::PINMODE7),
-        26 => (20, &reg::PINMODE7),
-        _  => unsafe { abort() },
+        25|26 => (self.pin-16, &reg::PINMODE7),
+        _     => unsafe { abort() },
       },
       Port4 => match self.pin {
-        28 => (24, &reg::PINMODE9),
-        29 => (26, &reg::PINMODE9),
-        _  => unsafe { abort() },
+        28|29 => (self.pin-16, &reg::PINMODE9),
+        _     => unsafe { abort() },
       },
     }
   }
 
+  fn odreg(&self) -> &reg::PINMODE_OD {
+    match self.port {
+      Port0 => &reg::PINMODE_OD0,
+      Port1 => &reg::PINMODE_OD1,
+      Port2 => &reg::PINMODE_OD2,
+      Port3 => &reg::PINMODE_OD3,
+      Port4 => &reg::PINMODE_OD4,
+    }
+  }
+
   /// Get adc channel number
   fn adc_channel(&self) -> Option<u8> {
     match self.port {
@@ -201,17 +220,9 @@ impl Pin {
   }
 
   fn setup_adc(&self) {
-    // ensure power is turned on
-    let pconp = &reg::PCONP;
-    let pconp_value = pconp.value();
-    pconp.set_value(pconp_value | (1 << 12));
-
-    // set PCLK of ADC to /1
-    let pclksel0 = &reg::PCLKSEL0;
-    let mut pclksel0_val: u32 = pclksel0.value();
-    pclksel0_val &= !(0x3 << 24);
-    pclksel0_val |= 0x1 << 24;
-    pclksel0.set_value(pclksel0_val);
+    // ensure power is turned on and set PCLK of ADC to /1
+    PeripheralClock::ADCClock.enable();
+    PeripheralClock::ADCClock.set_divisor(1);
 
     fn div_round_up(x: u32, y: u32) -> u32 {
       (x + (y - 1)) / y
@@ -220,14 +231,13 @@ impl Pin {
     let max_adc_clk = 13000000;
     let clkdiv = div_round_up(pclk, max_adc_clk);
 
-    let cr = (0 << 0)      // SEL: 0 = no channels selected
-           | (clkdiv << 8) // CLKDIV: PCLK max ~= 25MHz, /25 to give safe 1MHz at fastest
-           | (0 << 16)     // BURST: 0 = software control
-           | (0 << 17)     // CLKS: not applicable
-           | (1 << 21)     // PDN: 1 = operational
-           | (0 << 24)     // START: 0 = no start
-           | (0 << 27);    // EDGE: not applicable
-    &reg::ADC.set_CR(cr);
+    reg::ADC.cr
+      .set_sel(0)                   // no channels selected
+      .set_clkdiv(clkdiv)           // PCLK max ~= 25MHz, /25 to give safe 1MHz at fastest
+      .set_burst(false)             // software control
+      .set_pdn(true)                // operational
+      .set_start(reg::ADC_cr_start::NoStart)
+      .set_edge(false);             // not applicable
 
     self.set_mode(Mode::Floating);
   }
@@ -236,82 +246,142 @@ impl Pin {
 impl ::hal::pin::Gpio for Pin {
   /// Sets output GPIO value to high.
   fn set_high(&self) {
-    self.gpioreg().set_FIOSET(1 << (self.pin as usize));
+    self.gpioreg().fioset.set_drive_high(self.pin as usize, true);
   }
 
   /// Sets output GPIO value to low.
   fn set_low(&self) {
-    self.gpioreg().set_FIOCLR(1 << (self.pin as usize));
+    self.gpioreg().fioclr.set_drive_low(self.pin as usize, true);
   }
 
   /// Returns input GPIO level.
   fn level(&self) -> ::hal::pin::GpioLevel {
-    let bit: u32 = 1 << (self.pin as usize);
-    let reg = self.gpioreg();
-
-    match reg.FIOPIN() & bit {
-      0 => ::hal::pin::Low,
-      _ => ::hal::pin::High,
+    match self.gpioreg().fiopin.level(self.pin as usize) {
+      false => ::hal::pin::Low,
+      true  => ::hal::pin::High,
     }
   }
 
   /// Sets output GPIO direction.
   fn set_direction(&self, new_mode: ::hal::pin::GpioDirection) {
-    let bit: u32 = 1 << (self.pin as usize);
-    let mask: u32 = !bit;
-    let reg = self.gpioreg();
-    let val: u32 = reg.FIODIR();
-    let new_val: u32 = match new_mode {
-      ::hal::pin::In  => val & mask,
-      ::hal::pin::Out => (val & mask) | bit,
+    self.gpioreg().fiodir.set_dir(self.pin as usize, match new_mode {
+      ::hal::pin::In  => reg::GPIO_fiodir_dir::Input,
+      ::hal::pin::Out => reg::GPIO_fiodir_dir::Output,
+    });
+  }
+
+}
+
+impl ::hal::pin::PinConfig for Pin {
+  fn set_pull(&self, pull: GpioPull) {
+    self.set_mode(match pull {
+      GpioPull::Floating => Mode::Floating,
+      GpioPull::PullUp   => Mode::PullUp,
+      GpioPull::PullDown => Mode::PullDown,
+    });
+  }
+
+  fn set_open_drain(&self, enabled: bool) {
+    self.odreg().pinmode_od.set_open_drain(self.pin as usize, enabled);
+  }
+
+  /// The pins have no slew rate control, the speed is ignored.
+  fn set_speed(&self, _speed: GpioSpeed) {
+  }
+}
+
+impl ::hal::pin::InterruptPin for Pin {
+  /// Enables the pin interrupt, only edge triggers are supported.
+  fn enable_interrupt(&self, trigger: GpioTrigger) {
+    let (rising, falling) = match trigger {
+      GpioTrigger::RisingEdge  => (true, false),
+      GpioTrigger::FallingEdge => (false, true),
+      GpioTrigger::BothEdges   => (true, true),
+      _                        => unsafe { abort() },
     };
+    let (reg, _) = self.intreg_and_slot();
+    reg.enr.set_enabled(self.pin as usize, rising);
+    reg.enf.set_enabled(self.pin as usize, falling);
+    nvic::enable_irq(EINT3_IRQ);
+  }
+
+  fn disable_interrupt(&self) {
+    let (reg, _) = self.intreg_and_slot();
+    reg.enr.set_enabled(self.pin as usize, false);
+    reg.enf.set_enabled(self.pin as usize, false);
+  }
 
-    reg.set_FIODIR(new_val);
+  fn is_interrupt_pending(&self) -> bool {
+    let (reg, _) = self.intreg_and_slot();
+    reg.statr.pending(self.pin as usize) || reg.statf.pending(self.pin as usize)
   }
 
+  fn clear_interrupt(&self) {
+    let (reg, _) = self.intreg_and_slot();
+    reg.clr.set_clear(self.pin as usize, true);
+  }
+
+  fn set_interrupt_handler(&self, handler: InterruptHandler) {
+    let (_, slot) = self.intreg_and_slot();
+    unsafe { INTERRUPT_HANDLERS[slot] = Some(handler) };
+  }
+}
+
+/// Calls the handlers of pins with pending interrupts and clears them. To be
+/// called from `isr_eint_3`.
+pub fn dispatch_interrupts() {
+  for (port, reg) in [&reg::GPIOINT_0, &reg::GPIOINT_2].iter().enumerate() {
+    let pending = reg.statr.get().raw() | reg.statf.get().raw();
+    for pin in 0..32 {
+      if pending & (1 << pin) == 0 {
+        continue;
+      }
+      reg.clr.set_clear(pin, true);
+      match unsafe { INTERRUPT_HANDLERS[port * 32 + pin] } {
+        Some(handler) => handler(),
+        None => (),
+      }
+    }
+  }
 }
 
 impl ::hal::pin::Adc for Pin {
   /// Read analog input value of pin
   fn read(&self) -> u32 {
     let adc = &reg::ADC;
-    let channel = self.adc_channel().unwrap();
-    let mut cr = adc.CR();
-    cr &= !(0xFF as u32);
-    cr |= 1 << channel;
-    cr |= (1 << 24) as u32;
-    adc.set_CR(cr);
-
-    wait_for!((adc.STAT() & (1 << channel)) != 0);
-
-    let data = match channel {
-      0 => adc.DR0(),
-      1 => adc.DR1(),
-      2 => adc.DR2(),
-      3 => adc.DR3(),
-      4 => adc.DR4(),
-      5 => adc.DR5(),
-      6 => adc.DR6(),
-      7 => adc.DR7(),
-      _ => unsafe { abort() },
-    };
+    let channel = self.adc_channel().unwrap() as usize;
+    adc.cr
+      .set_sel(1 << channel)
+      .set_start(reg::ADC_cr_start::StartNow);
+
+    wait_for!(adc.stat.done(channel));
 
-    adc.set_CR((adc.CR() as u32) & !(1 << 24));
-    (data >> 4) & 0xFFF // 12 bit range
+    let data = adc.dr[channel].result();
+
+    adc.cr.set_start(reg::ADC_cr_start::NoStart);
+    data // 12 bit range
   }
 }
 
 /// Sets the state of trace port interface.
 pub fn set_trace_port_interface_enabled(enabled: bool) {
-  let value: u32 = if enabled { 0b1000 } else { 0 };
-  reg::PINSEL10.set_value(value);
+  reg::PINSEL10.pinsel10.set_gpio_trace(enabled);
 }
 
 mod reg {
   use volatile_cell::VolatileCell;
 
-  ioreg_old!(PINSEL: u32, value);
-  reg_rw!(PINSEL, u32, value, set_value, value);
+  ioregs!(PINSEL = {
+    0x0 => reg32 pinsel {       //! Pin function select
+      0..31 => function[16],    //= Pin function, see `Function`
+    }
+  });
+
+  ioregs!(PINSEL10 = {
+    0x0 => reg32 pinsel10 {     //! Trace port function select
+      3 => gpio_trace,          //= Trace port interface enabled on P2.2-P2.6
+    }
+  });
 
   extern {
     #[link_name="lpc17xx_iomem_PINSEL0"]  pub static PINSEL0:  PINSEL;
@@ -321,11 +391,15 @@ mod reg {
     #[link_name="lpc17xx_iomem_PINSEL4"]  pub static PINSEL4:  PINSEL;
     #[link_name="lpc17xx_iomem_PINSEL7"]  pub static PINSEL7:  PINSEL;
     #[link_name="lpc17xx_iomem_PINSEL9"]  pub static PINSEL9:  PINSEL;
-    #[link_name="lpc17xx_iomem_PINSEL10"] pub static PINSEL10: PINSEL;
+    #[link_name="lpc17xx_iomem_PINSEL10"] pub static PINSEL10: PINSEL10;
   }
 
-  ioreg_old!(PINMODE: u32, value);
-  reg_rw!(PINMODE, u32, value, set_value, value);
+  ioregs!(PINMODE = {
+    0x0 => reg32 pinmode {      //! Pin mode select
+      0..31 => mode[16],        //= Pull-up/pull-down resistor mode, see `Mode`
+    }
+  });
+
   extern {
     #[link_name="lpc17xx_iomem_PINMODE0"] pub static PINMODE0: PINMODE;
     #[link_name="lpc17xx_iomem_PINMODE1"] pub static PINMODE1: PINMODE;
@@ -336,44 +410,111 @@ mod reg {
     #[link_name="lpc17xx_iomem_PINMODE9"] pub static PINMODE9: PINMODE;
   }
 
-  ioreg_old!(Gpio: u32, FIODIR, _r0, _r1, _r2, FIOMASK, FIOPIN, FIOSET, FIOCLR);
-  reg_rw!(Gpio, u32, FIODIR,  set_FIODIR,  FIODIR);
-  reg_rw!(Gpio, u32, FIOMASK, set_FIOMASK, FIOMASK);
-  reg_rw!(Gpio, u32, FIOPIN,  set_FIOPIN,  FIOPIN);
-  reg_rw!(Gpio, u32, FIOSET,  set_FIOSET,  FIOSET);
-  reg_rw!(Gpio, u32, FIOCLR,  set_FIOCLR,  FIOCLR);
+  ioregs!(PINMODE_OD = {
+    0x0 => reg32 pinmode_od {   //! Open drain mode control
+      0..31 => open_drain[32],  //= Pin is in open drain mode
+    }
+  });
+
+  extern {
+    #[link_name="lpc17xx_iomem_PINMODE_OD0"] pub static PINMODE_OD0: PINMODE_OD;
+    #[link_name="lpc17xx_iomem_PINMODE_OD1"] pub static PINMODE_OD1: PINMODE_OD;
+    #[link_name="lpc17xx_iomem_PINMODE_OD2"] pub static PINMODE_OD2: PINMODE_OD;
+    #[link_name="lpc17xx_iomem_PINMODE_OD3"] pub static PINMODE_OD3: PINMODE_OD;
+    #[link_name="lpc17xx_iomem_PINMODE_OD4"] pub static PINMODE_OD4: PINMODE_OD;
+  }
+
+  ioregs!(GPIO = {
+    0x00 => reg32 fiodir {      //! Fast GPIO port direction control
+      0..31 => dir[32] {
+        0 => Input,
+        1 => Output,
+      }
+    },
+    0x10 => reg32 fiomask {     //! Fast GPIO mask
+      0..31 => masked[32],
+    },
+    0x14 => reg32 fiopin {      //! Fast GPIO pin value
+      0..31 => level[32],
+    },
+    0x18 => reg32 fioset {      //! Fast GPIO output set
+      0..31 => drive_high[32] : wo,
+    },
+    0x1c => reg32 fioclr {      //! Fast GPIO output clear
+      0..31 => drive_low[32] : wo,
+    },
+  });
 
   extern {
-    #[link_name="lpc17xx_iomem_GPIO0"] pub static GPIO_0: Gpio;
-    #[link_name="lpc17xx_iomem_GPIO1"] pub static GPIO_1: Gpio;
-    #[link_name="lpc17xx_iomem_GPIO2"] pub static GPIO_2: Gpio;
-    #[link_name="lpc17xx_iomem_GPIO3"] pub static GPIO_3: Gpio;
-    #[link_name="lpc17xx_iomem_GPIO4"] pub static GPIO_4: Gpio;
+    #[link_name="lpc17xx_iomem_GPIO0"] pub static GPIO_0: GPIO;
+    #[link_name="lpc17xx_iomem_GPIO1"] pub static GPIO_1: GPIO;
+    #[link_name="lpc17xx_iomem_GPIO2"] pub static GPIO_2: GPIO;
+    #[link_name="lpc17xx_iomem_GPIO3"] pub static GPIO_3: GPIO;
+    #[link_name="lpc17xx_iomem_GPIO4"] pub static GPIO_4: GPIO;
   }
 
+  ioregs!(GPIOINT = {
+    0x00 => reg32 statr {       //! GPIO interrupt status for rising edge
+      0..31 => pending[32] : ro,
+    },
+    0x04 => reg32 statf {       //! GPIO interrupt status for falling edge
+      0..31 => pending[32] : ro,
+    },
+    0x08 => reg32 clr {         //! GPIO interrupt clear
+      0..31 => clear[32] : wo,
+    },
+    0x0c => reg32 enr {         //! GPIO interrupt enable for rising edge
+      0..31 => enabled[32],
+    },
+    0x10 => reg32 enf {         //! GPIO interrupt enable for falling edge
+      0..31 => enabled[32],
+    },
+  });
 
-  ioreg_old!(PCONP: u32, value);
-  ioreg_old!(PCLKSEL0: u32, value);
-  reg_rw!(PCONP, u32, value, set_value, value);
-  reg_rw!(PCLKSEL0, u32, value, set_value, value);
   extern {
-    #[link_name="lpc17xx_iomem_PCONP"]  pub static PCONP:  PCONP;
-    #[link_name="lpc17xx_iomem_PCLKSEL0"]  pub static PCLKSEL0: PCLKSEL0;
+    #[link_name="lpc17xx_iomem_GPIOINT0"] pub static GPIOINT_0: GPIOINT;
+    #[link_name="lpc17xx_iomem_GPIOINT2"] pub static GPIOINT_2: GPIOINT;
   }
 
-  ioreg_old!(ADC: u32, CR, GDR, pad_0, INTEN, DR0, DR1, DR2, DR3, DR4, DR5, DR6, DR7, STAT, TRM);
-  reg_rw!(ADC, u32, CR, set_CR, CR);
-  reg_r!(ADC, u32, GDR, GDR);
-  reg_rw!(ADC, u32, INTEN, set_INTEN, INTEN);
-  reg_rw!(ADC, u32, DR0, set_DR0, DR0);
-  reg_rw!(ADC, u32, DR1, set_DR1, DR1);
-  reg_rw!(ADC, u32, DR2, set_DR2, DR2);
-  reg_rw!(ADC, u32, DR3, set_DR3, DR3);
-  reg_rw!(ADC, u32, DR4, set_DR4, DR4);
-  reg_rw!(ADC, u32, DR5, set_DR5, DR5);
-  reg_rw!(ADC, u32, DR6, set_DR6, DR6);
-  reg_rw!(ADC, u32, DR7, set_DR7, DR7);
-  reg_rw!(ADC, u32, STAT, set_STAT, STAT);
+  ioregs!(ADC = {
+    0x00 => reg32 cr {          //! A/D control register
+      7..0   => sel,            //= Channels to be sampled
+      15..8  => clkdiv,         //= ADC clock is PCLK / (clkdiv + 1)
+      16     => burst,          //= Repeated conversions in hardware
+      21     => pdn,            //= ADC is operational
+      26..24 => start {         //! Conversion start trigger
+        0 => NoStart,
+        1 => StartNow,
+        2 => StartOnEint0,
+        3 => StartOnCap01,
+        4 => StartOnMat01,
+        5 => StartOnMat03,
+        6 => StartOnMat10,
+        7 => StartOnMat11,
+      },
+      27     => edge,           //= Start on falling edge of the selected trigger
+    },
+    0x04 => reg32 gdr {         //! A/D global data register
+      15..4  => result : ro,
+      26..24 => channel : ro,
+      30     => overrun : ro,
+      31     => done : ro,
+    },
+    0x0c => reg32 inten {       //! A/D interrupt enable register
+      0..7 => channel_int[8],
+      8    => global_int,
+    },
+    0x10 => reg32 dr[8] {       //! A/D channel data registers
+      15..4 => result : ro,
+      30    => overrun : ro,
+      31    => done : ro,
+    },
+    0x30 => reg32 stat {        //! A/D status register
+      0..7  => done[8] : ro,
+      8..15 => overrun[8] : ro,
+      16    => adint : ro,
+    },
+  });
 
   extern {
     #[link_name="lpc17xx_iomem_ADC"]  pub static ADC:  ADC;
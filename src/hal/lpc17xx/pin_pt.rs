@@ -17,10 +17,11 @@ use std::rc::Rc;
 use syntax::ext::base::ExtCtxt;
 
 use builder::{Builder, TokenString, add_node_dependency};
+use gpio_pt;
 use node;
 use super::pinmap;
 
-pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
+pub fn attach(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
   node.materializer.set(Some(verify as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
   for port_node in node.subnodes().iter() {
     port_node.materializer.set(Some(verify as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
@@ -29,6 +30,7 @@ pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
       pin_node.materializer.set(Some(build_pin as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
       add_node_dependency(port_node, pin_node);
       super::add_node_dependency_on_clock(builder, pin_node);
+      gpio_pt::claim_gpio_pin(builder, cx, pin_node);
     }
   }
 }
@@ -133,11 +135,16 @@ fn build_pin(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
           $direction);
   ).unwrap();
   builder.add_main_statement(st);
+
+  // All GPIO interrupts are routed to EINT3
+  let gpio_node = port_node.parent.clone().unwrap().upgrade().unwrap();
+  gpio_pt::build_interrupt(builder, cx, &node, &gpio_node, "eint3");
 }
 
 #[cfg(test)]
 mod test {
   use builder::Builder;
+  use builder::resources::Resource;
   use test_helpers::{assert_equal_source, with_parsed};
 
   #[test]
@@ -205,4 +212,65 @@ mod test {
                core::option::Option::None);");
     });
   }
+
+  #[test]
+  fn builds_interrupt_gpio() {
+    with_parsed("
+      gpio {
+        2 {
+          button@10 {
+            direction = \"in\";
+            interrupt = \"falling\";
+            interrupt_handler = \"on_button\";
+          }
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_pin(&mut builder, cx, pt.get_by_name("button").unwrap());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 3);
+
+      assert_equal_source(&builder.main_stmts()[1],
+          "zinc::hal::pin::InterruptPin::set_interrupt_handler(
+               &button, on_button);");
+      assert_equal_source(&builder.main_stmts()[2],
+          "zinc::hal::pin::InterruptPin::enable_interrupt(
+               &button, zinc::hal::pin::GpioTrigger::FallingEdge);");
+      assert!(builder.resources().owner_of(&Resource::Irq("eint3".to_string()))
+          == Some("gpio".to_string()));
+    });
+  }
+
+  #[test]
+  fn builds_interrupts_sharing_eint3() {
+    with_parsed("
+      gpio {
+        0 {
+          up@10 { direction = \"in\"; interrupt = \"falling\"; }
+        }
+        2 {
+          down@11 { direction = \"in\"; interrupt = \"rising\"; }
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_pin(&mut builder, cx, pt.get_by_name("up").unwrap());
+      super::build_pin(&mut builder, cx, pt.get_by_name("down").unwrap());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 4);
+    });
+  }
+
+  #[test]
+  fn fails_to_build_unknown_interrupt_trigger() {
+    with_parsed("
+      gpio {
+        2 {
+          button@10 { direction = \"in\"; interrupt = \"sideways\"; }
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_pin(&mut builder, cx, pt.get_by_name("button").unwrap());
+      assert!(unsafe{*failed} == true);
+    });
+  }
 }
@@ -22,6 +22,7 @@ use node;
 mod system_clock_pt;
 mod timer_pt;
 mod pin_pt;
+mod ssp_pt;
 mod uart_pt;
 
 mod pinmap;
@@ -35,6 +36,7 @@ pub fn attach(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
       "clock" => system_clock_pt::attach(builder, cx, sub.clone()),
       "timer" => timer_pt::attach(builder, cx, sub.clone()),
       "uart"  => uart_pt::attach(builder, cx, sub.clone()),
+      "ssp"   => ssp_pt::attach(builder, cx, sub.clone()),
       "gpio"  => pin_pt::attach(builder, cx, sub.clone()),
       _ => (),
     }
@@ -43,7 +45,7 @@ pub fn attach(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
 
 fn verify(_: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
   node.expect_no_attributes(cx);
-  node.expect_subnodes(cx, &["clock", "timer", "uart", "gpio"]);
+  node.expect_subnodes(cx, &["clock", "timer", "uart", "ssp", "gpio"]);
 }
 
 pub fn add_node_dependency_on_clock(builder: &mut Builder,
@@ -172,4 +174,213 @@ mod test {
           }");
     });
   }
+
+  #[test]
+  fn fails_to_build_pin_shared_by_uart_and_gpio_driver() {
+    with_parsed("
+      lpc17xx@mcu {
+        clock {
+          source = \"main-oscillator\";
+          source_frequency = 12_000_000;
+          pll {
+            m = 50;
+            n = 3;
+            divisor = 4;
+          }
+        }
+
+        timer {
+          timer@1 {
+            counter = 25;
+            divisor = 4;
+          }
+        }
+
+        uart {
+          uart@0 {
+            baud_rate = 115200;
+            mode = \"8N1\";
+            tx = &uart_tx;
+            rx = &uart_rx;
+          }
+        }
+
+        gpio {
+          0 {
+            uart_tx@2;
+            uart_rx@3;
+          }
+        }
+      }
+
+      drivers {
+        dht@dht22 {
+          pin = &uart_tx;
+          timer = &timer;
+        }
+      }
+
+      os {
+        single_task {
+          loop = \"run\";
+          args {
+            uart = &uart;
+            dht = &dht;
+          }
+        }
+      }", |cx, failed, pt| {
+      Builder::build(cx, pt);
+      assert!(unsafe{*failed} == true);
+    });
+  }
+
+  #[test]
+  fn fails_to_build_uart_on_plain_gpio_pin() {
+    with_parsed("
+      lpc17xx@mcu {
+        clock {
+          source = \"main-oscillator\";
+          source_frequency = 12_000_000;
+          pll {
+            m = 50;
+            n = 3;
+            divisor = 4;
+          }
+        }
+
+        uart {
+          uart@0 {
+            baud_rate = 115200;
+            mode = \"8N1\";
+            tx = &uart_tx;
+            rx = &uart_rx;
+          }
+        }
+
+        gpio {
+          0 {
+            uart_tx@2 { direction = \"out\"; }
+            uart_rx@3;
+          }
+        }
+      }
+
+      os {
+        single_task {
+          loop = \"run\";
+          args {
+            uart = &uart;
+            tx = &uart_tx;
+          }
+        }
+      }", |cx, failed, pt| {
+      Builder::build(cx, pt);
+      assert!(unsafe{*failed} == true);
+    });
+  }
+
+  #[test]
+  fn builds_c12332_on_ssp() {
+    with_parsed("
+      lpc17xx@mcu {
+        clock {
+          source = \"main-oscillator\";
+          source_frequency = 12_000_000;
+          pll {
+            m = 50;
+            n = 3;
+            divisor = 4;
+          }
+        }
+
+        timer {
+          timer@1 {
+            counter = 25;
+            divisor = 4;
+          }
+        }
+
+        ssp {
+          lcd_spi@1 {
+            frequency = 1_000_000;
+            mode = 3;
+            sclk = &lcd_sclk;
+            mosi = &lcd_mosi;
+          }
+        }
+
+        gpio {
+          0 {
+            lcd_sclk@7;
+            lcd_mosi@9;
+            lcd_reset@6;
+            lcd_cs@18;
+          }
+          1 {
+            lcd_dc@31;
+          }
+        }
+      }
+
+      drivers {
+        lcd@c12332 {
+          spi = &lcd_spi;
+          timer = &timer;
+          dc = &lcd_dc;
+          cs = &lcd_cs;
+          reset = &lcd_reset;
+        }
+      }
+
+      os {
+        single_task {
+          loop = \"run\";
+          args {
+            lcd = &lcd;
+          }
+        }
+      }", |cx, failed, pt| {
+      let builder = Builder::build(cx, pt.clone());
+      assert!(builder.is_some());
+      assert!(unsafe{*failed} == false);
+
+      let sclk_node = pt.get_by_name("lcd_sclk").unwrap();
+      assert!(sclk_node.get_string_attr("function").unwrap() == "sck1".to_string());
+      let dc_node = pt.get_by_name("lcd_dc").unwrap();
+      assert!(dc_node.get_string_attr("direction").unwrap() == "out".to_string());
+    });
+  }
+
+  #[test]
+  fn warns_about_unreferenced_timer() {
+    with_parsed("
+      lpc17xx@mcu {
+        clock {
+          source = \"main-oscillator\";
+          source_frequency = 12_000_000;
+          pll {
+            m = 50;
+            n = 3;
+            divisor = 4;
+          }
+        }
+
+        timer {
+          timer@1 {
+            counter = 25;
+            divisor = 4;
+          }
+        }
+      }
+
+      os {
+        single_task {
+          loop = \"run\";
+        }
+      }", |cx, failed, pt| {
+      let builder = Builder::build(cx, pt);
+      assert!(builder.is_some());
+      assert!(unsafe{*failed} == true);
+    });
+  }
 }
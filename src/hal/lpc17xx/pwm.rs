@@ -21,8 +21,6 @@ use core::intrinsics::abort;
 use self::PWMChannel::*;
 
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
 
 const PWM_CLOCK_DIVISOR: u8 = 4;
 
@@ -0,0 +1,59 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reset cause for LPC17xx.
+
+use hal::reset::{ResetCause, ResetFlags};
+
+/// Returns the cause of the last reset. The LPC17xx has no flag for software
+/// and lockup resets, they are reported as `Unknown`.
+pub fn reset_cause() -> ResetCause {
+  let rsid = reg::RSID.rsid.get();
+  ResetFlags {
+    power_on: rsid.por(),
+    brown_out: rsid.bodr(),
+    watchdog: rsid.wdtr(),
+    software: false,
+    pin: rsid.extr(),
+    lockup: false,
+  }.cause()
+}
+
+/// Clears the reset flags.
+pub fn clear_reset_cause() {
+  reg::RSID.rsid
+    .clear_por()
+    .clear_extr()
+    .clear_wdtr()
+    .clear_bodr();
+}
+
+mod reg {
+  use volatile_cell::VolatileCell;
+  use core::ops::Drop;
+
+  ioregs!(RSID = {
+    0x0 => reg32 rsid {         //! Reset source identification
+      0 => por : set_to_clear,  //= Power-on reset
+      1 => extr : set_to_clear, //= External reset pin
+      2 => wdtr : set_to_clear, //= Watchdog time-out
+      3 => bodr : set_to_clear, //= Brown-out detection
+    }
+  });
+
+  extern {
+    #[link_name="lpc17xx_iomem_RSID"] pub static RSID: RSID;
+  }
+}
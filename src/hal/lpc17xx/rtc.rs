@@ -0,0 +1,207 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Real-time clock for LPC17xx.
+//!
+//! The RTC runs from the 32.768 kHz oscillator and keeps its time and general
+//! purpose registers on the battery supply. The alarm interrupt wakes the MCU
+//! up from sleep, deep sleep and power-down.
+
+use core::result::Result;
+use core::result::Result::Ok;
+
+use hal::rtc::{self, DateTime};
+use super::peripheral_clock::PeripheralClock::RTCClock;
+
+/// Alarm mask bits of the day of the week and the day of the year.
+const ALARM_MASK_DAY_OF_WEEK_AND_YEAR: u32 = 0x30;
+
+/// Alarm mask bits of all the counters.
+const ALARM_MASK_ALL: u32 = 0xff;
+
+/// Real-time clock.
+#[derive(Clone, Copy)]
+pub struct Rtc;
+
+impl Rtc {
+  /// Powers the RTC registers and starts the clock if it isn't running.
+  pub fn new() -> Rtc {
+    RTCClock.enable();
+    reg::RTC.ccr
+      .set_clken(true)
+      .set_ctcrst(false)
+      .set_ccalen(true);
+    Rtc
+  }
+}
+
+impl rtc::Rtc for Rtc {
+  fn set_time(&self, time: &DateTime) -> Result<(), rtc::Error> {
+    let r = &reg::RTC;
+
+    r.ccr.set_clken(false);
+    r.sec.set_value(time.second as u32);
+    r.min.set_value(time.minute as u32);
+    r.hour.set_value(time.hour as u32);
+    r.dom.set_value(time.day as u32);
+    r.dow.set_value(time.weekday().number_from_monday() as u32 % 7);
+    r.doy.set_value(time.day_of_year() as u32);
+    r.month.set_value(time.month as u32);
+    r.year.set_value(time.year as u32);
+    r.ccr.set_clken(true);
+    Ok(())
+  }
+
+  fn time(&self) -> DateTime {
+    // The counters are read twice, in case a second passes in between
+    let mut time0 = reg::RTC.ctime0.get();
+    loop {
+      let time1 = reg::RTC.ctime1.get();
+      let again = reg::RTC.ctime0.get();
+      if again.raw() == time0.raw() {
+        return DateTime {
+          year: time1.year() as u16,
+          month: time1.month() as u8,
+          day: time1.dom() as u8,
+          hour: time0.hour() as u8,
+          minute: time0.min() as u8,
+          second: time0.sec() as u8,
+        };
+      }
+      time0 = again;
+    }
+  }
+
+  /// Matches all of the date and time, except day of the week and of the
+  /// year.
+  fn set_alarm(&self, time: &DateTime) -> Result<(), rtc::Error> {
+    let r = &reg::RTC;
+
+    r.alsec.set_value(time.second as u32);
+    r.almin.set_value(time.minute as u32);
+    r.alhour.set_value(time.hour as u32);
+    r.aldom.set_value(time.day as u32);
+    r.almon.set_value(time.month as u32);
+    r.alyear.set_value(time.year as u32);
+    r.amr.set_mask(ALARM_MASK_DAY_OF_WEEK_AND_YEAR);
+    Ok(())
+  }
+
+  fn disable_alarm(&self) {
+    reg::RTC.amr.set_mask(ALARM_MASK_ALL);
+  }
+
+  fn alarm_pending(&self) -> bool {
+    reg::RTC.ilr.rtcalf()
+  }
+
+  fn clear_alarm(&self) {
+    reg::RTC.ilr.clear_rtcalf();
+  }
+}
+
+impl rtc::BackupRegisters for Rtc {
+  fn backup_len(&self) -> usize {
+    5
+  }
+
+  fn read_backup(&self, index: usize) -> u32 {
+    reg::RTC.gpreg[index].value()
+  }
+
+  fn write_backup(&self, index: usize, value: u32) {
+    reg::RTC.gpreg[index].set_value(value);
+  }
+}
+
+mod reg {
+  use volatile_cell::VolatileCell;
+  use core::ops::Drop;
+
+  ioregs!(RTC = {
+    0x00 => reg32 ilr {         //! Interrupt location
+      0 => rtccif : set_to_clear, //= Counter increment interrupt
+      1 => rtcalf : set_to_clear, //= Alarm interrupt
+    },
+    0x08 => reg32 ccr {         //! Clock control
+      0 => clken,               //= Clock enable
+      1 => ctcrst,              //= Reset the oscillator divider
+      4 => ccalen,              //= Calibration counter disable
+    },
+    0x10 => reg32 amr {         //! Alarm mask
+      7..0 => mask,             //= Counters excluded from the alarm comparison
+    },
+    0x14 => reg32 ctime0 {      //! Consolidated time 0
+      5..0 => sec : ro,
+      13..8 => min : ro,
+      20..16 => hour : ro,
+      26..24 => dow : ro,
+    },
+    0x18 => reg32 ctime1 {      //! Consolidated time 1
+      4..0 => dom : ro,
+      11..8 => month : ro,
+      27..16 => year : ro,
+    },
+    0x20 => reg32 sec {         //! Seconds counter
+      5..0 => value,
+    },
+    0x24 => reg32 min {         //! Minutes counter
+      5..0 => value,
+    },
+    0x28 => reg32 hour {        //! Hours counter
+      4..0 => value,
+    },
+    0x2c => reg32 dom {         //! Day of month counter
+      4..0 => value,
+    },
+    0x30 => reg32 dow {         //! Day of week counter, 0 is Sunday
+      2..0 => value,
+    },
+    0x34 => reg32 doy {         //! Day of year counter
+      8..0 => value,
+    },
+    0x38 => reg32 month {       //! Months counter
+      3..0 => value,
+    },
+    0x3c => reg32 year {        //! Years counter
+      11..0 => value,
+    },
+    0x44 => reg32 gpreg[5] {    //! General purpose registers
+      31..0 => value,
+    },
+    0x60 => reg32 alsec {       //! Alarm seconds
+      5..0 => value,
+    },
+    0x64 => reg32 almin {       //! Alarm minutes
+      5..0 => value,
+    },
+    0x68 => reg32 alhour {      //! Alarm hours
+      4..0 => value,
+    },
+    0x6c => reg32 aldom {       //! Alarm day of month
+      4..0 => value,
+    },
+    0x78 => reg32 almon {       //! Alarm months
+      3..0 => value,
+    },
+    0x7c => reg32 alyear {      //! Alarm years
+      11..0 => value,
+    },
+  });
+
+  extern {
+    #[link_name="lpc17xx_iomem_RTC"] pub static RTC: RTC;
+  }
+}
@@ -22,17 +22,21 @@ it's currently not supported at all.
 
 use core::intrinsics::abort;
 
-use hal::lpc17xx::peripheral_clock::{PeripheralClock, SSP0Clock, SSP1Clock};
+use hal::lpc17xx::peripheral_clock::PeripheralClock;
+use hal::lpc17xx::peripheral_clock::PeripheralClock::{SSP0Clock, SSP1Clock};
 use hal::lpc17xx::system_clock::system_clock;
-use hal::pin::PinConf_;
 use hal::spi;
 
-#[path="../../util/ioreg.rs"] mod ioreg;
+use self::SSPPeripheral::*;
+
+#[path="../../util/wait_for.rs"]
+#[macro_use] mod wait_for;
 
 /// SPI configuration.
 ///
-/// This configuration doesn't manage the chip-select pin, it must be configured
-/// and used externally via GPIOConf.
+/// This configuration doesn't manage the pins. SCLK, MOSI and MISO must be
+/// switched to their SSP function, and the chip-select pin must be configured
+/// and used externally as a GPIO.
 pub struct SPIConf {
   /// Peripheral to use, mcu-specific.
   pub peripheral: SSPPeripheral,
@@ -44,13 +48,6 @@ pub struct SPIConf {
   ///
   /// The divisor is currently hardcoded and is equal to 1.
   pub frequency: u32,
-
-  /// MOSI pin to use, supports NotConnected pin.
-  pub mosi: PinConf_,
-  /// MISO pin to use, supports NotConnected pin.
-  pub miso: PinConf_,
-  /// SCLK pin to use, supports NotConnected pin.
-  pub sclk: PinConf_,
 }
 
 impl SPIConf {
@@ -67,10 +64,6 @@ impl SPIConf {
     ssp.set_format(self.bits, self.mode);
     ssp.set_frequency(self.frequency);
 
-    self.mosi.setup();
-    self.miso.setup();
-    self.sclk.setup();
-
     ssp
   }
 }
@@ -82,10 +75,13 @@ pub struct SSP {
   reg: &'static reg::SSP,
 }
 
+/// Available SSP peripherals.
+#[allow(missing_docs)]
+#[derive(Clone, Copy)]
 pub enum SSPPeripheral {SSP0, SSP1}
 
 impl SSPPeripheral {
-  fn reg(self) -> &reg::SSP {
+  fn reg(self) -> &'static reg::SSP {
     match self {
       SSP0 => &reg::SSP0,
       SSP1 => &reg::SSP1,
@@ -101,7 +97,6 @@ impl SSPPeripheral {
 }
 
 impl SSP {
-  #[allow(non_snake_case)]
   fn set_format(&self, bits: u8, mode: u8) {
     let slave = false;
 
@@ -110,32 +105,17 @@ impl SSP {
       unsafe { abort() };
     }
 
-    let polarity = mode & 0x2 != 0;
-    let phase = mode & 0x1 != 0;
-
-    let DSS: u32 = bits as u32 - 1;            // DSS (data select size)
-    let SPO: u32 = if polarity { 1 } else { 0 };  // SPO - clock out polarity
-    let SPH: u32 = if phase { 1 } else { 0 };     // SPH - clock out phase
-
-    let FRF: u32 = 0;                   // FRF (frame format) = SPI
-    let old_reg0: u32 = self.reg.CR0();
-    let new_reg0: u32 = old_reg0 & 0xffffff00 |
-      (DSS << 0) |
-      (FRF << 4) |
-      (SPO << 6) |
-      (SPH << 7) ;
-    self.reg.set_CR0(new_reg0);
-
-    let LBM: u32 = 0;
-    let SSE: u32 = 0;
-    let MS:  u32 = if slave { 1 } else { 0 };
-    let SOD: u32 = 0;
-    let new_reg1: u32 =
-      (LBM << 0) |
-      (SSE << 1) |
-      (MS  << 2) |
-      (SOD << 0);
-    self.reg.set_CR1(new_reg1);
+    self.reg.cr0
+      .set_dss(bits as u32 - 1)
+      .set_frf(reg::SSP_cr0_frf::SPI)
+      .set_cpol(mode & 0x2 != 0)
+      .set_cpha(mode & 0x1 != 0);
+
+    self.reg.cr1
+      .set_lbm(false)
+      .set_sse(false)
+      .set_ms(slave)
+      .set_sod(false);
 
     self.enable();
   }
@@ -153,14 +133,8 @@ impl SSP {
 
       // check we can support the divider
       if divider < 256 {
-          // prescaler
-          self.reg.set_CPSR(prescaler);
-
-          // divider
-          let old_reg: u32 = self.reg.CR0();
-          let new_reg: u32 = old_reg & 0xff |
-            ((divider-1) << 8);
-          self.reg.set_CR0(new_reg);
+          self.reg.cpsr.set_cpsdvsr(prescaler);
+          self.reg.cr0.set_scr(divider - 1);
           self.enable();
           return
       }
@@ -170,71 +144,88 @@ impl SSP {
   }
 
   fn disable(&self) {
-    let old_reg: u32 = self.reg.CR1();
-    let new_reg: u32 = old_reg & 0b1101;
-    self.reg.set_CR1(new_reg);
+    self.reg.cr1.set_sse(false);
   }
 
   fn enable(&self) {
-    let old_reg: u32 = self.reg.CR1();
-    let new_reg: u32 = old_reg | 0b0010;
-    self.reg.set_CR1(new_reg);
-  }
-
-  fn readable(&self) -> bool {
-    let val: u32 = self.reg.SR();
-
-    (val & 0b00100) == 0b00100
-  }
-
-  fn writeable(&self) -> bool {
-    let val: u32 = self.reg.SR();
-
-    (val & 0b00010) == 0b00010
-  }
-
-  fn written(&self) -> bool {
-    let val: u32 = self.reg.SR();
-
-    (val & 0b10000) == 0
+    self.reg.cr1.set_sse(true);
   }
 }
 
 impl spi::Spi for SSP {
   fn write(&self, value: u8) {
-    loop { if self.writeable() {
-      break;
-    } }
-    self.reg.set_DR(value as u32);
-    loop { if self.written() {
-        break;
-    } }
+    wait_for!(self.reg.sr.tnf());
+    self.reg.dr.set_data(value as u32);
+    wait_for!(!self.reg.sr.bsy());
   }
 
   fn read(&self) -> u8 {
-    loop {
-      if self.readable() {
-        break;
-      }
-    }
-    (self.reg.DR() & 0xff) as u8
+    wait_for!(self.reg.sr.rne());
+    self.reg.dr.data() as u8
   }
 }
 
 mod reg {
   use volatile_cell::VolatileCell;
 
-  ioreg_old!(SSP: u32, CR0, CR1, DR, SR, CPSR, IMSC, RIS, MIS, ICR, DMACR)
-  reg_rw!(SSP, u32, CR0,   set_CR0,   CR0)
-  reg_rw!(SSP, u32, CR1,   set_CR1,   CR1)
-  reg_rw!(SSP, u32, DR,    set_DR,    DR)
-  reg_r!( SSP, u32, SR,               SR)
-  reg_rw!(SSP, u32, CPSR,  set_CPSR,  CPSR)
-  reg_rw!(SSP, u32, IMSC,  set_IMSC,  IMSC)
-  reg_rw!(SSP, u32, RIS,   set_RIS,   RIS)
-  reg_rw!(SSP, u32, MIS,   set_MIS,   MIS)
-  reg_rw!(SSP, u32, ICR,   set_ICR,   ICR)
-  reg_rw!(SSP, u32, DMACR, set_DMACR, DMACR)
+  ioregs!(SSP = {
+    0x00 => reg32 cr0 {         //! Control register 0
+      3..0  => dss,             //= Data size select, bits per transfer minus one
+      5..4  => frf {            //! Frame format
+        0 => SPI,
+        1 => TI,
+        2 => Microwire,
+      },
+      6     => cpol,            //= Clock out polarity
+      7     => cpha,            //= Clock out phase
+      15..8 => scr,             //= Serial clock rate
+    },
+    0x04 => reg32 cr1 {         //! Control register 1
+      0 => lbm,                 //= Loop back mode
+      1 => sse,                 //= SSP enable
+      2 => ms,                  //= Slave mode
+      3 => sod,                 //= Slave output disable
+    },
+    0x08 => reg32 dr {          //! Data register
+      15..0 => data,
+    },
+    0x0c => reg32 sr {          //! Status register
+      0 => tfe : ro,            //= Transmit FIFO empty
+      1 => tnf : ro,            //= Transmit FIFO not full
+      2 => rne : ro,            //= Receive FIFO not empty
+      3 => rff : ro,            //= Receive FIFO full
+      4 => bsy : ro,            //= Busy
+    },
+    0x10 => reg32 cpsr {        //! Clock prescale register
+      7..0 => cpsdvsr,          //= Even prescaler value, 2 to 254
+    },
+    0x14 => reg32 imsc {        //! Interrupt mask set/clear register
+      0 => rorim,
+      1 => rtim,
+      2 => rxim,
+      3 => txim,
+    },
+    0x18 => reg32 ris {         //! Raw interrupt status register
+      0 => rorris : ro,
+      1 => rtris : ro,
+      2 => rxris : ro,
+      3 => txris : ro,
+    },
+    0x1c => reg32 mis {         //! Masked interrupt status register
+      0 => rormis : ro,
+      1 => rtmis : ro,
+      2 => rxmis : ro,
+      3 => txmis : ro,
+    },
+    0x20 => reg32 icr {         //! Interrupt clear register
+      0 => roric : wo,
+      1 => rtic : wo,
+    },
+    0x24 => reg32 dmacr {       //! DMA control register
+      0 => rxdmae,
+      1 => txdmae,
+    },
+  });
 
   extern {
     #[link_name="lpc17xx_iomem_SSP0"] pub static SSP0: SSP;
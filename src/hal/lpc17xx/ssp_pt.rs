@@ -0,0 +1,213 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::rc::Rc;
+use syntax::ext::base::ExtCtxt;
+
+use builder::{Builder, TokenString, add_node_dependency};
+use builder::resources::Resource;
+use node;
+
+/// SSP pin attributes, with the pin function prefix and whether the pin is an
+/// output. MISO is optional for devices that are only written to.
+static SSP_PINS: &'static [(&'static str, &'static str, bool)] = &[
+  ("sclk", "sck",  true),
+  ("mosi", "mosi", true),
+  ("miso", "miso", false),
+];
+
+pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.materializer.set(Some(verify as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+
+  for sub in node.subnodes().iter() {
+    add_node_dependency(&node, sub);
+    for &(attr, _, _) in SSP_PINS.iter() {
+      let pin_node = sub.get_ref_attr(attr).and_then(|name| {
+        builder.pt().get_by_name(name.as_str())
+      });
+      match pin_node {
+        Some(pin_node) => add_node_dependency(sub, &pin_node),
+        None => (),
+      }
+    }
+    super::add_node_dependency_on_clock(builder, sub);
+
+    sub.materializer.set(Some(build_ssp as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+    sub.mutator.set(Some(mutate_pins as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  }
+}
+
+pub fn verify(_: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.expect_no_attributes(cx);
+}
+
+/// Switches the pins referenced by an SSP node to their SSP function.
+pub fn mutate_pins(builder: &mut Builder, cx: &mut ExtCtxt, sub: Rc<node::Node>) {
+  let ssp_idx = match sub.path.as_str().parse::<usize>() {
+    Ok(i) => i,
+    Err(_) => return,  // reported by build_ssp
+  };
+
+  for &(attr, function, isout) in SSP_PINS.iter() {
+    let pin_node = sub.get_ref_attr(attr).and_then(|name| {
+      builder.pt().get_by_name(name.as_str())
+    });
+    let pin_node = match pin_node {
+      Some(pin_node) => pin_node,
+      None => continue,
+    };
+    if !builder.claim_resource(cx, Resource::pin_for_node(&pin_node), &sub,
+        sub.get_attr(attr).value_span) {
+      continue
+    }
+    let direction = (if isout {"out"} else {"in"}).to_string();
+    let function = format!("{}{}", function, ssp_idx);
+    pin_node.attributes.borrow_mut().insert("direction".to_string(),
+          Rc::new(node::Attribute::new_nosp(node::StrValue(direction))));
+    pin_node.attributes.borrow_mut().insert("function".to_string(),
+          Rc::new(node::Attribute::new_nosp(node::StrValue(function))));
+  }
+}
+
+pub fn build_ssp(builder: &mut Builder, cx: &mut ExtCtxt,
+    sub: Rc<node::Node>) {
+  if !sub.expect_no_subnodes(cx) {return}
+
+  let ssp_peripheral = match sub.path.as_str().parse::<usize>() {
+    Ok(i @ 0...1) => TokenString(format!("SSPPeripheral::SSP{}", i)),
+    _ => {
+      cx.parse_sess().span_diagnostic.span_err(sub.path_span,
+          format!("unknown SSP `{}`, allowed values: 0, 1",
+              sub.path).as_str());
+      return
+    }
+  };
+
+  if sub.name.is_none() {
+    cx.parse_sess().span_diagnostic.span_err(sub.name_span,
+        "SSP node must have a name");
+    return
+  }
+
+  if !sub.expect_attributes(cx, &[
+      ("frequency", node::IntAttribute),
+      ("mode", node::IntAttribute),
+      ("sclk", node::RefAttribute),
+      ("mosi", node::RefAttribute)]) {
+    return
+  }
+
+  let frequency = sub.get_int_attr("frequency").unwrap() as u32;
+  let mode = match sub.get_int_attr("mode").unwrap() {
+    m @ 0...3 => m as u8,
+    other => {
+      cx.parse_sess().span_diagnostic.span_err(sub.get_attr("mode").value_span,
+          format!("unknown SPI mode `{}`, allowed values: 0...3",
+              other).as_str());
+      return
+    }
+  };
+
+  if !builder.claim_resource(cx,
+      Resource::Peripheral(format!("ssp{}", sub.path)),
+      &sub, sub.path_span) {
+    return
+  }
+
+  sub.set_type_name("zinc::hal::lpc17xx::ssp::SSP".to_string());
+  let ssp_name = TokenString(sub.name.clone().unwrap());
+
+  let st = quote_stmt!(&*cx,
+      let $ssp_name = zinc::hal::lpc17xx::ssp::SPIConf {
+        peripheral: zinc::hal::lpc17xx::ssp::$ssp_peripheral,
+        bits: 8u8,
+        mode: $mode,
+        frequency: $frequency,
+      }.setup();
+  ).unwrap();
+  builder.add_main_statement(st);
+}
+
+#[cfg(test)]
+mod test {
+  use builder::Builder;
+  use test_helpers::{assert_equal_source, with_parsed};
+
+  #[test]
+  fn builds_ssp() {
+    with_parsed("
+      mcu {
+        clock {
+          system_frequency = 100_000_000;
+        }
+      }
+      ssp {
+        lcd_spi@1 {
+          frequency = 1_000_000;
+          mode = 3;
+          sclk = &ssp_sclk;
+          mosi = &ssp_mosi;
+        }
+      }
+      gpio {
+        ssp_sclk@7;
+        ssp_mosi@9;
+      }
+      ", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::mutate_pins(&mut builder, cx, pt.get_by_name("lcd_spi").unwrap());
+      super::build_ssp(&mut builder, cx, pt.get_by_name("lcd_spi").unwrap());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 1);
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "let lcd_spi = zinc::hal::lpc17xx::ssp::SPIConf {
+               peripheral: zinc::hal::lpc17xx::ssp::SSPPeripheral::SSP1,
+               bits: 8u8,
+               mode: 3u8,
+               frequency: 1000000u32,
+             }.setup();");
+
+      let sclk_node = pt.get_by_name("ssp_sclk").unwrap();
+      assert!(sclk_node.get_string_attr("function").unwrap() == "sck1".to_string());
+
+      let mosi_node = pt.get_by_name("ssp_mosi").unwrap();
+      assert!(mosi_node.get_string_attr("function").unwrap() == "mosi1".to_string());
+    });
+  }
+
+  #[test]
+  fn fails_to_build_unknown_ssp() {
+    with_parsed("
+      ssp {
+        bus@2 {
+          frequency = 1_000_000;
+          mode = 0;
+          sclk = &ssp_sclk;
+          mosi = &ssp_mosi;
+        }
+      }
+      gpio {
+        ssp_sclk@7;
+        ssp_mosi@9;
+      }
+      ", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_ssp(&mut builder, cx, pt.get_by_name("bus").unwrap());
+      assert!(unsafe{*failed} == true);
+      assert!(builder.main_stmts().len() == 0);
+    });
+  }
+}
@@ -22,8 +22,6 @@ performing initial peripheral configuration.
 
 use core::option::Option::{self, Some, None};
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
 #[path="../../util/wait_for.rs"]
 #[macro_use] mod wait_for;
 
@@ -103,12 +101,13 @@ pub fn init_clock(clock: &Clock) {
 
 #[inline(always)]
 fn init_main_oscillator(freq: u32) {
-  let val: u32 = if freq > 15_000_000 { 1 << 4 } else { 0 } |
-                 (1 << 5);
+  use self::reg::SCS_scs_oscrange::*;
 
-  reg::SCS.set_value(val);
+  reg::SCS.scs
+    .set_oscrange(if freq > 15_000_000 { Range15To25MHz } else { Range1To20MHz })
+    .set_oscen(true);
 
-  wait_for!(reg::SCS.value() & (1 << 6) == (1 << 6));
+  wait_for!(reg::SCS.scs.oscstat());
 }
 
 #[inline(always)]
@@ -119,62 +118,107 @@ fn init_flash_access(freq: u32) {
                         if freq > 40_000_000 { 3 } else
                         if freq > 20_000_000 { 2 } else
                         { 1 };
-  let val = (num_clocks - 1) << 12;
-  reg::FLASHCFG.set_value(val);
-}
-
-#[inline(always)]
-fn wait_for_pll0stat_bit(bit: usize) {
-  wait_for!(reg::PLL0STAT.value() & (1 << bit) == (1 << bit));
+  reg::FLASHCFG.flashcfg.set_flashtim(num_clocks - 1);
 }
 
 #[inline(always)]
 fn write_pll0_changes() {
-  reg::PLL0FEED.set_value(0xaa);
-  reg::PLL0FEED.set_value(0x55);
+  reg::PLL0FEED.pll0feed.set_feed(0xaa);
+  reg::PLL0FEED.pll0feed.set_feed(0x55);
 }
 
 #[inline(always)]
 fn init_pll(pll: &PLL0, source: &ClockSource) {
   use self::ClockSource::*;
-  match source {
-    &Internal => reg::CLKSRCSEL.set_value(0),
-    &Main(_)  => reg::CLKSRCSEL.set_value(1),
-    &RTC =>      reg::CLKSRCSEL.set_value(2),
-  }
+  use self::reg::CLKSRCSEL_clksrcsel_clksrc::*;
+
+  reg::CLKSRCSEL.clksrcsel.set_clksrc(match source {
+    &Internal => IRC,
+    &Main(_)  => MainOscillator,
+    &RTC =>      RTCOscillator,
+  });
 
-  let val: u32 = ((pll.n as u32 - 1) << 16) | ((pll.m as u32 - 1) << 0);
-  reg::PLL0CFG.set_value(val);
+  reg::PLL0CFG.pll0cfg
+    .set_msel0(pll.m as u32 - 1)
+    .set_nsel0(pll.n as u32 - 1);
   write_pll0_changes();
-  reg::PLL0CON.set_value(1);
+  reg::PLL0CON.pll0con.set_plle0(true);
   write_pll0_changes();
-  wait_for_pll0stat_bit(24);
-  reg::CCLKCFG.set_value((pll.divisor - 1) as u32);
-  wait_for_pll0stat_bit(26);
-  reg::PLL0CON.set_value(3);
+  wait_for!(reg::PLL0STAT.pll0stat.plle0_stat());
+  reg::CCLKCFG.cclkcfg.set_cclksel((pll.divisor - 1) as u32);
+  wait_for!(reg::PLL0STAT.pll0stat.plock0());
+  reg::PLL0CON.pll0con
+    .set_plle0(true)
+    .set_pllc0(true);
   write_pll0_changes();
-  wait_for_pll0stat_bit(25);
+  wait_for!(reg::PLL0STAT.pll0stat.pllc0_stat());
 }
 
 mod reg {
   use volatile_cell::VolatileCell;
 
-  ioreg_old!(SCS: u32, value);
-  reg_rw!(SCS, u32, value, set_value, value);
-  ioreg_old!(FLASHCFG: u32, value);
-  reg_w!(FLASHCFG, u32, set_value, value);
-  ioreg_old!(PLL0CFG: u32, value);
-  reg_w!(PLL0CFG, u32, set_value, value);
-  ioreg_old!(PLL0CON: u32, value);
-  reg_w!(PLL0CON, u32, set_value, value);
-  ioreg_old!(PLL0FEED: u32, value);
-  reg_w!(PLL0FEED, u32, set_value, value);
-  ioreg_old!(PLL0STAT: u32, value);
-  reg_r!(PLL0STAT, u32, value, value);
-  ioreg_old!(CCLKCFG: u32, value);
-  reg_w!(CCLKCFG, u32, set_value, value);
-  ioreg_old!(CLKSRCSEL: u32, value);
-  reg_w!(CLKSRCSEL, u32, set_value, value);
+  ioregs!(SCS = {
+    0x0 => reg32 scs {          //! System controls and status
+      4 => oscrange {           //! Main oscillator range select
+        0 => Range1To20MHz,
+        1 => Range15To25MHz,
+      },
+      5 => oscen,               //= Main oscillator enable
+      6 => oscstat : ro,        //= Main oscillator is ready to be used
+    }
+  });
+
+  ioregs!(FLASHCFG = {
+    0x0 => reg32 flashcfg {     //! Flash accelerator configuration
+      15..12 => flashtim,       //= Flash access time, in CPU clocks minus one
+    }
+  });
+
+  ioregs!(PLL0CFG = {
+    0x0 => reg32 pll0cfg {      //! PLL0 configuration
+      14..0  => msel0,          //= PLL0 multiplier value minus one
+      23..16 => nsel0,          //= PLL0 pre-divider value minus one
+    }
+  });
+
+  ioregs!(PLL0CON = {
+    0x0 => reg32 pll0con {      //! PLL0 control
+      0 => plle0,               //= PLL0 enable
+      1 => pllc0,               //= PLL0 connect
+    }
+  });
+
+  ioregs!(PLL0FEED = {
+    0x0 => reg32 pll0feed {     //! PLL0 feed sequence
+      7..0 => feed : wo,
+    }
+  });
+
+  ioregs!(PLL0STAT = {
+    0x0 => reg32 pll0stat {     //! PLL0 status
+      14..0  => msel0 : ro,
+      23..16 => nsel0 : ro,
+      24     => plle0_stat : ro,  //= PLL0 is enabled
+      25     => pllc0_stat : ro,  //= PLL0 is connected
+      26     => plock0 : ro,      //= PLL0 is locked
+    }
+  });
+
+  ioregs!(CCLKCFG = {
+    0x0 => reg32 cclkcfg {      //! CPU clock configuration
+      7..0 => cclksel,          //= PLL0 output divider minus one
+    }
+  });
+
+  ioregs!(CLKSRCSEL = {
+    0x0 => reg32 clksrcsel {    //! Clock source select
+      1..0 => clksrc {          //! PLL0 clock source
+        0 => IRC,
+        1 => MainOscillator,
+        2 => RTCOscillator,
+      }
+    }
+  });
 
   extern {
     #[link_name="lpc17xx_iomem_SCS"] pub static SCS: SCS;
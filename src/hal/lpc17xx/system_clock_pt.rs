@@ -18,8 +18,24 @@ use syntax::ext::base::ExtCtxt;
 use syntax::ext::build::AstBuilder;
 
 use builder::{Builder, TokenString};
+use builder::clock::{Factor, PllLimits, format_frequency};
+use builder::clock;
 use node;
 
+/// PLL0 limits, see UM10360 chapter 4.5.
+///
+/// `mult` and `output_div` are further restricted to fit into u8 fields of
+/// `system_clock::PLL0`.
+static PLL0_LIMITS: PllLimits = PllLimits {
+  input_div: Factor::Range(1, 32),
+  mult: Factor::Range(6, 255),
+  output_div: Factor::Range(3, 255),
+  vco_scale: 2,
+  input_range: (32_000, 50_000_000),
+  vco_range: (275_000_000, 550_000_000),
+  max_output: 100_000_000,
+};
+
 pub fn attach(_: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
   node.materializer.set(Some(build_clock as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
 }
@@ -61,23 +77,41 @@ fn build_clock(builder: &mut Builder, cx: &mut ExtCtxt,
     },
   });
 
-  let some_pll_conf = node.get_by_path("pll").and_then(|sub|
-      -> Option<(usize, usize, usize)> {
-    if !sub.expect_no_subnodes(cx) || !sub.expect_attributes(cx, &[
-        ("m", node::IntAttribute),
-        ("n", node::IntAttribute),
-        ("divisor", node::IntAttribute)]) {
-      None
-    } else {
-      let m = sub.get_int_attr("m").unwrap();
-      let n = sub.get_int_attr("n").unwrap();
-      let divisor = sub.get_int_attr("divisor").unwrap();
-      Some((m, n, divisor))
-    }
-  });
+  let some_pll_conf = match node.get_int_attr("target_frequency") {
+    Some(target) => {
+      if source_freq == 0 {
+        return;
+      }
+      match node.get_by_path("pll") {
+        Some(sub) => {
+          cx.parse_sess().span_diagnostic.span_err(sub.path_span,
+              "subnode `pll` can't be used together with `target_frequency`");
+          return;
+        },
+        None => (),
+      }
+      solve_pll(cx, &node, source_freq, target)
+    },
+    None => node.get_by_path("pll").and_then(|sub|
+        -> Option<(usize, usize, usize)> {
+      if !sub.expect_no_subnodes(cx) || !sub.expect_attributes(cx, &[
+          ("m", node::IntAttribute),
+          ("n", node::IntAttribute),
+          ("divisor", node::IntAttribute)]) {
+        None
+      } else {
+        let m = sub.get_int_attr("m").unwrap();
+        let n = sub.get_int_attr("n").unwrap();
+        let divisor = sub.get_int_attr("divisor").unwrap();
+        Some((m, n, divisor))
+      }
+    }),
+  };
   if some_pll_conf.is_none() {
-    cx.parse_sess().span_diagnostic.span_err(node.name_span,
-        "required subnode `pll` is missing");
+    if node.get_int_attr("target_frequency").is_none() {
+      cx.parse_sess().span_diagnostic.span_err(node.name_span,
+          "required subnode `pll` is missing");
+    }
     return;
   }
 
@@ -91,6 +125,13 @@ fn build_clock(builder: &mut Builder, cx: &mut ExtCtxt,
   node.attributes.borrow_mut().insert("system_frequency".to_string(),
       Rc::new(node::Attribute::new_nosp(node::IntValue(sysfreq))));
 
+  builder.add_report(format!(
+      "lpc17xx clock: cclk = {} (PLL0 m = {}, n = {}, divisor = {}, \
+      Fcco = {}), flash access time = {} cclk, pclk = {}",
+      format_frequency(sysfreq), m, n, divisor,
+      format_frequency(source_freq * 2 * m / n), flash_access_clocks(sysfreq),
+      format_frequency(sysfreq / 4)));
+
   let ex = quote_expr!(&*cx,
       {
         use zinc::hal::lpc17xx::system_clock;
@@ -109,6 +150,42 @@ fn build_clock(builder: &mut Builder, cx: &mut ExtCtxt,
   builder.add_main_statement(cx.stmt_expr(ex));
 }
 
+/// Returns (m, n, divisor) of PLL0 that generate `target` frequency.
+fn solve_pll(cx: &ExtCtxt, node: &Rc<node::Node>, source_freq: usize,
+    target: usize) -> Option<(usize, usize, usize)> {
+  let span = node.get_attr("target_frequency").value_span;
+  match clock::solve_pll(source_freq, target, &PLL0_LIMITS) {
+    Some(solution) => {
+      if solution.output != target {
+        cx.parse_sess().span_diagnostic.span_warn(span,
+            format!("can't generate exactly {} from {}, using {}",
+                format_frequency(target), format_frequency(source_freq),
+                format_frequency(solution.output)).as_str());
+      }
+      Some((solution.mult, solution.input_div, solution.output_div))
+    },
+    None => {
+      cx.parse_sess().span_diagnostic.span_err(span,
+          format!("can't generate {} from {}, max cclk is {}",
+              format_frequency(target), format_frequency(source_freq),
+              format_frequency(PLL0_LIMITS.max_output)).as_str());
+      None
+    },
+  }
+}
+
+/// Flash access time in cclk cycles, matching `system_clock::init_flash_access`.
+fn flash_access_clocks(freq: usize) -> usize {
+  match freq {
+    0...20_000_000 => 1,
+    20_000_001...40_000_000 => 2,
+    40_000_001...60_000_000 => 3,
+    60_000_001...80_000_000 => 4,
+    80_000_001...100_000_000 => 5,
+    _ => 6,
+  }
+}
+
 #[cfg(test)]
 mod test {
   use builder::Builder;
@@ -170,6 +247,74 @@ mod test {
     });
   }
 
+  #[test]
+  fn builds_clock_init_for_target_frequency() {
+    with_parsed("
+      clock {
+        source = \"main-oscillator\";
+        source_frequency = 12_000_000;
+        target_frequency = 100_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      let node = pt.get_by_path("clock").unwrap();
+      super::build_clock(&mut builder, cx, node.clone());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 1);
+      assert!(node.get_int_attr("system_frequency") == Some(100_000_000));
+      assert!(builder.reports().len() == 1);
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "{
+            use zinc::hal::lpc17xx::system_clock;
+            system_clock::init_clock(
+                &system_clock::Clock {
+                  source: system_clock::ClockSource::Main(12000000),
+                  pll: core::option::Option::Some(system_clock::PLL0 {
+                    m: 25u8,
+                    n: 2u8,
+                    divisor: 3u8,
+                  }),
+                }
+            );
+          }");
+    });
+  }
+
+  #[test]
+  fn fails_to_build_unreachable_target_frequency() {
+    with_parsed("
+      clock {
+        source = \"main-oscillator\";
+        source_frequency = 12_000_000;
+        target_frequency = 120_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_clock(&mut builder, cx, pt.get_by_path("clock").unwrap());
+      assert!(unsafe{*failed} == true);
+      assert!(builder.main_stmts().len() == 0);
+    });
+  }
+
+  #[test]
+  fn fails_to_build_target_frequency_with_pll() {
+    with_parsed("
+      clock {
+        source = \"main-oscillator\";
+        source_frequency = 12_000_000;
+        target_frequency = 100_000_000;
+        pll {
+          m = 50;
+          n = 3;
+          divisor = 4;
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_clock(&mut builder, cx, pt.get_by_path("clock").unwrap());
+      assert!(unsafe{*failed} == true);
+      assert!(builder.main_stmts().len() == 0);
+    });
+  }
+
   #[test]
   fn fails_to_parse_bad_clock_conf() {
     fails_to_build("lpc17xx@mcu { clock {
@@ -16,15 +16,20 @@
 /*!
 Timer configuration.
 
-This code supports all four primary timers of the MCU.
+This code supports all four primary timers of the MCU, with input capture on
+their CAPn.0 and CAPn.1 pins, channels 0 and 1, and one pulse output on their
+MATn.0 to MATn.3 pins, channels 0 to 3.
 */
 
-use hal::timer;
+use core::intrinsics::abort;
+use core::option::Option;
+
+use hal::timer::{self, CaptureEdge};
 
 use self::TimerPeripheral::*;
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
+#[path="../../util/wait_for.rs"]
+#[macro_use] mod wait_for;
 
 /// Available timer peripherals.
 #[allow(missing_docs)]
@@ -67,10 +72,14 @@ impl Timer {
     clock.enable();
     clock.set_divisor(divisor);
 
-    reg.set_CTCR(0);
-    reg.set_TCR(2);
-    reg.set_PR(counter - 1);
-    reg.set_TCR(1);
+    reg.ctcr.set_mode(reg::TIMER_ctcr_mode::Timer);
+    reg.tcr
+      .set_counter_enable(false)
+      .set_counter_reset(true);
+    reg.pr.set_value(counter - 1);
+    reg.tcr
+      .set_counter_enable(true)
+      .set_counter_reset(false);
 
     Timer {
       reg: reg,
@@ -81,29 +90,185 @@ impl Timer {
 impl timer::Timer for Timer {
   #[inline(always)]
   fn get_counter(&self) -> u32 {
-    self.reg.TC()
+    self.reg.tc.value()
+  }
+}
+
+impl timer::Capture for Timer {
+  fn enable_capture(&self, channel: u8, edge: CaptureEdge) {
+    let (rising, falling) = match edge {
+      CaptureEdge::Rising  => (true, false),
+      CaptureEdge::Falling => (false, true),
+      CaptureEdge::Both    => (true, true),
+    };
+    // The interrupt flag is only raised with the interrupt enabled, it
+    // doesn't fire unless the timer IRQ is enabled in the NVIC.
+    match channel {
+      0 => self.reg.ccr
+        .set_cap0_rising(rising)
+        .set_cap0_falling(falling)
+        .set_cap0_interrupt(true),
+      1 => self.reg.ccr
+        .set_cap1_rising(rising)
+        .set_cap1_falling(falling)
+        .set_cap1_interrupt(true),
+      _ => unsafe { abort() },
+    };
+  }
+
+  fn disable_capture(&self, channel: u8) {
+    match channel {
+      0 => self.reg.ccr
+        .set_cap0_rising(false)
+        .set_cap0_falling(false)
+        .set_cap0_interrupt(false),
+      1 => self.reg.ccr
+        .set_cap1_rising(false)
+        .set_cap1_falling(false)
+        .set_cap1_interrupt(false),
+      _ => unsafe { abort() },
+    };
+  }
+
+  fn take_capture(&self, channel: u8) -> Option<u32> {
+    let channel = channel as usize;
+    if self.reg.ir.capture_int(channel) {
+      self.reg.ir.clear_capture_int(channel);
+      Some(self.reg.cr[channel].value())
+    } else {
+      None
+    }
+  }
+}
+
+impl Timer {
+  /// Sets the interrupt flag and stop on match bits of match `channel`.
+  fn set_match_control(&self, channel: u8, interrupt: bool, stop: bool) {
+    match channel {
+      0 => self.reg.mcr
+        .set_mr0_interrupt(interrupt)
+        .set_mr0_reset(false)
+        .set_mr0_stop(stop),
+      1 => self.reg.mcr
+        .set_mr1_interrupt(interrupt)
+        .set_mr1_reset(false)
+        .set_mr1_stop(stop),
+      2 => self.reg.mcr
+        .set_mr2_interrupt(interrupt)
+        .set_mr2_reset(false)
+        .set_mr2_stop(stop),
+      3 => self.reg.mcr
+        .set_mr3_interrupt(interrupt)
+        .set_mr3_reset(false)
+        .set_mr3_stop(stop),
+      _ => unsafe { abort() },
+    };
+  }
+}
+
+impl timer::OnePulse for Timer {
+  /// Restarts the counter from 0 and stops it after the pulse, so the timer
+  /// can't be used for delays any more. A match register has a single
+  /// output action, so the call waits for the leading edge to switch it to
+  /// the trailing one: `width` must outlast that switch, a few instructions.
+  fn one_pulse(&self, channel: u8, delay: u32, width: u32) {
+    use self::reg::TIMER_emr_external_match_control as Control;
+
+    // A match on 0 may be missed as the counter restarts
+    let delay = if delay == 0 { 1 } else { delay };
+    let index = channel as usize;
+
+    self.reg.tcr
+      .set_counter_enable(false)
+      .set_counter_reset(true);
+    // The interrupt flag is only raised with the interrupt enabled, see
+    // `enable_capture`
+    self.set_match_control(channel, true, false);
+    self.reg.mr[index].set_value(delay);
+    self.reg.emr
+      .set_external_match(index, false)
+      .set_external_match_control(index, Control::Set);
+    self.reg.ir.clear_match_int(index);
+    self.reg.tcr
+      .set_counter_enable(true)
+      .set_counter_reset(false);
+
+    wait_for!(self.reg.ir.match_int(index));
+    self.reg.mr[index].set_value(delay + width);
+    self.reg.emr.set_external_match_control(index, Control::Clear);
+    self.set_match_control(channel, false, true);
+    self.reg.ir.clear_match_int(index);
   }
 }
 
 mod reg {
   use volatile_cell::VolatileCell;
 
-  ioreg_old!(TIMER: u32, IR, TCR, TC, PR, PC, MCR, MR0, MR1, MR2, MR3, CCR, CR0, CR1, EMR, CTCR);
-  reg_rw!(TIMER, u32, IR,  set_IR,  IR);
-  reg_rw!(TIMER, u32, TCR, set_TCR, TCR);
-  reg_rw!(TIMER, u32, TC, set_TC, TC);
-  reg_rw!(TIMER, u32, PR, set_PR, PR);
-  reg_rw!(TIMER, u32, PC, set_PC, PC);
-  reg_rw!(TIMER, u32, MCR, set_MCR, MCR);
-  reg_rw!(TIMER, u32, MR0, set_MR0, MR0);
-  reg_rw!(TIMER, u32, MR1, set_MR1, MR1);
-  reg_rw!(TIMER, u32, MR2, set_MR2, MR2);
-  reg_rw!(TIMER, u32, MR3, set_MR3, MR3);
-  reg_rw!(TIMER, u32, CCR, set_CCR, CCR);
-  reg_rw!(TIMER, u32, CR0, set_CR0, CR0);
-  reg_rw!(TIMER, u32, CR1, set_CR1, CR1);
-  reg_rw!(TIMER, u32, EMR, set_EMR, EMR);
-  reg_rw!(TIMER, u32, CTCR, set_CTCR, CTCR);
+  ioregs!(TIMER = {
+    0x00 => reg32 ir {          //! Interrupt register
+      0..3 => match_int[4] : set_to_clear,    //= Match channel interrupt flags
+      4..5 => capture_int[2] : set_to_clear,  //= Capture channel interrupt flags
+    },
+    0x04 => reg32 tcr {         //! Timer control register
+      0 => counter_enable,
+      1 => counter_reset,
+    },
+    0x08 => reg32 tc {          //! Timer counter
+      31..0 => value,
+    },
+    0x0c => reg32 pr {          //! Prescale register
+      31..0 => value,
+    },
+    0x10 => reg32 pc {          //! Prescale counter
+      31..0 => value,
+    },
+    0x14 => reg32 mcr {         //! Match control register
+      0  => mr0_interrupt,
+      1  => mr0_reset,
+      2  => mr0_stop,
+      3  => mr1_interrupt,
+      4  => mr1_reset,
+      5  => mr1_stop,
+      6  => mr2_interrupt,
+      7  => mr2_reset,
+      8  => mr2_stop,
+      9  => mr3_interrupt,
+      10 => mr3_reset,
+      11 => mr3_stop,
+    },
+    0x18 => reg32 mr[4] {       //! Match registers
+      31..0 => value,
+    },
+    0x28 => reg32 ccr {         //! Capture control register
+      0 => cap0_rising,
+      1 => cap0_falling,
+      2 => cap0_interrupt,
+      3 => cap1_rising,
+      4 => cap1_falling,
+      5 => cap1_interrupt,
+    },
+    0x2c => reg32 cr[2] {       //! Capture registers
+      31..0 => value : ro,
+    },
+    0x3c => reg32 emr {         //! External match register
+      0..3  => external_match[4],
+      4..11 => external_match_control[4] {
+        0 => DoNothing,
+        1 => Clear,
+        2 => Set,
+        3 => Toggle,
+      },
+    },
+    0x70 => reg32 ctcr {        //! Count control register
+      1..0 => mode {
+        0 => Timer,           //= Increment on every rising PCLK edge
+        1 => CounterRising,   //= Increment on rising edges of the CAP input
+        2 => CounterFalling,  //= Increment on falling edges of the CAP input
+        3 => CounterBoth,     //= Increment on both edges of the CAP input
+      },
+      3..2 => input_select,
+    },
+  });
 
   extern {
     #[link_name="lpc17xx_iomem_TIMER0"] pub static TIMER0: TIMER;
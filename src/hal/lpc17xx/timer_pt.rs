@@ -17,6 +17,7 @@ use std::rc::Rc;
 use syntax::ext::base::ExtCtxt;
 
 use builder::{Builder, TokenString, add_node_dependency};
+use builder::resources::Resource;
 use node;
 
 pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
@@ -61,6 +62,12 @@ fn build_timer(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
     }
   };
 
+  if !builder.claim_resource(cx,
+      Resource::Peripheral(format!("timer{}", timer_index)),
+      &node, node.path_span) {
+    return
+  }
+
   node.set_type_name("zinc::hal::lpc17xx::timer::Timer".to_string());
 
   let st = quote_stmt!(&*cx,
@@ -31,8 +31,6 @@ use hal::uart;
 
 use self::UARTPeripheral::*;
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
 #[path="../../util/wait_for.rs"]
 #[macro_use] mod wait_for;
 
@@ -91,47 +89,11 @@ impl StopBit {
   }
 }
 
-enum ParityEnabled {
-  PEDisabled = 0b0_0_00,
-  PEEnabled  = 0b1_0_00,
-}
-
-enum ParitySelect {
-  PSOdd    = 0b00_0_0_00,
-  PSEven   = 0b01_0_0_00,
-  PSForced1 = 0b10_0_0_00,
-  PSForced0 = 0b11_0_0_00,
-}
-
-#[allow(dead_code)]
-enum BreakControl {
-  BCDisabled = 0b0_00_0_0_00,
-  BCEnabled  = 0b1_00_0_0_00,
-}
-
-enum FIFOEnabled {
-  FEEnabled  = 0b1,
-  FEDisabled = 0b0,
-}
-
-#[allow(dead_code)]
-enum FIFODmaMode {
-  FDEnabled  = 0b1_0_0_0,
-  FDDisabled = 0b0_0_0_0,
-}
-
-#[allow(dead_code)]
-enum FIFOTriggerLevel {
-  FT1char   = 0b00_00_0_0_0_0,
-  FT4chars  = 0b01_00_0_0_0_0,
-  FT8chars  = 0b10_00_0_0_0_0,
-  FT14chars = 0b11_00_0_0_0_0,
-}
-
 /// Structure describing a UART instance.
 #[derive(Clone)]
 pub struct UART {
   reg: &'static reg::UART,
+  alias: &'static reg::UART_ALIAS,
   clock: PeripheralClock,
 }
 
@@ -144,6 +106,14 @@ impl UARTPeripheral {
     }
   }
 
+  fn alias_reg(self) -> &'static reg::UART_ALIAS {
+    match self {
+      UART0 => &reg::UART0_ALIAS,
+      UART2 => &reg::UART2_ALIAS,
+      UART3 => &reg::UART3_ALIAS,
+    }
+  }
+
   fn peripheral_clock(self) -> PeripheralClock {
     match self {
       UART0 => UART0Clock,
@@ -159,6 +129,7 @@ impl UART {
       parity: uart::Parity, stop_bits: u8) -> UART {
     let uart = UART {
       reg: peripheral.reg(),
+      alias: peripheral.alias_reg(),
       clock: peripheral.peripheral_clock(),
     };
 
@@ -176,46 +147,53 @@ impl UART {
   }
 
   fn set_baud_rate(&self, baud_rate: u32) {
-    self.reg.set_LCR(0b1000_0000); // enable divisor latch access
+    self.reg.lcr.set_dlab(true); // enable divisor latch access
 
     let (dl, div_add_val, mul_val) = self.calculate_divisors(baud_rate);
 
-    self.reg.set_DLM((dl >> 8) & 0xff);
-    self.reg.set_DLL(dl & 0xff);
-    self.reg.set_FDR(div_add_val | (mul_val << 4));
+    self.alias.dlm.set_value((dl >> 8) & 0xff);
+    self.alias.dll.set_value(dl & 0xff);
+    self.reg.fdr
+      .set_divaddval(div_add_val)
+      .set_mulval(mul_val);
 
-    self.reg.set_LCR(3);
+    self.reg.lcr.set_dlab(false);
   }
 
   fn set_mode(&self, word_len: WordLen, parity: uart::Parity, stop_bits: StopBit) {
-    use self::ParityEnabled::*;
-    use self::ParitySelect::*;
-    let lcr: u8 = (*(self.reg)).LCR() as u8;
-    let computed_val: u8 = word_len as u8 | stop_bits as u8 | match parity {
-      uart::Parity::Disabled => PEDisabled as u8  | PSOdd as u8,
-      uart::Parity::Odd      => PEEnabled as u8   | PSOdd as u8,
-      uart::Parity::Even     => PEEnabled as u8   | PSEven as u8,
-      uart::Parity::Forced1  => PEEnabled as u8   | PSForced1 as u8,
-      uart::Parity::Forced0  => PEEnabled as u8   | PSForced0 as u8,
+    use self::reg::UART_lcr_parity_select::*;
+
+    let (parity_enabled, parity_select) = match parity {
+      uart::Parity::Disabled => (false, Odd),
+      uart::Parity::Odd      => (true,  Odd),
+      uart::Parity::Even     => (true,  Even),
+      uart::Parity::Forced1  => (true,  Forced1),
+      uart::Parity::Forced0  => (true,  Forced0),
     };
-    let new_lcr = (lcr & !LCRModeMask) | computed_val;
 
-    (*(self.reg)).set_LCR(new_lcr as u32);
+    self.reg.lcr
+      .set_word_length(match word_len {
+        WordLen::WordLen5bits => reg::UART_lcr_word_length::Bits5,
+        WordLen::WordLen6bits => reg::UART_lcr_word_length::Bits6,
+        WordLen::WordLen7bits => reg::UART_lcr_word_length::Bits7,
+        WordLen::WordLen8bits => reg::UART_lcr_word_length::Bits8,
+      })
+      .set_two_stop_bits(match stop_bits {
+        StopBit::StopBit1bit  => false,
+        StopBit::StopBit2bits => true,
+      })
+      .set_parity_enabled(parity_enabled)
+      .set_parity_select(parity_select)
+      .set_break_control(false);
   }
 
   fn set_fifo_enabled(&self, enabled: bool, reset: bool) {
-    use self::FIFOEnabled::*;
-    use self::FIFODmaMode::*;
-    use self::FIFOTriggerLevel::*;
-    let val: u8 = match enabled {
-      true => FEEnabled as u8,
-      false => FEDisabled as u8
-    } | match reset {
-      true  => FIFOResetTx & FIFOResetRx,
-      false => 0,
-    } | FDDisabled as u8 | FT1char as u8;
-
-    (*(self.reg)).set_FCR(val as u32);
+    self.alias.fcr
+      .set_fifo_enable(enabled)
+      .set_rx_fifo_reset(reset)
+      .set_tx_fifo_reset(reset)
+      .set_dma_mode(false)
+      .set_rx_trigger_level(reg::UART_ALIAS_fcr_rx_trigger_level::Trigger1Char);
   }
 
   // TODO(farcaller): license note
@@ -284,44 +262,180 @@ impl UART {
 
 impl CharIO for UART {
   fn putc(&self, value: char) {
-    wait_for!(self.reg.LSR() as u8 & LSRTHREmpty == LSRTHREmpty);
-    self.reg.set_THR(value as u32);
+    wait_for!(self.reg.lsr.thre());
+    self.reg.rbr_thr.ignoring_state().set_data(value as u32);
   }
 }
 
-#[allow(non_upper_case_globals)]
-static FIFOResetRx: u8 = 0b1_0;
-#[allow(non_upper_case_globals)]
-static FIFOResetTx: u8 = 0b1_0_0;
-
-#[allow(non_upper_case_globals)]
-static LCRModeMask: u8 = 0b1_11_1_1_11;
-
-#[allow(non_upper_case_globals)]
-static LSRTHREmpty: u8 = 0x20;
-
 mod reg {
   use volatile_cell::VolatileCell;
 
-  ioreg_old!(UART: u32, RBR_THR_DLL, DLM_IER, IIR_FCR, LCR, _pad_0, LSR, _pad_1, SCR, ACR, ICR, FDR, _pad_2, TER);
-  reg_r!( UART, u32, RBR,          RBR_THR_DLL);
-  reg_w!( UART, u32,      set_THR, RBR_THR_DLL);
-  reg_rw!(UART, u32, DLL, set_DLL, RBR_THR_DLL);
-  reg_rw!(UART, u32, DLM, set_DLM, DLM_IER);
-  reg_rw!(UART, u32, IER, set_IER, DLM_IER);
-  reg_r!( UART, u32, IIR,          IIR_FCR);
-  reg_w!( UART, u32,      set_FCR, IIR_FCR);
-  reg_rw!(UART, u32, LCR, set_LCR, LCR);
-  reg_r!( UART, u32, LSR,          LSR);
-  reg_rw!(UART, u32, SCR, set_SCR, SCR);
-  reg_rw!(UART, u32, ACR, set_ACR, ACR);
-  reg_rw!(UART, u32, ICR, set_ICR, ICR);
-  reg_rw!(UART, u32, FDR, set_FDR, FDR);
-  reg_rw!(UART, u32, TER, set_TER, TER);
+  ioregs!(UART = {
+    0x00 => reg32 rbr_thr {     //! Receiver buffer (read) / transmit holding (write) register, DLAB = 0
+      7..0 => data,
+    },
+    0x04 => reg32 ier {         //! Interrupt enable register, DLAB = 0
+      0 => rbr_int_enabled,     //= Receive data available interrupt enable
+      1 => thre_int_enabled,    //= THR empty interrupt enable
+      2 => rx_line_int_enabled, //= RX line status interrupt enable
+      8 => abeo_int_enabled,    //= End of auto-baud interrupt enable
+      9 => abto_int_enabled,    //= Auto-baud time-out interrupt enable
+    },
+    0x08 => reg32 iir {         //! Interrupt identification register
+      0    => no_int_pending : ro,
+      3..1 => int_id : ro {
+        0b011 => ReceiveLineStatus,
+        0b010 => ReceiveDataAvailable,
+        0b110 => CharacterTimeout,
+        0b001 => THREmpty,
+        0b000 => Modem,
+      },
+      7..6 => fifo_enabled : ro,
+      8    => abeo_int : ro,
+      9    => abto_int : ro,
+    },
+    0x0c => reg32 lcr {         //! Line control register
+      1..0 => word_length {
+        0 => Bits5,
+        1 => Bits6,
+        2 => Bits7,
+        3 => Bits8,
+      },
+      2    => two_stop_bits,
+      3    => parity_enabled,
+      5..4 => parity_select {
+        0 => Odd,
+        1 => Even,
+        2 => Forced1,
+        3 => Forced0,
+      },
+      6    => break_control,
+      7    => dlab,             //= Divisor latch access
+    },
+    0x14 => reg32 lsr {         //! Line status register
+      0 => rdr : ro,            //= Receiver data ready
+      1 => oe : ro,             //= Overrun error
+      2 => pe : ro,             //= Parity error
+      3 => fe : ro,             //= Framing error
+      4 => bi : ro,             //= Break interrupt
+      5 => thre : ro,           //= Transmitter holding register empty
+      6 => temt : ro,           //= Transmitter empty
+      7 => rxfe : ro,           //= Error in RX FIFO
+    },
+    0x1c => reg32 scr {         //! Scratch pad register
+      7..0 => value,
+    },
+    0x20 => reg32 acr {         //! Auto-baud control register
+      0 => start,
+      1 => mode,
+      2 => auto_restart,
+      8 => abeo_int_clear : wo,
+      9 => abto_int_clear : wo,
+    },
+    0x24 => reg32 icr {         //! IrDA control register
+      0    => irda_enabled,
+      1    => irda_invert,
+      2    => fixed_pulse_enabled,
+      5..3 => pulse_div,
+    },
+    0x28 => reg32 fdr {         //! Fractional divider register
+      3..0 => divaddval,
+      7..4 => mulval,
+    },
+    0x30 => reg32 ter {         //! Transmit enable register
+      7 => tx_enabled,
+    },
+  });
+
+  ioregs!(UART_ALIAS = {        //! Registers sharing addresses with `UART` ones
+    0x00 => reg32 dll {         //! Divisor latch LSB, DLAB = 1
+      7..0 => value,
+    },
+    0x04 => reg32 dlm {         //! Divisor latch MSB, DLAB = 1
+      7..0 => value,
+    },
+    0x08 => reg32 fcr {         //! FIFO control register
+      0    => fifo_enable : wo,
+      1    => rx_fifo_reset : wo,
+      2    => tx_fifo_reset : wo,
+      3    => dma_mode : wo,
+      7..6 => rx_trigger_level : wo {
+        0 => Trigger1Char,
+        1 => Trigger4Chars,
+        2 => Trigger8Chars,
+        3 => Trigger14Chars,
+      },
+    },
+  });
 
   extern {
     #[link_name="lpc17xx_iomem_UART0"] pub static UART0: UART;
     #[link_name="lpc17xx_iomem_UART2"] pub static UART2: UART;
     #[link_name="lpc17xx_iomem_UART3"] pub static UART3: UART;
+
+    #[link_name="lpc17xx_iomem_UART0_ALIAS"] pub static UART0_ALIAS: UART_ALIAS;
+    #[link_name="lpc17xx_iomem_UART2_ALIAS"] pub static UART2_ALIAS: UART_ALIAS;
+    #[link_name="lpc17xx_iomem_UART3_ALIAS"] pub static UART3_ALIAS: UART_ALIAS;
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::{UART, reg};
+  use hal::lpc17xx::peripheral_clock::PeripheralClock::UART0Clock;
+  use drivers::chario::CharIO;
+  use volatile_cell::{VolatileCellReplayer, set_replayer};
+  use volatile_cell::models::UartModel;
+  use expectest::prelude::*;
+  use expectest;
+
+  fn uart0() -> UART {
+    UART {
+      reg: unsafe { &*(0x4000_C000 as *const reg::UART) },
+      alias: unsafe { &*(0x4000_C000 as *const reg::UART_ALIAS) },
+      clock: UART0Clock,
+    }
+  }
+
+  #[test]
+  fn putc_waits_for_empty_transmit_holding_register() {
+    init_replayer!();
+
+    // LSR: THRE is set after a few polls, other status bits don't matter
+    expect_volatile_poll!(0x4000_C014, 0x0000_0001, 0x0000_0021, 3);
+    // THR: only the data bits are checked
+    expect_volatile_write_masked!(0x4000_C000, 'z' as u32, 0xff);
+
+    uart0().putc('z');
+
+    expect_replayer_valid!();
+  }
+
+  #[test]
+  fn puts_writes_each_character() {
+    init_replayer!();
+
+    for c in "ok".chars() {
+      expect_volatile_poll!(0x4000_C014, 0x0000_0000, 0x0000_0020, 2);
+      expect_volatile_write_masked!(0x4000_C000, c as u32, 0xff);
+    }
+
+    uart0().puts("ok");
+
+    expect_replayer_valid!();
+  }
+
+  #[test]
+  fn transmits_string_through_simulated_uart() {
+    init_replayer!();
+    // THRE sets two LSR reads after each write
+    let model = UartModel::new(0x4000_C000, 0x4000_C014, 0x20, 2);
+    let transmitted = model.transmitted();
+    add_volatile_model!(model);
+
+    uart0().puts("hello");
+
+    expect!(&transmitted.borrow()[..]).to(be_equal_to(&b"hello"[..]));
+    expect_replayer_valid!();
   }
 }
@@ -17,6 +17,7 @@ use std::rc::Rc;
 use syntax::ext::base::ExtCtxt;
 
 use builder::{Builder, TokenString, add_node_dependency};
+use builder::resources::Resource;
 use node;
 
 
@@ -42,14 +43,12 @@ pub fn verify(_: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
   node.expect_no_attributes(cx);
 }
 
-pub fn mutate_pins(builder: &mut Builder, _: &mut ExtCtxt, sub: Rc<node::Node>) {
+pub fn mutate_pins(builder: &mut Builder, cx: &mut ExtCtxt, sub: Rc<node::Node>) {
   let tx_node_name = sub.get_ref_attr("tx").unwrap();
   let rx_node_name = sub.get_ref_attr("rx").unwrap();
 
-  build_uart_gpio(builder, sub.path.as_str().parse().unwrap(),
-      tx_node_name.as_str(), true);
-  build_uart_gpio(builder, sub.path.as_str().parse().unwrap(),
-      rx_node_name.as_str(), false);
+  build_uart_gpio(builder, cx, &sub, tx_node_name.as_str(), true);
+  build_uart_gpio(builder, cx, &sub, rx_node_name.as_str(), false);
 }
 
 pub fn build_uart(builder: &mut Builder, cx: &mut ExtCtxt,
@@ -95,6 +94,12 @@ pub fn build_uart(builder: &mut Builder, cx: &mut ExtCtxt,
       }.to_string());
   let stop_bits = mode.as_str().chars().nth(2).unwrap().to_digit(10).unwrap() as u8;
 
+  if !builder.claim_resource(cx,
+      Resource::Peripheral(format!("uart{}", sub.path)),
+      &sub, sub.path_span) {
+    return
+  }
+
   sub.set_type_name("zinc::hal::lpc17xx::uart::UART".to_string());
   let uart_name = TokenString(sub.name.clone().unwrap());
 
@@ -109,9 +114,15 @@ pub fn build_uart(builder: &mut Builder, cx: &mut ExtCtxt,
   builder.add_main_statement(st);
 }
 
-pub fn build_uart_gpio(builder: &Builder, uart_idx: usize, name: &str,
-    istx: bool) {
+pub fn build_uart_gpio(builder: &mut Builder, cx: &ExtCtxt,
+    uart: &Rc<node::Node>, name: &str, istx: bool) {
+  let uart_idx: usize = uart.path.as_str().parse().unwrap();
   let node = builder.pt().get_by_name(name).unwrap();
+  let attr_span = uart.get_attr(if istx {"tx"} else {"rx"}).value_span;
+  if !builder.claim_resource(cx, Resource::pin_for_node(&node), uart,
+      attr_span) {
+    return
+  }
   let direction = (if istx {"out"} else {"in"}).to_string();
   let function = format!("{}{}", if istx {"txd"} else {"rxd"}, uart_idx);
   node.attributes.borrow_mut().insert("direction".to_string(),
@@ -0,0 +1,98 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Watchdog timer for LPC17xx.
+
+use hal::cortex_m3::irq::NoInterrupts;
+use hal::watchdog;
+
+/// The watchdog counts the 4MHz internal RC oscillator divided by 4.
+const TICKS_PER_MS: u32 = 1000;
+
+/// Smallest timeout the counter can be loaded with.
+const MIN_TICKS: u32 = 0xff;
+
+/// Watchdog timer, running from the internal RC oscillator.
+#[derive(Clone, Copy)]
+pub struct Wdt;
+
+impl watchdog::Watchdog for Wdt {
+  fn start(&self, timeout_ms: u32) {
+    let ticks = timeout_ms.saturating_mul(TICKS_PER_MS);
+
+    reg::WDT.wdclksel.set_wdsel(reg::WDT_wdclksel_wdsel::IRC);
+    reg::WDT.wdtc.set_count(if ticks < MIN_TICKS { MIN_TICKS } else { ticks });
+    reg::WDT.wdmod
+      .set_wden(true)
+      .set_wdreset(true);
+
+    // The watchdog only starts counting after the first feed
+    self.feed();
+  }
+
+  fn feed(&self) {
+    // Any other watchdog access between the two writes resets the MCU
+    let _crit = NoInterrupts::new();
+    reg::WDT.wdfeed.set_feed(0xaa);
+    reg::WDT.wdfeed.set_feed(0x55);
+  }
+
+  /// Returns the watchdog time-out flag, which is kept over resets until
+  /// cleared with `clear_reset_flag`.
+  fn caused_reset(&self) -> bool {
+    reg::WDT.wdmod.wdtof()
+  }
+}
+
+impl Wdt {
+  /// Clears the time-out flag reported by `caused_reset`.
+  pub fn clear_reset_flag(&self) {
+    reg::WDT.wdmod.set_wdtof(false);
+  }
+}
+
+mod reg {
+  use volatile_cell::VolatileCell;
+
+  ioregs!(WDT = {
+    0x00 => reg32 wdmod {       //! Watchdog mode
+      0 => wden,                //= Watchdog enable, can't be cleared
+      1 => wdreset,             //= Reset on time-out, can't be cleared
+      2 => wdtof,               //= Time-out flag
+      3 => wdint : ro,          //= Interrupt flag
+    },
+    0x04 => reg32 wdtc {        //! Watchdog timer constant
+      31..0 => count,           //= Time-out value in watchdog clocks
+    },
+    0x08 => reg32 wdfeed {      //! Watchdog feed sequence
+      7..0 => feed : wo,
+    },
+    0x0c => reg32 wdtv {        //! Watchdog timer value
+      31..0 => value : ro,
+    },
+    0x10 => reg32 wdclksel {    //! Watchdog clock source selection
+      1..0 => wdsel {           //! Clock source
+        0 => IRC,
+        1 => PCLK,
+        2 => RTCOscillator,
+      },
+      31 => wdlock,             //= Clock source can't be changed
+    },
+  });
+
+  extern {
+    #[link_name="lpc17xx_iomem_WDT"] pub static WDT: WDT;
+  }
+}
@@ -41,15 +41,25 @@ pub mod cortex_m3;
 pub mod cortex_m4;
 #[cfg(feature = "cpu_cortex-m7")]
 pub mod cortex_m7;
+#[cfg(any(feature = "cpu_cortex-m0",
+          feature = "cpu_cortex-m3",
+          feature = "cpu_cortex-m4",
+          feature = "cpu_cortex-m7"))]
+pub mod power;
 
+pub mod alarm;
+pub mod flash;
 pub mod mem_init;
 pub mod pin;
 pub mod pwm;
 pub mod spi;
 pub mod stack;
+pub mod time;
 pub mod timer;
 pub mod uart;
+pub mod watchdog;
 pub mod quadrature;
+pub mod reset;
 
 #[cfg(target_os = "none")]
 pub mod isr;
@@ -60,6 +60,83 @@ pub trait Gpio {
   }
 }
 
+/// Internal pull resistor configuration.
+#[derive(PartialEq, Clone, Copy)]
+pub enum GpioPull {
+  /// No pull resistor.
+  Floating,
+  /// Pull-up resistor.
+  PullUp,
+  /// Pull-down resistor.
+  PullDown,
+}
+
+/// Output speed, mapped to the slew rate and drive strength settings of the
+/// MCU. `Low` is the slowest and weakest configuration, `High` the fastest.
+#[derive(PartialEq, Clone, Copy)]
+pub enum GpioSpeed {
+  /// Slowest edges, least noise.
+  Low,
+  /// Medium edges.
+  Medium,
+  /// Fastest edges.
+  High,
+}
+
+/// Electrical configuration of a pin.
+pub trait PinConfig {
+  /// Selects the internal pull resistor.
+  fn set_pull(&self, pull: GpioPull);
+
+  /// Enables or disables the open-drain output.
+  fn set_open_drain(&self, enabled: bool);
+
+  /// Sets the output speed. MCUs without slew rate or drive strength control
+  /// ignore it.
+  fn set_speed(&self, speed: GpioSpeed);
+}
+
+/// Pin change triggering an interrupt.
+#[derive(PartialEq, Clone, Copy)]
+pub enum GpioTrigger {
+  /// Transition from low to high.
+  RisingEdge,
+  /// Transition from high to low.
+  FallingEdge,
+  /// Any transition.
+  BothEdges,
+  /// Logic low, for as long as the level persists.
+  LowLevel,
+  /// Logic high, for as long as the level persists.
+  HighLevel,
+}
+
+/// Function called by the MCU pin interrupt dispatcher.
+pub type InterruptHandler = fn();
+
+/// GPIO pin that can trigger interrupts.
+///
+/// Pin interrupts are handled by calling the `dispatch_interrupts()` function
+/// of the MCU pin module from the GPIO interrupt service routine, which calls
+/// the handlers of the pending pins and clears their interrupts.
+pub trait InterruptPin: Gpio {
+  /// Enables the interrupt on the given trigger. Not all MCUs support level
+  /// triggers, requesting one where it is unsupported aborts.
+  fn enable_interrupt(&self, trigger: GpioTrigger);
+
+  /// Disables the interrupt.
+  fn disable_interrupt(&self);
+
+  /// Returns true if the interrupt is pending.
+  fn is_interrupt_pending(&self) -> bool;
+
+  /// Clears the pending interrupt.
+  fn clear_interrupt(&self);
+
+  /// Sets the handler called by `dispatch_interrupts()` for this pin.
+  fn set_interrupt_handler(&self, handler: InterruptHandler);
+}
+
 /// Analog Input
 pub trait Adc {
   /// Read analog input value
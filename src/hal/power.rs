@@ -0,0 +1,46 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/*!
+Low-power modes.
+
+`sleep` stops the core clock until an interrupt arrives. `deep_sleep` also
+lets the MCU stop its clocks, what exactly is turned off and which sources can
+wake it up are MCU-specific. MCUs with more modes, like stop and standby on
+STM32, provide them in their `power` module along with the wakeup sources.
+*/
+
+use hal::cortex_common::scb;
+use util::support::wfi;
+
+/// Sleeps until an interrupt arrives.
+pub fn sleep() {
+  scb::set_sleep_deep(false);
+  wfi();
+}
+
+/// Sleeps with clocks stopped until a wakeup event. The clock configuration
+/// may have to be restored afterwards.
+pub fn deep_sleep() {
+  scb::set_sleep_deep(true);
+  wfi();
+  scb::set_sleep_deep(false);
+}
+
+/// Returns to sleep after every interrupt handler instead of to the main
+/// program, for programs running entirely from interrupts.
+pub fn sleep_on_exit(enabled: bool) {
+  scb::set_sleep_on_exit(enabled);
+}
@@ -0,0 +1,126 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/*!
+System reset and reset cause.
+
+Each MCU module provides `reset::reset_cause()`, decoding its reset status
+register, and `reset::clear_reset_cause()`. Reset flags usually accumulate
+until cleared, so the cause should be read and cleared early after boot.
+*/
+
+/// Cause of the last reset.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ResetCause {
+  /// Power was applied.
+  PowerOn,
+  /// Supply voltage dropped below the brown-out threshold.
+  BrownOut,
+  /// A watchdog expired.
+  Watchdog,
+  /// Software requested a reset.
+  Software,
+  /// The reset pin was asserted.
+  Pin,
+  /// The core locked up after a fault in a fault handler.
+  Lockup,
+  /// None of the above, or the MCU doesn't report it.
+  Unknown,
+}
+
+/// Reset flags, as reported by an MCU.
+#[allow(missing_docs)]
+#[derive(Clone, Copy)]
+pub struct ResetFlags {
+  pub power_on: bool,
+  pub brown_out: bool,
+  pub watchdog: bool,
+  pub software: bool,
+  pub pin: bool,
+  pub lockup: bool,
+}
+
+impl ResetFlags {
+  /// Returns the cause of the reset. Several flags can be set at once, e.g.
+  /// a power-on reset also sets the brown-out and pin flags on some MCUs,
+  /// so the most fundamental cause wins.
+  pub fn cause(&self) -> ResetCause {
+    if self.power_on {
+      ResetCause::PowerOn
+    } else if self.brown_out {
+      ResetCause::BrownOut
+    } else if self.watchdog {
+      ResetCause::Watchdog
+    } else if self.lockup {
+      ResetCause::Lockup
+    } else if self.software {
+      ResetCause::Software
+    } else if self.pin {
+      ResetCause::Pin
+    } else {
+      ResetCause::Unknown
+    }
+  }
+}
+
+/// Resets the MCU.
+#[cfg(any(feature = "cpu_cortex-m0",
+          feature = "cpu_cortex-m3",
+          feature = "cpu_cortex-m4",
+          feature = "cpu_cortex-m7"))]
+pub fn system_reset() -> ! {
+  super::cortex_common::scb::system_reset()
+}
+
+#[cfg(test)]
+mod test {
+  use super::{ResetCause, ResetFlags};
+  use expectest::prelude::*;
+
+  fn no_flags() -> ResetFlags {
+    ResetFlags {
+      power_on: false,
+      brown_out: false,
+      watchdog: false,
+      software: false,
+      pin: false,
+      lockup: false,
+    }
+  }
+
+  #[test]
+  fn power_on_wins_over_other_flags() {
+    let flags = ResetFlags { power_on: true, brown_out: true, pin: true,
+        ..no_flags() };
+    expect!(flags.cause()).to(be_equal_to(ResetCause::PowerOn));
+  }
+
+  #[test]
+  fn watchdog_wins_over_pin() {
+    let flags = ResetFlags { watchdog: true, pin: true, ..no_flags() };
+    expect!(flags.cause()).to(be_equal_to(ResetCause::Watchdog));
+  }
+
+  #[test]
+  fn decodes_single_flags() {
+    expect!(ResetFlags { software: true, ..no_flags() }.cause())
+        .to(be_equal_to(ResetCause::Software));
+    expect!(ResetFlags { lockup: true, ..no_flags() }.cause())
+        .to(be_equal_to(ResetCause::Lockup));
+    expect!(ResetFlags { pin: true, ..no_flags() }.cause())
+        .to(be_equal_to(ResetCause::Pin));
+    expect!(no_flags().cause()).to(be_equal_to(ResetCause::Unknown));
+  }
+}
@@ -0,0 +1,284 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/*!
+Real-time clock interface and calendar.
+
+`DateTime` is a calendar date and time of day, without a time zone. It
+converts to and from seconds since 1970-01-01 00:00:00, which is how some RTCs
+count time and a compact format for timestamps. Timestamps are 32 bits, so
+only years 1970 to 2105 are supported.
+*/
+
+use core::option::Option;
+use core::result::Result;
+
+/// RTC errors.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Error {
+  /// The date is outside of the years the RTC counts.
+  OutOfRange,
+}
+
+/// Seconds in a day.
+const SECONDS_PER_DAY: u32 = 86400;
+
+/// Days from 0000-03-01 to 1970-01-01 in the proleptic Gregorian calendar.
+const DAYS_TO_EPOCH: u32 = 719468;
+
+/// Days in a 400 year cycle.
+const DAYS_PER_ERA: u32 = 146097;
+
+/// Day of the week.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Weekday {
+  Monday,
+  Tuesday,
+  Wednesday,
+  Thursday,
+  Friday,
+  Saturday,
+  Sunday,
+}
+
+impl Weekday {
+  /// Returns the ISO 8601 number of the day, 1 for Monday to 7 for Sunday.
+  pub fn number_from_monday(&self) -> u8 {
+    *self as u8 + 1
+  }
+
+  fn from_days_since_monday(days: u32) -> Weekday {
+    match days % 7 {
+      0 => Weekday::Monday,
+      1 => Weekday::Tuesday,
+      2 => Weekday::Wednesday,
+      3 => Weekday::Thursday,
+      4 => Weekday::Friday,
+      5 => Weekday::Saturday,
+      _ => Weekday::Sunday,
+    }
+  }
+}
+
+/// Returns true if `year` has a February 29.
+pub fn is_leap_year(year: u16) -> bool {
+  (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days in `month` (1 to 12) of `year`.
+pub fn days_in_month(year: u16, month: u8) -> u8 {
+  match month {
+    4 | 6 | 9 | 11 => 30,
+    2 => if is_leap_year(year) { 29 } else { 28 },
+    _ => 31,
+  }
+}
+
+/// Calendar date and time of day.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DateTime {
+  /// Year, from 1970.
+  pub year: u16,
+  /// Month, 1 to 12.
+  pub month: u8,
+  /// Day of the month, from 1.
+  pub day: u8,
+  /// Hour, 0 to 23.
+  pub hour: u8,
+  /// Minute, 0 to 59.
+  pub minute: u8,
+  /// Second, 0 to 59.
+  pub second: u8,
+}
+
+impl DateTime {
+  /// Creates a date and time, or returns None if it doesn't exist.
+  pub fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8)
+      -> Option<DateTime> {
+    let valid = year >= 1970 && year <= 2105 &&
+        month >= 1 && month <= 12 &&
+        day >= 1 && day <= days_in_month(year, month) &&
+        hour < 24 && minute < 60 && second < 60;
+    if valid {
+      Some(DateTime {
+        year: year,
+        month: month,
+        day: day,
+        hour: hour,
+        minute: minute,
+        second: second,
+      })
+    } else {
+      None
+    }
+  }
+
+  /// Creates the date and time `timestamp` seconds after 1970-01-01 00:00:00.
+  pub fn from_timestamp(timestamp: u32) -> DateTime {
+    let (year, month, day) = civil_from_days(timestamp / SECONDS_PER_DAY);
+    let seconds = timestamp % SECONDS_PER_DAY;
+    DateTime {
+      year: year,
+      month: month,
+      day: day,
+      hour: (seconds / 3600) as u8,
+      minute: (seconds / 60 % 60) as u8,
+      second: (seconds % 60) as u8,
+    }
+  }
+
+  /// Returns the seconds since 1970-01-01 00:00:00.
+  pub fn timestamp(&self) -> u32 {
+    self.days_since_epoch() * SECONDS_PER_DAY +
+        self.hour as u32 * 3600 + self.minute as u32 * 60 + self.second as u32
+  }
+
+  /// Returns the day of the week.
+  pub fn weekday(&self) -> Weekday {
+    // 1970-01-01 was a Thursday
+    Weekday::from_days_since_monday(self.days_since_epoch() + 3)
+  }
+
+  /// Returns the day of the year, from 1.
+  pub fn day_of_year(&self) -> u16 {
+    let mut days = self.day as u16;
+    for month in 1..self.month {
+      days += days_in_month(self.year, month) as u16;
+    }
+    days
+  }
+
+  fn days_since_epoch(&self) -> u32 {
+    // Counts years from March, so that the leap day is the last day of a year
+    let year = (if self.month <= 2 { self.year - 1 } else { self.year }) as u32;
+    let month = self.month as u32;
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2)
+        / 5 + self.day as u32 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 +
+        day_of_year;
+    era * DAYS_PER_ERA + day_of_era - DAYS_TO_EPOCH
+  }
+}
+
+fn civil_from_days(days: u32) -> (u16, u8, u8) {
+  let days = days + DAYS_TO_EPOCH;
+  let era = days / DAYS_PER_ERA;
+  let day_of_era = days - era * DAYS_PER_ERA;
+  let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 -
+      day_of_era / (DAYS_PER_ERA - 1)) / 365;
+  let day_of_year = day_of_era -
+      (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_from_march = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+  let month = if month_from_march < 10 {
+    month_from_march + 3
+  } else {
+    month_from_march - 9
+  };
+  let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+  (year as u16, month as u8, day as u8)
+}
+
+/// Real-time clock.
+pub trait Rtc {
+  /// Sets the current date and time, or fails with `OutOfRange` if the RTC
+  /// can't count its year.
+  fn set_time(&self, time: &DateTime) -> Result<(), Error>;
+
+  /// Returns the current date and time.
+  fn time(&self) -> DateTime;
+
+  /// Sets the alarm to fire at `time`. The alarm interrupt can wake the MCU
+  /// up from low-power modes, see the MCU `rtc` module for which ones. Fails
+  /// with `OutOfRange` if the RTC can't count the year of `time`.
+  fn set_alarm(&self, time: &DateTime) -> Result<(), Error>;
+
+  /// Disables the alarm.
+  fn disable_alarm(&self);
+
+  /// Returns true if the alarm fired.
+  fn alarm_pending(&self) -> bool;
+
+  /// Clears the fired alarm.
+  fn clear_alarm(&self);
+}
+
+/// Registers kept while the main supply is off, as long as the RTC is
+/// powered.
+pub trait BackupRegisters {
+  /// Returns the number of registers.
+  fn backup_len(&self) -> usize;
+
+  /// Reads the register at `index`, which must be less than `backup_len`.
+  fn read_backup(&self, index: usize) -> u32;
+
+  /// Writes the register at `index`, which must be less than `backup_len`.
+  fn write_backup(&self, index: usize, value: u32);
+}
+
+#[cfg(test)]
+mod test {
+  use super::{DateTime, Weekday, days_in_month, is_leap_year};
+  use expectest::prelude::*;
+
+  #[test]
+  fn knows_leap_years() {
+    expect!(is_leap_year(2024)).to(be_true());
+    expect!(is_leap_year(2000)).to(be_true());
+    expect!(is_leap_year(2100)).to(be_equal_to(false));
+    expect!(is_leap_year(2023)).to(be_equal_to(false));
+    expect!(days_in_month(2024, 2)).to(be_equal_to(29));
+    expect!(days_in_month(2100, 2)).to(be_equal_to(28));
+  }
+
+  #[test]
+  fn validates_dates() {
+    expect!(DateTime::new(2024, 2, 29, 23, 59, 59).is_some()).to(be_true());
+    expect!(DateTime::new(2023, 2, 29, 0, 0, 0).is_none()).to(be_true());
+    expect!(DateTime::new(2023, 4, 31, 0, 0, 0).is_none()).to(be_true());
+    expect!(DateTime::new(2023, 1, 1, 24, 0, 0).is_none()).to(be_true());
+    expect!(DateTime::new(1969, 12, 31, 0, 0, 0).is_none()).to(be_true());
+  }
+
+  #[test]
+  fn converts_timestamps() {
+    let epoch = DateTime::new(1970, 1, 1, 0, 0, 0).unwrap();
+    expect!(epoch.timestamp()).to(be_equal_to(0));
+
+    let leap_day = DateTime::new(2000, 2, 29, 12, 30, 15).unwrap();
+    expect!(leap_day.timestamp()).to(be_equal_to(951827415));
+    expect!(DateTime::from_timestamp(951827415)).to(be_equal_to(leap_day));
+
+    let last = DateTime {
+      year: 2106, month: 2, day: 7, hour: 6, minute: 28, second: 15 };
+    expect!(DateTime::from_timestamp(0xffff_ffff)).to(be_equal_to(last));
+    expect!(last.timestamp()).to(be_equal_to(0xffff_ffff));
+  }
+
+  #[test]
+  fn computes_weekday_and_day_of_year() {
+    let date = DateTime::new(2000, 1, 1, 0, 0, 0).unwrap();
+    expect!(date.weekday()).to(be_equal_to(Weekday::Saturday));
+    expect!(date.day_of_year()).to(be_equal_to(1));
+
+    let date = DateTime::new(2024, 12, 31, 0, 0, 0).unwrap();
+    expect!(date.weekday()).to(be_equal_to(Weekday::Tuesday));
+    expect!(date.day_of_year()).to(be_equal_to(366));
+    expect!(date.weekday().number_from_monday()).to(be_equal_to(2));
+  }
+}
@@ -0,0 +1,135 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! EXTI driver shared by the STM32 families, included by their `exti` module.
+//!
+//! The family module provides the `EXTI` and `SYSCFG` statics,
+//! `enable_port_selection` and `port_selection`.
+
+use core::intrinsics::abort;
+
+use hal::pin::{GpioTrigger, InterruptHandler};
+use super::{EXTI, SYSCFG, enable_port_selection, port_selection};
+use super::super::pin::Port;
+
+/// Number of EXTI lines connected to GPIO pins.
+pub const GPIO_LINES: usize = 16;
+
+/// Interrupt handlers of the GPIO lines.
+static mut HANDLERS: [Option<InterruptHandler>; GPIO_LINES] = [None; GPIO_LINES];
+
+/// Routes GPIO line `line` to the pin of the same index on `port`.
+pub fn select_port(line: usize, port: Port) {
+  enable_port_selection();
+  SYSCFG.exticr[line / 4].set_exti(line % 4, port_selection(port));
+}
+
+/// Enables the interrupt of `line` on the edges selected by `trigger`.
+///
+/// EXTI lines are edge triggered only, level triggers abort.
+pub fn enable(line: usize, trigger: GpioTrigger) {
+  let (rising, falling) = match trigger {
+    GpioTrigger::RisingEdge  => (true, false),
+    GpioTrigger::FallingEdge => (false, true),
+    GpioTrigger::BothEdges   => (true, true),
+    _                        => unsafe { abort() },
+  };
+
+  EXTI.rtsr.set_tr(line, rising);
+  EXTI.ftsr.set_tr(line, falling);
+  EXTI.imr.set_mr(line, true);
+}
+
+/// Disables the interrupt of `line`.
+pub fn disable(line: usize) {
+  EXTI.imr.set_mr(line, false);
+}
+
+/// Returns true if `line` has a pending interrupt.
+pub fn is_pending(line: usize) -> bool {
+  EXTI.pr.pr(line)
+}
+
+/// Clears the pending interrupt of `line`.
+pub fn clear(line: usize) {
+  EXTI.pr.clear_pr(line);
+}
+
+/// Returns the IRQ shared by GPIO line `line`.
+pub fn irq(line: usize) -> usize {
+  match line {
+    0...4   => 6 + line,
+    5...9   => 23,
+    10...15 => 40,
+    _       => unsafe { abort() },
+  }
+}
+
+/// Sets the handler called by `dispatch_interrupts` for GPIO line `line`.
+pub fn set_handler(line: usize, handler: InterruptHandler) {
+  unsafe { HANDLERS[line] = Some(handler) };
+}
+
+/// Calls the handlers of GPIO lines with pending interrupts and clears them.
+/// To be called from the EXTI interrupt service routines.
+pub fn dispatch_interrupts() {
+  let pending = EXTI.pr.get().raw() & EXTI.imr.get().raw();
+  for line in 0..GPIO_LINES {
+    if pending & (1 << line) == 0 {
+      continue;
+    }
+    EXTI.pr.clear_pr(line);
+    match unsafe { HANDLERS[line] } {
+      Some(handler) => handler(),
+      None => (),
+    }
+  }
+}
+
+/// Registers of the largest EXTI, lines past the last one of a family are
+/// reserved.
+#[allow(dead_code)]
+pub mod reg {
+  use core::ops::Drop;
+  use volatile_cell::VolatileCell;
+
+  ioregs!(EXTI = {
+    0x00 => reg32 imr {         //! Interrupt mask register
+      0..23 => mr[24],
+    }
+    0x04 => reg32 emr {         //! Event mask register
+      0..23 => mr[24],
+    }
+    0x08 => reg32 rtsr {        //! Rising trigger selection register
+      0..23 => tr[24],
+    }
+    0x0c => reg32 ftsr {        //! Falling trigger selection register
+      0..23 => tr[24],
+    }
+    0x10 => reg32 swier {       //! Software interrupt event register
+      0..23 => swier[24],
+    }
+    0x14 => reg32 pr {          //! Pending register
+      0..23 => pr[24]: set_to_clear,
+    }
+  });
+
+  // AFIO on STM32F1, its EXTICR registers are at the same offset
+  ioregs!(SYSCFG = {
+    0x08 => reg32 exticr[4] {   //! External interrupt configuration registers
+      0..15 => exti[4],         //= Port of the EXTI line
+    }
+  });
+}
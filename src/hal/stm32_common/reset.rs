@@ -0,0 +1,43 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reset cause shared by the STM32 families, included by their `reset`
+//! module.
+//!
+//! The family module provides `power_on_reset` and `brown_out_reset`, as
+//! the RCC_CSR power flags differ between families.
+
+use hal::reset::{ResetCause, ResetFlags};
+use super::{power_on_reset, brown_out_reset};
+use super::super::init::reg;
+
+/// Returns the cause of the last reset. Low-power resets are reported as
+/// `Unknown`.
+pub fn reset_cause() -> ResetCause {
+  let csr = reg::RCC.csr.get();
+  ResetFlags {
+    power_on: power_on_reset(&csr),
+    brown_out: brown_out_reset(&csr),
+    watchdog: csr.independent_watchdog_reset() || csr.window_watchdog_reset(),
+    software: csr.software_reset(),
+    pin: csr.pin_reset(),
+    lockup: false,
+  }.cause()
+}
+
+/// Clears the reset flags.
+pub fn clear_reset_cause() {
+  reg::RCC.csr.set_remove_reset(true);
+}
@@ -0,0 +1,174 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! IWDG and WWDG drivers shared by the STM32 families, included by their
+//! `watchdog` module.
+//!
+//! The family module provides the `IWDG` and `WWDG` statics, `LSI_HZ` and
+//! `enable_wwdg_clock`, and the `wait_for!` macro.
+
+use core::cell::Cell;
+
+use hal::watchdog;
+use super::{IWDG, WWDG, LSI_HZ, enable_wwdg_clock};
+use super::super::init::reg::RCC;
+
+/// Independent watchdog, running from the LSI oscillator.
+#[derive(Clone, Copy)]
+pub struct Iwdg;
+
+impl watchdog::Watchdog for Iwdg {
+  /// Starts the watchdog, also starting the LSI oscillator. The LSI frequency
+  /// varies between parts, so the timeout is approximate.
+  fn start(&self, timeout_ms: u32) {
+    use self::reg::IWDG_kr_key::*;
+
+    let ticks = timeout_ms.saturating_mul(LSI_HZ / 1000);
+    // Use the smallest divider, 4 << prescaler, the timeout fits in
+    let mut prescaler = 0;
+    while prescaler < 6 && ticks / (4 << prescaler) > 0xfff {
+      prescaler += 1;
+    }
+    let reload = ticks / (4 << prescaler);
+
+    IWDG.kr.set_key(Start);
+    IWDG.kr.set_key(Unlock);
+    wait_for!(!IWDG.sr.pvu() && !IWDG.sr.rvu());
+    IWDG.pr.set_pr(prescaler);
+    IWDG.rlr.set_rl(if reload > 0xfff { 0xfff } else { reload });
+    wait_for!(!IWDG.sr.pvu() && !IWDG.sr.rvu());
+    self.feed();
+  }
+
+  fn feed(&self) {
+    IWDG.kr.set_key(reg::IWDG_kr_key::Reload);
+  }
+
+  fn caused_reset(&self) -> bool {
+    RCC.csr.independent_watchdog_reset()
+  }
+}
+
+/// Window watchdog, running from the APB1 clock.
+pub struct Wwdg {
+  apb1_frequency: u32,
+  counter: Cell<u32>,
+}
+
+impl Wwdg {
+  /// Creates the window watchdog driver, for an APB1 clock running at
+  /// `apb1_frequency`.
+  pub fn new(apb1_frequency: u32) -> Wwdg {
+    Wwdg {
+      apb1_frequency: apb1_frequency,
+      counter: Cell::new(0x7f),
+    }
+  }
+
+  /// Watchdog counter frequency, in ticks per second.
+  fn tick_hz(&self) -> u32 {
+    self.apb1_frequency / 4096 / (1 << WWDG.cfr.wdgtb())
+  }
+}
+
+impl watchdog::Watchdog for Wwdg {
+  /// Starts the watchdog. The counter is 6 bits wide, so the longest timeout
+  /// is 64 * 4096 * 8 APB1 clocks.
+  fn start(&self, timeout_ms: u32) {
+    enable_wwdg_clock();
+
+    // Use the smallest divider, 1 << wdgtb, the timeout fits in
+    let mut wdgtb = 0;
+    let mut ticks;
+    loop {
+      WWDG.cfr.set_wdgtb(wdgtb);
+      ticks = (timeout_ms as u64 * self.tick_hz() as u64 / 1000) as u32;
+      if ticks <= 0x40 || wdgtb == 3 {
+        break;
+      }
+      wdgtb += 1;
+    }
+    let ticks = match ticks {
+      0 => 1,
+      1...0x40 => ticks,
+      _ => 0x40,
+    };
+
+    // The MCU resets when the counter goes from 0x40 to 0x3f
+    self.counter.set(0x3f + ticks);
+    WWDG.cfr.set_w(0x7f);
+    WWDG.cr
+      .set_t(self.counter.get())
+      .set_wdga(true);
+  }
+
+  fn feed(&self) {
+    WWDG.cr.set_t(self.counter.get());
+  }
+
+  fn caused_reset(&self) -> bool {
+    RCC.csr.window_watchdog_reset()
+  }
+}
+
+impl watchdog::WindowedWatchdog for Wwdg {
+  /// Sets the window relative to the timeout of the last `start`.
+  fn set_window(&self, min_ms: u32) {
+    let ticks = (min_ms as u64 * self.tick_hz() as u64 / 1000) as u32;
+    let counter = self.counter.get();
+    let window = if counter < 0x40 + ticks { 0x40 } else { counter - ticks };
+    WWDG.cfr.set_w(window);
+  }
+}
+
+pub mod reg {
+  use volatile_cell::VolatileCell;
+  use core::ops::Drop;
+
+  ioregs!(IWDG = {
+    0x00 => reg32 kr {         // key
+      15..0 => key : wo {
+        0xaaaa => Reload,
+        0x5555 => Unlock,
+        0xcccc => Start,
+      }
+    },
+    0x04 => reg32 pr {         // prescaler, divides by 4 << pr
+      2..0 => pr : rw,
+    },
+    0x08 => reg32 rlr {        // reload
+      11..0 => rl : rw,
+    },
+    0x0c => reg32 sr {         // status
+      0 => pvu : ro,           // prescaler update in progress
+      1 => rvu : ro,           // reload update in progress
+    },
+  });
+
+  ioregs!(WWDG = {
+    0x00 => reg32 cr {         // control
+      6..0 => t : rw,          // counter
+      7    => wdga : rw,       // activation, can't be cleared
+    },
+    0x04 => reg32 cfr {        // configuration
+      6..0 => w : rw,          // window value
+      8..7 => wdgtb : rw,      // timer base, divides by 1 << wdgtb
+      9    => ewi : rw,        // early wakeup interrupt
+    },
+    0x08 => reg32 sr {         // status
+      0 => ewif : rw,          // early wakeup interrupt flag
+    },
+  });
+}
@@ -0,0 +1,257 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use syntax::ext::base::ExtCtxt;
+use syntax::ext::build::AstBuilder;
+
+use builder::{Builder, TokenString};
+use builder::clock::{Factor, PllLimits, format_frequency};
+use builder::clock;
+use node;
+
+/// PLL limits with HSE input, see RM0008 chapter 7.2.3.
+static PLL_HSE_LIMITS: PllLimits = PllLimits {
+  input_div: Factor::Range(1, 2),
+  mult: Factor::Range(2, 16),
+  output_div: Factor::Range(1, 1),
+  vco_scale: 1,
+  input_range: (1_000_000, 25_000_000),
+  vco_range: (16_000_000, 72_000_000),
+  max_output: 72_000_000,
+};
+
+/// PLL limits with HSI input, which is always divided by 2.
+static PLL_HSI_LIMITS: PllLimits = PllLimits {
+  input_div: Factor::Set(&[2]),
+  mult: Factor::Range(2, 16),
+  output_div: Factor::Range(1, 1),
+  vco_scale: 1,
+  input_range: (1_000_000, 25_000_000),
+  vco_range: (16_000_000, 72_000_000),
+  max_output: 72_000_000,
+};
+
+const HSI_FREQUENCY: usize = 8_000_000;
+const MAX_APB1_FREQUENCY: usize = 36_000_000;
+const USB_FREQUENCY: usize = 48_000_000;
+
+pub fn attach(_: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.materializer.set(Some(build_clock as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+}
+
+fn build_clock(builder: &mut Builder, cx: &mut ExtCtxt,
+    node: Rc<node::Node>) {
+  if !node.expect_attributes(cx, &[("source", node::StrAttribute)]) {
+    return;
+  }
+
+  let source = node.get_string_attr("source").unwrap();
+  let source_freq = match source.as_str() {
+    "hsi" => HSI_FREQUENCY,
+    "hse" => match node.get_required_int_attr(cx, "source_frequency") {
+      Some(freq) => freq,
+      None => return,
+    },
+    other => {
+      cx.span_err(node.get_attr("source").value_span,
+          format!("unknown oscillator value `{}`, allowed values: \
+                  `hsi`, `hse`", other).as_str());
+      return;
+    },
+  };
+
+  let (sysfreq, clock_source) = match node.get_int_attr("target_frequency") {
+    None => {
+      (source_freq, if source == "hsi" {
+        "init::SystemClockSource::SystemClockHSI".to_string()
+      } else {
+        format!("init::SystemClockSource::SystemClockHSE({}u32)", source_freq)
+      })
+    },
+    Some(target) => {
+      let limits = if source == "hsi" { &PLL_HSI_LIMITS } else { &PLL_HSE_LIMITS };
+      let span = node.get_attr("target_frequency").value_span;
+      let solution = match clock::solve_pll(source_freq, target, limits) {
+        Some(solution) => solution,
+        None => {
+          cx.span_err(span,
+              format!("can't generate {} from {}, max sysclk is {}",
+                  format_frequency(target), format_frequency(source_freq),
+                  format_frequency(limits.max_output)).as_str());
+          return;
+        },
+      };
+      if solution.output != target {
+        cx.parse_sess().span_diagnostic.span_warn(span,
+            format!("can't generate exactly {} from {}, using {}",
+                format_frequency(target), format_frequency(source_freq),
+                format_frequency(solution.output)).as_str());
+      }
+
+      let pll_source = if source == "hsi" {
+        "init::PllClockSource::PllSourceHSIDiv2".to_string()
+      } else {
+        format!("init::PllClockSource::PllSourceHSE({}u32)", source_freq)
+      };
+      // USB clock is either PLL / 1 or PLL / 1.5.
+      let (usb_prescaler, usb_freq) = if solution.output == USB_FREQUENCY {
+        ("PllUsbDiv1", solution.output)
+      } else {
+        ("PllUsbDiv1p5", solution.output * 2 / 3)
+      };
+      builder.add_report(format!(
+          "stm32f1 clock: sysclk = {} (PLL from {}, prediv = {}, mult = {}), \
+          usb = {}",
+          format_frequency(solution.output), source, solution.input_div,
+          solution.mult, format_frequency(usb_freq)));
+
+      (solution.output, format!(
+          "init::SystemClockSource::SystemClockPLL(init::PllConf {{
+            source: {},
+            mult: init::PllMult::PllMul{},
+            hse_prediv: init::PllHsePrediv::PllHsePrediv{},
+            usb_prescaler: init::PllUsbDiv::{},
+          }})", pll_source, solution.mult,
+          if source == "hsi" { 1 } else { solution.input_div },
+          usb_prescaler))
+    },
+  };
+
+  let (apb1_prescaler, apb1_freq) = if sysfreq > MAX_APB1_FREQUENCY {
+    ("ApbDiv2", sysfreq / 2)
+  } else {
+    ("ApbDivNone", sysfreq)
+  };
+  let wait_states = flash_wait_states(sysfreq);
+  builder.add_report(format!(
+      "stm32f1 clock: ahb = {}, apb1 = {}, apb2 = {}, flash wait states = {}",
+      format_frequency(sysfreq), format_frequency(apb1_freq),
+      format_frequency(sysfreq), wait_states));
+
+  node.attributes.borrow_mut().insert("system_frequency".to_string(),
+      Rc::new(node::Attribute::new_nosp(node::IntValue(sysfreq))));
+
+  let clock_source = TokenString(clock_source);
+  let apb1_prescaler = TokenString(apb1_prescaler.to_string());
+  let flash_latency = TokenString(format!("FlashLatency{}", wait_states));
+  let ex = quote_expr!(&*cx,
+      {
+        use zinc::hal::stm32f1::init;
+        init::ClockConfig {
+          source: $clock_source,
+          ahb_prescaler: init::ClockAhbPrescaler::AhbDivNone,
+          apb1_prescaler: init::ClockApbPrescaler::$apb1_prescaler,
+          apb2_prescaler: init::ClockApbPrescaler::ApbDivNone,
+          flash_latency: init::FlashLatency::$flash_latency,
+          mco: init::McoSource::McoClockNone,
+        }.setup();
+      }
+  );
+  builder.add_main_statement(cx.stmt_expr(ex));
+}
+
+/// Flash wait states, see RM0008 chapter 3.3.3.
+fn flash_wait_states(freq: usize) -> usize {
+  match freq {
+    0...24_000_000 => 0,
+    24_000_001...48_000_000 => 1,
+    _ => 2,
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use builder::Builder;
+  use test_helpers::{assert_equal_source, with_parsed};
+
+  #[test]
+  fn builds_pll_clock_for_target_frequency() {
+    with_parsed("
+      clock {
+        source = \"hse\";
+        source_frequency = 8_000_000;
+        target_frequency = 72_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      let node = pt.get_by_path("clock").unwrap();
+      super::build_clock(&mut builder, cx, node.clone());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 1);
+      assert!(node.get_int_attr("system_frequency") == Some(72_000_000));
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "{
+            use zinc::hal::stm32f1::init;
+            init::ClockConfig {
+              source: init::SystemClockSource::SystemClockPLL(init::PllConf {
+                source: init::PllClockSource::PllSourceHSE(8000000u32),
+                mult: init::PllMult::PllMul9,
+                hse_prediv: init::PllHsePrediv::PllHsePrediv1,
+                usb_prescaler: init::PllUsbDiv::PllUsbDiv1p5,
+              }),
+              ahb_prescaler: init::ClockAhbPrescaler::AhbDivNone,
+              apb1_prescaler: init::ClockApbPrescaler::ApbDiv2,
+              apb2_prescaler: init::ClockApbPrescaler::ApbDivNone,
+              flash_latency: init::FlashLatency::FlashLatency2,
+              mco: init::McoSource::McoClockNone,
+            }.setup();
+          }");
+    });
+  }
+
+  #[test]
+  fn builds_hsi_pll_clock() {
+    with_parsed("
+      clock {
+        source = \"hsi\";
+        target_frequency = 48_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_clock(&mut builder, cx, pt.get_by_path("clock").unwrap());
+      assert!(unsafe{*failed} == false);
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "{
+            use zinc::hal::stm32f1::init;
+            init::ClockConfig {
+              source: init::SystemClockSource::SystemClockPLL(init::PllConf {
+                source: init::PllClockSource::PllSourceHSIDiv2,
+                mult: init::PllMult::PllMul12,
+                hse_prediv: init::PllHsePrediv::PllHsePrediv1,
+                usb_prescaler: init::PllUsbDiv::PllUsbDiv1,
+              }),
+              ahb_prescaler: init::ClockAhbPrescaler::AhbDivNone,
+              apb1_prescaler: init::ClockApbPrescaler::ApbDiv2,
+              apb2_prescaler: init::ClockApbPrescaler::ApbDivNone,
+              flash_latency: init::FlashLatency::FlashLatency1,
+              mco: init::McoSource::McoClockNone,
+            }.setup();
+          }");
+    });
+  }
+
+  #[test]
+  fn fails_to_build_hsi_above_pll_range() {
+    with_parsed("
+      clock {
+        source = \"hsi\";
+        target_frequency = 80_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_clock(&mut builder, cx, pt.get_by_path("clock").unwrap());
+      assert!(unsafe{*failed} == true);
+    });
+  }
+}
@@ -15,9 +15,12 @@
 
 //! HAL for STM32F1.
 
+pub mod exti;
 pub mod init;
 pub mod peripheral_clock;
 pub mod pin;
+pub mod reset;
 pub mod spi;
 pub mod timer;
 pub mod usart;
+pub mod watchdog;
@@ -24,8 +24,6 @@ use core::marker::Copy;
 
 pub use self::PeripheralClock::*;
 
-#[path="../../util/ioreg.rs"] mod ioreg;
-
 #[allow(missing_docs)]
 #[repr(u8)]
 #[derive(Clone)]
@@ -18,10 +18,14 @@
 //! Some pins that could be configured here may be missing from actual MCU
 //! depending on the package.
 
+use hal::cortex_m3::nvic;
+use hal::pin::{GpioTrigger, InterruptHandler, InterruptPin};
+use super::exti;
 use super::peripheral_clock;
-use core::intrinsics::abort;
 use self::Port::*;
 
+pub use super::exti::dispatch_interrupts;
+
 /// Available port names.
 #[allow(missing_docs)]
 #[repr(u8)]
@@ -67,6 +71,8 @@ pub enum PinConf {
 pub struct Pin {
   /// Pin index.
   pub index: u8,
+  /// Pin port.
+  port: Port,
   /// GPIO register
   reg: &'static reg::GPIO,
 }
@@ -111,20 +117,36 @@ impl Pin {
       OutOpenDrainAlt50MHz => 0b11_11,
     };
 
-    let offset = (pin_index % 8) as usize * 4;
-    let mask = !(0xFu32 << offset);
+    let pin = Pin {
+      index: pin_index,
+      port: port,
+      reg: reg,
+    };
+    pin.set_conf(conf);
+    pin
+  }
 
-    if pin_index < 8 {
-        let mode: u32 = reg.crlr.crl() & mask;
-        reg.crlr.set_crl(mode | (conf << offset));
+  /// Returns the 4-bit CNF/MODE configuration of the pin.
+  fn conf(&self) -> u32 {
+    let offset = (self.index % 8) as usize * 4;
+    let conf: u32 = if self.index < 8 {
+      self.reg.crlr.crl()
     } else {
-        let mode: u32 = reg.crhr.crh() & mask;
-        reg.crhr.set_crh(mode | (conf << offset));
-    }
+      self.reg.crhr.crh()
+    };
+    (conf >> offset) & 0xF
+  }
 
-    Pin {
-      index: pin_index,
-      reg: reg,
+  fn set_conf(&self, conf: u32) {
+    let offset = (self.index % 8) as usize * 4;
+    let mask = !(0xFu32 << offset);
+
+    if self.index < 8 {
+        let mode: u32 = self.reg.crlr.crl() & mask;
+        self.reg.crlr.set_crl(mode | (conf << offset));
+    } else {
+        let mode: u32 = self.reg.crhr.crh() & mask;
+        self.reg.crhr.set_crh(mode | (conf << offset));
     }
   }
 }
@@ -149,9 +171,81 @@ impl ::hal::pin::Gpio for Pin {
     }
   }
 
-  fn set_direction(&self, _new_mode: ::hal::pin::GpioDirection) {
-    //TODO(kvark)
-    unsafe { abort() }
+  /// Sets the pin direction. CNF bits mean different things for inputs and
+  /// outputs, so the pin becomes a floating input or a 2 MHz push-pull
+  /// output and the pull and open-drain configuration has to be redone.
+  fn set_direction(&self, new_mode: ::hal::pin::GpioDirection) {
+    self.set_conf(match new_mode {
+      ::hal::pin::In  => 0b01_00,
+      ::hal::pin::Out => 0b00_10,
+    });
+  }
+}
+
+impl ::hal::pin::PinConfig for Pin {
+  /// Selects the pull resistor, which is only available on inputs. The pin is
+  /// configured as an input and the output latch selects the resistor.
+  fn set_pull(&self, pull: ::hal::pin::GpioPull) {
+    use hal::pin::{Gpio, GpioPull};
+
+    match pull {
+      GpioPull::Floating => self.set_conf(0b01_00),
+      GpioPull::PullUp   => { self.set_high(); self.set_conf(0b10_00) },
+      GpioPull::PullDown => { self.set_low(); self.set_conf(0b10_00) },
+    }
+  }
+
+  /// Enables or disables the open-drain output, ignored on inputs.
+  fn set_open_drain(&self, enabled: bool) {
+    let conf = self.conf();
+    if conf & 0b11 == 0 {
+      return;
+    }
+    let od: u32 = if enabled { 0b01_00 } else { 0 };
+    self.set_conf((conf & !0b01_00) | od);
+  }
+
+  /// Sets the maximum output speed: 2, 10 or 50 MHz, ignored on inputs.
+  fn set_speed(&self, speed: ::hal::pin::GpioSpeed) {
+    use hal::pin::GpioSpeed;
+
+    let conf = self.conf();
+    if conf & 0b11 == 0 {
+      return;
+    }
+    let mode: u32 = match speed {
+      GpioSpeed::Low    => 0b10,
+      GpioSpeed::Medium => 0b01,
+      GpioSpeed::High   => 0b11,
+    };
+    self.set_conf((conf & !0b11) | mode);
+  }
+}
+
+impl InterruptPin for Pin {
+  /// Routes the pin to its EXTI line and enables the line interrupt, only edge
+  /// triggers are supported.
+  fn enable_interrupt(&self, trigger: GpioTrigger) {
+    let line = self.index as usize;
+    exti::select_port(line, self.port);
+    exti::enable(line, trigger);
+    nvic::enable_irq(exti::irq(line));
+  }
+
+  fn disable_interrupt(&self) {
+    exti::disable(self.index as usize);
+  }
+
+  fn is_interrupt_pending(&self) -> bool {
+    exti::is_pending(self.index as usize)
+  }
+
+  fn clear_interrupt(&self) {
+    exti::clear(self.index as usize);
+  }
+
+  fn set_interrupt_handler(&self, handler: InterruptHandler) {
+    exti::set_handler(self.index as usize, handler);
   }
 }
 
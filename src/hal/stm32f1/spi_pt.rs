@@ -0,0 +1,154 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::rc::Rc;
+use syntax::ext::base::ExtCtxt;
+
+use builder::{Builder, TokenString, add_node_dependency};
+use builder::resources::Resource;
+use node;
+
+pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.materializer.set(Some(verify as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  for spi_node in node.subnodes().iter() {
+    spi_node.materializer.set(Some(build_spi as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+    add_node_dependency(&node, spi_node);
+    super::add_node_dependency_on_clock(builder, spi_node);
+  }
+}
+
+pub fn verify(_: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.expect_no_attributes(cx);
+}
+
+fn build_spi(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
+  if !node.expect_no_subnodes(cx) {return}
+
+  if !node.expect_attributes(cx, &[("prescaler_shift", node::IntAttribute)]) {
+    return
+  }
+
+  if node.name.is_none() {
+    cx.parse_sess().span_diagnostic.span_err(node.name_span,
+        "SPI node must have a name");
+    return
+  }
+
+  let spi_index = match node.path.as_str().parse::<usize>() {
+    Ok(i @ 1...3) => i,
+    _ => {
+      cx.parse_sess().span_diagnostic.span_err(node.path_span,
+          format!("unknown SPI `{}`, allowed values: 1, 2, 3",
+              node.path).as_str());
+      return
+    }
+  };
+
+  let prescaler_shift = match node.get_int_attr("prescaler_shift").unwrap() {
+    shift @ 1...8 => shift as u8,
+    other => {
+      cx.parse_sess().span_diagnostic.span_err(
+          node.get_attr("prescaler_shift").value_span,
+          format!("invalid prescaler_shift `{}`, allowed values: 1...8",
+              other).as_str());
+      return
+    }
+  };
+
+  if !builder.claim_resource(cx,
+      Resource::Peripheral(format!("spi{}", spi_index)),
+      &node, node.path_span) {
+    return
+  }
+
+  let spi_peripheral = TokenString(format!(
+      "zinc::hal::stm32f1::spi::Peripheral::Spi{}", spi_index));
+  let name = TokenString(node.name.clone().unwrap());
+
+  node.set_type_name("zinc::hal::stm32f1::spi::Spi".to_string());
+
+  // The prescaler shift is validated above, so Spi::new can only fail on a
+  // mode fault, which leaves nothing to recover.
+  let st = quote_stmt!(&*cx,
+      let $name = zinc::hal::stm32f1::spi::Spi::new(
+          $spi_peripheral,
+          zinc::hal::stm32f1::spi::Direction::FullDuplex,
+          zinc::hal::stm32f1::spi::Role::Master,
+          zinc::hal::stm32f1::spi::DataSize::U8,
+          zinc::hal::stm32f1::spi::DataFormat::MsbFirst,
+          $prescaler_shift).unwrap_or_else(|_| core::intrinsics::abort());
+  ).unwrap();
+  builder.add_main_statement(st);
+}
+
+#[cfg(test)]
+mod test {
+  use builder::Builder;
+  use test_helpers::{assert_equal_source, with_parsed};
+
+  #[test]
+  fn builds_spi() {
+    with_parsed("
+      spi {
+        bus@1 {
+          prescaler_shift = 3;
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_spi(&mut builder, cx, pt.get_by_name("bus").unwrap());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 1);
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "let bus = zinc::hal::stm32f1::spi::Spi::new(
+              zinc::hal::stm32f1::spi::Peripheral::Spi1,
+              zinc::hal::stm32f1::spi::Direction::FullDuplex,
+              zinc::hal::stm32f1::spi::Role::Master,
+              zinc::hal::stm32f1::spi::DataSize::U8,
+              zinc::hal::stm32f1::spi::DataFormat::MsbFirst,
+              3u8).unwrap_or_else(|_| core::intrinsics::abort());");
+    });
+  }
+
+  #[test]
+  fn fails_to_build_unknown_spi() {
+    with_parsed("
+      spi {
+        bus@0 {
+          prescaler_shift = 3;
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_spi(&mut builder, cx, pt.get_by_name("bus").unwrap());
+      assert!(unsafe{*failed} == true);
+      assert!(builder.main_stmts().len() == 0);
+    });
+  }
+
+  #[test]
+  fn fails_to_build_invalid_prescaler() {
+    with_parsed("
+      spi {
+        bus@2 {
+          prescaler_shift = 9;
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_spi(&mut builder, cx, pt.get_by_name("bus").unwrap());
+      assert!(unsafe{*failed} == true);
+      assert!(builder.main_stmts().len() == 0);
+    });
+  }
+}
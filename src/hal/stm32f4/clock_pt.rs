@@ -0,0 +1,245 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::rc::Rc;
+use syntax::ext::base::ExtCtxt;
+use syntax::ext::build::AstBuilder;
+
+use builder::{Builder, TokenString};
+use builder::clock::{Factor, PllLimits, format_frequency};
+use builder::clock;
+use node;
+
+/// Main PLL limits, see RM0090 chapter 6.3.2.
+static PLL_LIMITS: PllLimits = PllLimits {
+  input_div: Factor::Range(2, 63),
+  mult: Factor::Range(50, 432),
+  output_div: Factor::Step(2, 8, 2),
+  vco_scale: 1,
+  input_range: (1_000_000, 2_000_000),
+  vco_range: (192_000_000, 432_000_000),
+  max_output: 168_000_000,
+};
+
+/// USB OTG FS, SDIO and RNG need 48MHz (or less for SDIO and RNG).
+const PLL48_FREQUENCY: usize = 48_000_000;
+
+/// `init::ClockConf` always divides APB1 by 4 and APB2 by 2.
+const APB1_DIVISOR: usize = 4;
+const APB2_DIVISOR: usize = 2;
+
+/// `init::ClockConf` doesn't configure flash wait states for HSE.
+const MAX_HSE_SYSCLK: usize = 30_000_000;
+
+pub fn attach(_: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.materializer.set(Some(build_clock as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+}
+
+fn build_clock(builder: &mut Builder, cx: &mut ExtCtxt,
+    node: Rc<node::Node>) {
+  if !node.expect_attributes(cx, &[("source", node::StrAttribute)]) {
+    return;
+  }
+
+  let source = node.get_string_attr("source").unwrap();
+  let (source_freq, pll_source) = match source.as_str() {
+    "hsi" => (16_000_000, "init::PLLClockSource::PLLClockHSI".to_string()),
+    "hse" => match node.get_required_int_attr(cx, "source_frequency") {
+      Some(freq) => (freq,
+          format!("init::PLLClockSource::PLLClockHSE({}u32)", freq)),
+      None => return,
+    },
+    other => {
+      cx.span_err(node.get_attr("source").value_span,
+          format!("unknown oscillator value `{}`, allowed values: \
+                  `hsi`, `hse`", other).as_str());
+      return;
+    },
+  };
+
+  let (sysfreq, clock_source) = match node.get_int_attr("target_frequency") {
+    None => {
+      if source == "hse" && source_freq > MAX_HSE_SYSCLK {
+        cx.span_err(node.get_attr("source_frequency").value_span,
+            format!("HSE above {} requires `target_frequency` to be set",
+                format_frequency(MAX_HSE_SYSCLK)).as_str());
+        return;
+      }
+      builder.add_report(format!("stm32f4 clock: sysclk = {} ({})",
+          format_frequency(source_freq), source));
+      (source_freq, if source == "hsi" {
+        "init::SystemClockSource::SystemClockHSI".to_string()
+      } else {
+        format!("init::SystemClockSource::SystemClockHSE({}u32)", source_freq)
+      })
+    },
+    Some(target) => {
+      let span = node.get_attr("target_frequency").value_span;
+      let solution = match clock::solve_pll(source_freq, target, &PLL_LIMITS) {
+        Some(solution) => solution,
+        None => {
+          cx.span_err(span,
+              format!("can't generate {} from {}, max sysclk is {}",
+                  format_frequency(target), format_frequency(source_freq),
+                  format_frequency(PLL_LIMITS.max_output)).as_str());
+          return;
+        },
+      };
+      if solution.output != target {
+        cx.parse_sess().span_diagnostic.span_warn(span,
+            format!("can't generate exactly {} from {}, using {}",
+                format_frequency(target), format_frequency(source_freq),
+                format_frequency(solution.output)).as_str());
+      }
+
+      let q = pll48_divisor(solution.vco);
+      builder.add_report(format!(
+          "stm32f4 clock: sysclk = {} (PLL from {}, m = {}, n = {}, p = {}, \
+          q = {}, VCO = {}), flash wait states = {}",
+          format_frequency(solution.output), source, solution.input_div,
+          solution.mult, solution.output_div, q,
+          format_frequency(solution.vco), flash_wait_states(solution.output)));
+      builder.add_report(format!(
+          "stm32f4 clock: ahb = {}, apb1 = {}, apb2 = {}, pll48 = {}",
+          format_frequency(solution.output),
+          format_frequency(solution.output / APB1_DIVISOR),
+          format_frequency(solution.output / APB2_DIVISOR),
+          format_frequency(solution.vco / q)));
+
+      (solution.output, format!(
+          "init::SystemClockSource::SystemClockPLL(init::PLLConf {{
+            source: {},
+            m: {}u8,
+            n: {}u16,
+            p: {}u8,
+            q: {}u8,
+          }})", pll_source, solution.input_div, solution.mult,
+          solution.output_div, q))
+    },
+  };
+
+  node.attributes.borrow_mut().insert("system_frequency".to_string(),
+      Rc::new(node::Attribute::new_nosp(node::IntValue(sysfreq))));
+
+  let clock_source = TokenString(clock_source);
+  let ex = quote_expr!(&*cx,
+      {
+        use zinc::hal::stm32f4::init;
+        init::SysConf {
+          clock: init::ClockConf {
+            source: $clock_source,
+          },
+        }.setup();
+      }
+  );
+  builder.add_main_statement(cx.stmt_expr(ex));
+}
+
+/// Returns the smallest PLLQ divisor that keeps PLL48CLK at or below 48MHz.
+fn pll48_divisor(vco: usize) -> usize {
+  let mut q = 2;
+  while q < 15 && vco / q > PLL48_FREQUENCY {
+    q += 1;
+  }
+  q
+}
+
+/// Flash wait states for 2.7-3.6V range, see RM0090 table 10.
+fn flash_wait_states(freq: usize) -> usize {
+  (freq - 1) / 30_000_000
+}
+
+#[cfg(test)]
+mod test {
+  use builder::Builder;
+  use test_helpers::{assert_equal_source, with_parsed};
+
+  #[test]
+  fn builds_hsi_clock() {
+    with_parsed("
+      clock {
+        source = \"hsi\";
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_clock(&mut builder, cx, pt.get_by_path("clock").unwrap());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 1);
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "{
+            use zinc::hal::stm32f4::init;
+            init::SysConf {
+              clock: init::ClockConf {
+                source: init::SystemClockSource::SystemClockHSI,
+              },
+            }.setup();
+          }");
+    });
+  }
+
+  #[test]
+  fn builds_pll_clock_for_target_frequency() {
+    with_parsed("
+      clock {
+        source = \"hse\";
+        source_frequency = 8_000_000;
+        target_frequency = 168_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      let node = pt.get_by_path("clock").unwrap();
+      super::build_clock(&mut builder, cx, node.clone());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 1);
+      assert!(node.get_int_attr("system_frequency") == Some(168_000_000));
+      assert!(builder.reports().len() == 2);
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "{
+            use zinc::hal::stm32f4::init;
+            init::SysConf {
+              clock: init::ClockConf {
+                source: init::SystemClockSource::SystemClockPLL(init::PLLConf {
+                  source: init::PLLClockSource::PLLClockHSE(8000000u32),
+                  m: 4u8,
+                  n: 168u16,
+                  p: 2u8,
+                  q: 7u8,
+                }),
+              },
+            }.setup();
+          }");
+    });
+  }
+
+  #[test]
+  fn fails_to_build_target_frequency_above_limit() {
+    with_parsed("
+      clock {
+        source = \"hsi\";
+        target_frequency = 180_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_clock(&mut builder, cx, pt.get_by_path("clock").unwrap());
+      assert!(unsafe{*failed} == true);
+      assert!(builder.main_stmts().len() == 0);
+    });
+  }
+
+  #[test]
+  fn calculates_pll48_divisor() {
+    assert!(super::pll48_divisor(336_000_000) == 7);
+    assert!(super::pll48_divisor(432_000_000) == 9);
+  }
+}
@@ -0,0 +1,43 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! External interrupt/event controller (EXTI) for ST STM32F4.
+//!
+//! Lines 0...15 are shared by the pins of the same index on all ports, the
+//! port driving a line is selected with `select_port`.
+//!
+//! Lines 16...22 are wired to internal sources: PVD, RTC alarm, USB OTG FS
+//! wakeup, Ethernet wakeup, USB OTG HS wakeup, RTC tamper and RTC wakeup.
+
+use super::peripheral_clock::PeripheralClock;
+use super::pin::Port;
+
+#[path="../stm32_common/exti.rs"]
+mod common;
+
+pub use self::common::*;
+
+fn enable_port_selection() {
+  PeripheralClock::SYSCFGClock.enable();
+}
+
+fn port_selection(port: Port) -> u32 {
+  port as u32
+}
+
+extern {
+  #[link_name="stm32f4_iomem_EXTI"] static EXTI: common::reg::EXTI;
+  #[link_name="stm32f4_iomem_SYSCFG"] static SYSCFG: common::reg::SYSCFG;
+}
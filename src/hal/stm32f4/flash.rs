@@ -0,0 +1,176 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Flash programming for ST STM32F4.
+//!
+//! Pages are the 128 KB sectors from sector 5, at 0x08020000; the smaller
+//! sectors below usually hold the program. Words are programmed 32 bits at a
+//! time, which needs a supply of 2.7 V to 3.6 V, the same range `init` picks
+//! the flash latency for.
+
+use core::intrinsics::{volatile_load, volatile_store};
+use core::result::Result;
+use core::result::Result::{Ok, Err};
+
+use hal::flash::{self, Error};
+use super::init::reg::FLASH;
+
+#[path="../../util/wait_for.rs"]
+#[macro_use] mod wait_for;
+
+/// Address of the first page.
+const BASE_ADDRESS: usize = 0x0802_0000;
+
+/// Sector number of the first page.
+const BASE_SECTOR: usize = 5;
+
+/// Size of a page.
+const PAGE_SIZE: usize = 128 * 1024;
+
+/// Parallelism of 32 bits.
+const PROGRAM_SIZE_X32: u32 = 0b10;
+
+/// Flash memory controller.
+#[derive(Clone, Copy)]
+pub struct Flash {
+  pages: usize,
+}
+
+impl Flash {
+  /// Creates the controller of `pages` 128 KB sectors, 3 on 512 KB parts and
+  /// 7 on 1 MB parts.
+  pub fn new(pages: usize) -> Flash {
+    Flash { pages: pages }
+  }
+
+  fn address(&self, page: usize, offset: usize) -> usize {
+    BASE_ADDRESS + page * PAGE_SIZE + offset
+  }
+
+  /// Waits for the operation to end and returns its error, if any.
+  fn finish(&self) -> Result<(), Error> {
+    wait_for!(!FLASH.sr.busy());
+
+    let sr = FLASH.sr.get();
+    let result = if sr.write_protection_error() {
+      Err(Error::Protected)
+    } else if sr.programming_alignment_error() ||
+        sr.programming_parallelism_error() {
+      Err(Error::Alignment)
+    } else if sr.programming_sequence_error() || sr.operation_error() {
+      Err(Error::Failed)
+    } else {
+      Ok(())
+    };
+
+    FLASH.sr
+      .clear_end_of_operation()
+      .clear_operation_error()
+      .clear_write_protection_error()
+      .clear_programming_alignment_error()
+      .clear_programming_parallelism_error()
+      .clear_programming_sequence_error();
+    result
+  }
+}
+
+impl flash::Flash for Flash {
+  fn page_size(&self) -> usize {
+    PAGE_SIZE
+  }
+
+  fn page_count(&self) -> usize {
+    self.pages
+  }
+
+  fn write_size(&self) -> usize {
+    4
+  }
+
+  fn unlock(&self) {
+    if FLASH.cr.lock() {
+      FLASH.keyr.set_key(0x4567_0123);
+      FLASH.keyr.set_key(0xcdef_89ab);
+    }
+  }
+
+  fn lock(&self) {
+    FLASH.cr.set_lock(true);
+  }
+
+  fn is_protected(&self, page: usize) -> bool {
+    // Sectors are protected when their nWRP bit is cleared
+    FLASH.optcr.write_protection() & (1 << (BASE_SECTOR + page)) == 0
+  }
+
+  fn erase(&self, page: usize) -> Result<(), Error> {
+    if page >= self.pages {
+      return Err(Error::OutOfRange);
+    }
+    if FLASH.cr.lock() {
+      return Err(Error::Locked);
+    }
+
+    wait_for!(!FLASH.sr.busy());
+    FLASH.cr
+      .set_program_size(PROGRAM_SIZE_X32)
+      .set_sector_number((BASE_SECTOR + page) as u32)
+      .set_sector_erase(true);
+    FLASH.cr.set_start(true);
+    let result = self.finish();
+    FLASH.cr.set_sector_erase(false);
+    result
+  }
+
+  fn program(&self, page: usize, offset: usize, data: &[u8])
+      -> Result<(), Error> {
+    if offset % 4 != 0 || data.len() % 4 != 0 {
+      return Err(Error::Alignment);
+    }
+    if page >= self.pages || offset + data.len() > PAGE_SIZE {
+      return Err(Error::OutOfRange);
+    }
+    if FLASH.cr.lock() {
+      return Err(Error::Locked);
+    }
+
+    wait_for!(!FLASH.sr.busy());
+    FLASH.cr
+      .set_program_size(PROGRAM_SIZE_X32)
+      .set_program(true);
+
+    let mut result = Ok(());
+    for (i, word) in data.chunks(4).enumerate() {
+      let value = word[0] as u32 | (word[1] as u32) << 8 |
+          (word[2] as u32) << 16 | (word[3] as u32) << 24;
+      let address = self.address(page, offset + i * 4) as *mut u32;
+      unsafe { volatile_store(address, value) };
+      result = self.finish();
+      if result.is_err() {
+        break;
+      }
+    }
+
+    FLASH.cr.set_program(false);
+    result
+  }
+
+  fn read(&self, page: usize, offset: usize, data: &mut [u8]) {
+    let address = self.address(page, offset);
+    for i in 0..data.len() {
+      data[i] = unsafe { volatile_load((address + i) as *const u8) };
+    }
+  }
+}
@@ -21,8 +21,6 @@
 use hal::mem_init::init_data;
 use core::intrinsics::abort;
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
 #[path="../../util/wait_for.rs"]
 #[macro_use] mod wait_for;
 
@@ -129,7 +127,7 @@ impl ClockConf {
           unsafe { abort() };
         } else {
           self.enable_hse();
-          self.set_system_clock(reg::SystemClockSwitch::SystemClockHSE);
+          self.set_system_clock(reg::RCC_cfgr_system_clock::HSE);
           unsafe {
             SystemClock = freq;
             APBLowClock = freq;  // no divisor
@@ -145,11 +143,11 @@ impl ClockConf {
         let sysfreq: u32 = match pll_conf.source {
           PLLClockHSE(freq) => freq,
           PLLClockHSI       => 16_000_000,
-        } as u32 * (pll_conf.n as u32 / pll_conf.m as u32) / pll_conf.p as u32;
+        } as u32 / pll_conf.m as u32 * pll_conf.n as u32 / pll_conf.p as u32;
         // system_stm32f4xx.c enables PWR and sets VOS to 1 here, but VOS
         // defaults to 1 so I see no real reason to do that.
         // peripheral_clock::PWRClock.enable();
-        // reg::PWR.set_CR(reg::PWR.CR() | 0x0000C000);
+        // reg::PWR.cr.set_vos(true);
 
         // TODO(farcaller): this should be configureable via ClockConf
         let apb_low_divisor = 4;
@@ -157,7 +155,7 @@ impl ClockConf {
         pll_conf.setup();
         // TODO(farcaller): this doesn't really belong here.
         self.setup_flash(sysfreq);
-        self.set_system_clock(reg::SystemClockSwitch::SystemClockPLL);
+        self.set_system_clock(reg::RCC_cfgr_system_clock::PLL);
         unsafe {
           SystemClock = sysfreq;
           APBLowClock = sysfreq / apb_low_divisor as u32;
@@ -167,11 +165,11 @@ impl ClockConf {
   }
 
   fn setup_flash(&self, freq: u32) {
-    reg::FLASH.set_ACR(
-      (1 << 8)  |  // enable prefetch
-      (1 << 9)  |  // enable instruction cache
-      (1 << 10) |  // enable data cache
-      match freq/1_000_000 {  // wait states are calculated for 2.7-3.6V range
+    reg::FLASH.acr
+      .set_prefetch_on(true)
+      .set_icache_on(true)
+      .set_dcache_on(true)
+      .set_latency(match freq/1_000_000 {  // wait states are calculated for 2.7-3.6V range
         0...30    => 0,
         31...60   => 1,
         61...90   => 2,
@@ -179,96 +177,76 @@ impl ClockConf {
         121...150 => 4,
         151...168 => 5,
         _        => unsafe { abort() },
-      }
-    );
+      });
   }
 
   fn enable_hse(&self) {
-    let val = reg::RCC.CR();
-    let hse_on_bit: u32 = 1 << 16;
-    let hse_ready_bit: u32 = 1 << 17;
-    reg::RCC.set_CR(val | hse_on_bit);
-
-    wait_for!(reg::RCC.CR() & hse_ready_bit == hse_ready_bit);
+    reg::RCC.cr.set_hse_on(true);
+    wait_for!(reg::RCC.cr.hse_ready());
   }
 
-  fn set_system_clock(&self, clock: reg::SystemClockSwitch) {
-    let val = reg::RCC.CFGR();
-    let bits: u32 = clock as u32;
-    let mask: u32 = !0b1111;
-
-    reg::RCC.set_CFGR((val & mask) | bits);
+  fn set_system_clock(&self, clock: reg::RCC_cfgr_system_clock) {
+    reg::RCC.cfgr.set_system_clock(clock);
   }
 
   fn set_clock_divisors(&self, ahb: u16, apb_low_speed: u8, apb_hi_speed: u8) {
-    let val = reg::RCC.CFGR();
-    let mask: u32 = !0b111_111_00_1111_0000;
-    let bits: u32 = (match ahb {
-      1   => 0b0000,
-      2   => 0b1000,
-      4   => 0b1001,
-      8   => 0b1010,
-      16  => 0b1011,
-      64  => 0b1100,
-      128 => 0b1101,
-      256 => 0b1110,
-      512 => 0b1111,
-      _   => unsafe { abort() },
-    } << 4) | (match apb_low_speed {
-      1   => 0b000,
-      2   => 0b100,
-      4   => 0b101,
-      8   => 0b110,
-      16  => 0b111,
-      _   => unsafe { abort() },
-    } << 10) | (match apb_hi_speed {
-      1   => 0b000,
-      2   => 0b100,
-      4   => 0b101,
-      8   => 0b110,
-      16  => 0b111,
-      _   => unsafe { abort() },
-    } << 13);
+    use self::reg::RCC_cfgr_ahb_prescaler::*;
+
+    reg::RCC.cfgr
+      .set_ahb_prescaler(match ahb {
+        1   => AhbDiv1,
+        2   => AhbDiv2,
+        4   => AhbDiv4,
+        8   => AhbDiv8,
+        16  => AhbDiv16,
+        64  => AhbDiv64,
+        128 => AhbDiv128,
+        256 => AhbDiv256,
+        512 => AhbDiv512,
+        _   => unsafe { abort() }})
+      .set_apb1_prescaler(apb_divisor(apb_low_speed))
+      .set_apb2_prescaler(apb_divisor(apb_hi_speed));
+  }
+}
 
-    reg::RCC.set_CFGR((val & mask) | bits);
+fn apb_divisor(div: u8) -> u32 {
+  match div {
+    1   => 0b000,
+    2   => 0b100,
+    4   => 0b101,
+    8   => 0b110,
+    16  => 0b111,
+    _   => unsafe { abort() },
   }
 }
 
 impl PLLConf {
   fn setup(&self) {
     use self::PLLClockSource::*;
-
-    // TODO(farcaller): cmsis code overrides reserved bits in here, is that ok?
-    let val = reg::RCC.PLLCFGR();
-    let mask: u32 = !0b0000_1111_0_1_0000_11_0_111111111_111111;
-    let bits: u32 =
-      self.m as u32 |
-      ((self.n as u32) << 6) |
-      (match self.p {
-        2 => 0b00u32,
-        4 => 0b01u32,
-        6 => 0b10u32,
-        8 => 0b11u32,
+    use self::reg::RCC_pllcfgr_sysclk_prescaler::*;
+
+    reg::RCC.pllcfgr
+      .set_pll_div_factor(self.m as u32)
+      .set_pll_mul_factor(self.n as u32)
+      .set_sysclk_prescaler(match self.p {
+        2 => PllDiv2,
+        4 => PllDiv4,
+        6 => PllDiv6,
+        8 => PllDiv8,
         _ => unsafe { abort() },
-      } << 16) |
-      (match self.source {
-        PLLClockHSI    => 0u32,
-        PLLClockHSE(_) => 1u32,
-      } << 22) |
-      ((self.q as u32) << 24);
-
-    reg::RCC.set_PLLCFGR(val & mask | bits);
+      })
+      .set_pll_clock_source_hse(match self.source {
+        PLLClockHSI    => false,
+        PLLClockHSE(_) => true,
+      })
+      .set_usb_prescaler(self.q as u32);
 
     self.enable_pll();
   }
 
   fn enable_pll(&self) {
-    let val = reg::RCC.CR();
-    let pll_on_bit: u32 = 1 << 24;
-    let pll_ready_bit: u32 = 1 << 25;
-    reg::RCC.set_CR(val | pll_on_bit);
-
-    wait_for!(reg::RCC.CR() & pll_ready_bit == pll_ready_bit);
+    reg::RCC.cr.set_pll_on(true);
+    wait_for!(reg::RCC.cr.pll_ready());
   }
 }
 
@@ -279,58 +257,211 @@ impl PLLConf {
 pub mod reg {
   use volatile_cell::VolatileCell;
 
-  #[derive(Clone, Copy)]
-  pub enum SystemClockSwitch {
-    SystemClockHSI = 0,
-    SystemClockHSE = 1,
-    SystemClockPLL = 2,
-  }
-
-  ioreg_old!(RCCReg: u32, CR, PLLCFGR, CFGR, CIR, AHB1RSTR, AHB2RSTR, AHB3RSTR,
-                      _pad_0, APB1RSTR, APB2RSTR, _pad_1, _pad_2, AHB1ENR,
-                      AHB2ENR, AHB3ENR, _pad_3, APB1ENR, APB2ENR, _pad_4,
-                      _pad_5, AHB1LPENR, AHB2LPENR, AHB3LPENR, _pad_6,
-                      APB1LPENR, APB2LPENR, _pad_7, _pad_8, BDCR, CSR, _pad_9,
-                      _pad_10, SSCGR, PLLI2SCFGR);
-  reg_rw!(RCCReg, u32, CR,         set_CR,         CR);
-  reg_rw!(RCCReg, u32, PLLCFGR,    set_PLLCFGR,    PLLCFGR);
-  reg_rw!(RCCReg, u32, CFGR,       set_CFGR,       CFGR);
-  reg_rw!(RCCReg, u32, CIR,        set_CIR,        CIR);
-  reg_rw!(RCCReg, u32, AHB1RSTR,   set_AHB1RSTR,   AHB1RSTR);
-  reg_rw!(RCCReg, u32, AHB2RSTR,   set_AHB2RSTR,   AHB2RSTR);
-  reg_rw!(RCCReg, u32, AHB3RSTR,   set_AHB3RSTR,   AHB3RSTR);
-  reg_rw!(RCCReg, u32, APB1RSTR,   set_APB1RSTR,   APB1RSTR);
-  reg_rw!(RCCReg, u32, APB2RSTR,   set_APB2RSTR,   APB2RSTR);
-  reg_rw!(RCCReg, u32, AHB1ENR,    set_AHB1ENR,    AHB1ENR);
-  reg_rw!(RCCReg, u32, AHB2ENR,    set_AHB2ENR,    AHB2ENR);
-  reg_rw!(RCCReg, u32, AHB3ENR,    set_AHB3ENR,    AHB3ENR);
-  reg_rw!(RCCReg, u32, APB1ENR,    set_APB1ENR,    APB1ENR);
-  reg_rw!(RCCReg, u32, APB2ENR,    set_APB2ENR,    APB2ENR);
-  reg_rw!(RCCReg, u32, AHB1LPENR,  set_AHB1LPENR,  AHB1LPENR);
-  reg_rw!(RCCReg, u32, AHB2LPENR,  set_AHB2LPENR,  AHB2LPENR);
-  reg_rw!(RCCReg, u32, AHB3LPENR,  set_AHB3LPENR,  AHB3LPENR);
-  reg_rw!(RCCReg, u32, APB1LPENR,  set_APB1LPENR,  APB1LPENR);
-  reg_rw!(RCCReg, u32, APB2LPENR,  set_APB2LPENR,  APB2LPENR);
-  reg_rw!(RCCReg, u32, BDCR,       set_BDCR,       BDCR);
-  reg_rw!(RCCReg, u32, CSR,        set_CSR,        CSR);
-  reg_rw!(RCCReg, u32, SSCGR,      set_SSCGR,      SSCGR);
-  reg_rw!(RCCReg, u32, PLLI2SCFGR, set_PLLI2SCFGR, PLLI2SCFGR);
-
-  ioreg_old!(FLASHReg: u32, ACR, KEYR, OPTKEYR, SR, CR, OPTCR);
-  reg_rw!(FLASHReg, u32, ACR,   set_ACR,     ACR);
-  reg_w!(FLASHReg,  u32,        set_KEYR,    KEYR);
-  reg_w!(FLASHReg,  u32,        set_OPTKEYR, OPTKEYR);
-  reg_rw!(FLASHReg, u32, SR,    set_SR,      SR);
-  reg_rw!(FLASHReg, u32, CR,    set_CR,      CR);
-  reg_rw!(FLASHReg, u32, OPTCR, set_OPTCR,   OPTCR);
-
-  ioreg_old!(PWRReg: u32, CR, CSR);
-  reg_rw!(PWRReg, u32, CR,  set_CR,  CR);
-  reg_rw!(PWRReg, u32, CSR, set_CSR, CSR);
+  ioregs!(RCC = {
+    0x00 => reg32 cr {          // clock control
+      0     => hsi_on : rw,
+      1     => hsi_ready : ro,
+      7..3  => hsi_trim : rw,
+      15..8 => hsi_cal : ro,
+      16    => hse_on : rw,
+      17    => hse_ready : ro,
+      18    => hse_bypass : rw,
+      19    => css_on : rw,
+      24    => pll_on : rw,
+      25    => pll_ready : ro,
+      26    => pll_i2s_on : rw,
+      27    => pll_i2s_ready : ro,
+    },
+    0x04 => reg32 pllcfgr {     // PLL configuration
+      5..0   => pll_div_factor : rw,    // PLLM
+      14..6  => pll_mul_factor : rw,    // PLLN
+      17..16 => sysclk_prescaler {      // PLLP
+        0 => PllDiv2,
+        1 => PllDiv4,
+        2 => PllDiv6,
+        3 => PllDiv8,
+      },
+      22     => pll_clock_source_hse : rw,
+      27..24 => usb_prescaler : rw,     // PLLQ
+    },
+    0x08 => reg32 cfgr {        // clock configuration
+      1..0   => system_clock {
+        0 => HSI,
+        1 => HSE,
+        2 => PLL,
+      },
+      3..2   => system_clock_status : ro,
+      7..4   => ahb_prescaler {
+        0b0000 => AhbDiv1,
+        0b1000 => AhbDiv2,
+        0b1001 => AhbDiv4,
+        0b1010 => AhbDiv8,
+        0b1011 => AhbDiv16,
+        0b1100 => AhbDiv64,
+        0b1101 => AhbDiv128,
+        0b1110 => AhbDiv256,
+        0b1111 => AhbDiv512,
+      },
+      12..10 => apb1_prescaler : rw,
+      15..13 => apb2_prescaler : rw,
+      20..16 => rtc_prescaler : rw,
+      22..21 => mco1 : rw,
+      23     => i2s_clock_source_external : rw,
+      26..24 => mco1_prescaler : rw,
+      29..27 => mco2_prescaler : rw,
+      31..30 => mco2 : rw,
+    },
+    0x0c => reg32 cir {         // clock interrupt
+      31..0 => clock_interrupt : rw,
+    },
+    0x10 => reg32 ahb1rstr {    // AHB1 peripheral reset
+      31..0 => reset : rw,
+    },
+    0x14 => reg32 ahb2rstr {    // AHB2 peripheral reset
+      31..0 => reset : rw,
+    },
+    0x18 => reg32 ahb3rstr {    // AHB3 peripheral reset
+      31..0 => reset : rw,
+    },
+    0x20 => reg32 apb1rstr {    // APB1 peripheral reset
+      31..0 => reset : rw,
+    },
+    0x24 => reg32 apb2rstr {    // APB2 peripheral reset
+      31..0 => reset : rw,
+    },
+    0x30 => reg32 ahb1enr {     // AHB1 peripheral clock enable
+      31..0 => enable : rw,
+    },
+    0x34 => reg32 ahb2enr {     // AHB2 peripheral clock enable
+      31..0 => enable : rw,
+    },
+    0x38 => reg32 ahb3enr {     // AHB3 peripheral clock enable
+      31..0 => enable : rw,
+    },
+    0x40 => reg32 apb1enr {     // APB1 peripheral clock enable
+      31..0 => enable : rw,
+    },
+    0x44 => reg32 apb2enr {     // APB2 peripheral clock enable
+      31..0 => enable : rw,
+    },
+    0x50 => reg32 ahb1lpenr {   // AHB1 low power peripheral clock enable
+      31..0 => enable : rw,
+    },
+    0x54 => reg32 ahb2lpenr {   // AHB2 low power peripheral clock enable
+      31..0 => enable : rw,
+    },
+    0x58 => reg32 ahb3lpenr {   // AHB3 low power peripheral clock enable
+      31..0 => enable : rw,
+    },
+    0x60 => reg32 apb1lpenr {   // APB1 low power peripheral clock enable
+      31..0 => enable : rw,
+    },
+    0x64 => reg32 apb2lpenr {   // APB2 low power peripheral clock enable
+      31..0 => enable : rw,
+    },
+    0x70 => reg32 bdcr {        // backup domain control
+      0    => lse_on : rw,
+      1    => lse_ready : ro,
+      2    => lse_bypass : rw,
+      9..8 => rtc_source : rw,
+      15   => rtc_on : rw,
+      16   => backup_reset : rw,
+    },
+    0x74 => reg32 csr {         // clock control & status
+      0  => lsi_on : rw,
+      1  => lsi_ready : ro,
+      24 => remove_reset : rw,
+      25 => bor_reset : ro,
+      26 => pin_reset : ro,
+      27 => por_pdr_reset : ro,
+      28 => software_reset : ro,
+      29 => independent_watchdog_reset : ro,
+      30 => window_watchdog_reset : ro,
+      31 => low_power_reset : ro,
+    },
+    0x80 => reg32 sscgr {       // spread spectrum clock generation
+      12..0  => modulation_period : rw,
+      27..13 => increment_step : rw,
+      30     => spread_select : rw,
+      31     => ss_modulation_on : rw,
+    },
+    0x84 => reg32 plli2scfgr {  // PLLI2S configuration
+      14..6  => i2s_vco_mul_factor : rw,  // PLLI2SN
+      30..28 => i2s_prescaler : rw,       // PLLI2SR
+    },
+  });
+
+  ioregs!(FLASH = {
+    0x00 => reg32 acr {         // access control
+      2..0 => latency : rw,
+      8    => prefetch_on : rw,
+      9    => icache_on : rw,
+      10   => dcache_on : rw,
+      11   => icache_reset : rw,
+      12   => dcache_reset : rw,
+    },
+    0x04 => reg32 keyr {        // key
+      31..0 => key : wo,
+    },
+    0x08 => reg32 optkeyr {     // option key
+      31..0 => key : wo,
+    },
+    0x0c => reg32 sr {          // status
+      0 => end_of_operation : set_to_clear,
+      1 => operation_error : set_to_clear,
+      4 => write_protection_error : set_to_clear,
+      5 => programming_alignment_error : set_to_clear,
+      6 => programming_parallelism_error : set_to_clear,
+      7 => programming_sequence_error : set_to_clear,
+      16 => busy : ro,
+    },
+    0x10 => reg32 cr {          // control
+      0     => program : rw,
+      1     => sector_erase : rw,
+      2     => mass_erase : rw,
+      6..3  => sector_number : rw,
+      9..8  => program_size : rw,
+      16    => start : rw,
+      24    => eop_irq_enabled : rw,
+      25    => error_irq_enabled : rw,
+      31    => lock : rw,
+    },
+    0x14 => reg32 optcr {       // option control
+      0      => option_lock : rw,
+      1      => option_start : rw,
+      3..2   => bor_level : rw,
+      7..5   => user_options : rw,
+      15..8  => read_protection : rw,
+      27..16 => write_protection : rw,
+    },
+  });
+
+  ioregs!(PWR = {
+    0x00 => reg32 cr {          // power control
+      0    => low_power_deep_sleep : rw,
+      1    => power_down_deep_sleep : rw,
+      2    => clear_wakeup_flag : wo,
+      3    => clear_standby_flag : wo,
+      4    => pvd_enabled : rw,
+      7..5 => pvd_level : rw,
+      8    => backup_domain_write_enabled : rw,
+      9    => flash_power_down : rw,
+      14   => vos : rw,
+    },
+    0x04 => reg32 csr {         // power control/status
+      0  => wakeup_flag : ro,
+      1  => standby_flag : ro,
+      2  => pvd_output : ro,
+      3  => backup_regulator_ready : ro,
+      8  => wakeup_pin_enabled : rw,
+      9  => backup_regulator_enabled : rw,
+      14 => vos_ready : ro,
+    },
+  });
 
   extern {
-    #[link_name="stm32f4_iomem_RCC"] pub static RCC: RCCReg;
-    #[link_name="stm32f4_iomem_FLASH"] pub static FLASH: FLASHReg;
-    #[link_name="stm32f4_iomem_PWR"] pub static PWR: PWRReg;
+    #[link_name="stm32f4_iomem_RCC"] pub static RCC: RCC;
+    #[link_name="stm32f4_iomem_FLASH"] pub static FLASH: FLASH;
+    #[link_name="stm32f4_iomem_PWR"] pub static PWR: PWR;
   }
 }
@@ -15,7 +15,11 @@
 
 //! HAL for STM32F4.
 
+pub mod exti;
+pub mod flash;
 pub mod init;
 pub mod peripheral_clock;
 pub mod pin;
+pub mod reset;
 pub mod timer;
+pub mod watchdog;
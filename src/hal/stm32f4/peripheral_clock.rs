@@ -24,8 +24,6 @@ use core::marker::Copy;
 
 use self::PeripheralClock::*;
 
-#[path="../../util/ioreg.rs"] mod ioreg;
-
 /// Configures the state of peripheral clock.
 ///
 /// This enum contains all available clocks from both AHB and APB.
@@ -197,29 +195,24 @@ impl PeripheralClock {
       GPIOGClock|GPIOHClock|GPIOIClock|CRCClock|BKPSRAMClock|CCMDATARAMClock|
       DMA1Clock|DMA2Clock|ETHMACClock|ETHMACTxClock|ETHMACRxClock|
       ETHMACPTPClock|OTGHSClock|OTGHSULPIClock => {
-        let val = reg::RCC.AHB1ENR();
-        reg::RCC.set_AHB1ENR((val & mask) | bit);
+        reg::RCC.ahb1enr.set_enable((reg::RCC.ahb1enr.enable() & mask) | bit);
       },
       DCMIClock|CRYPClock|HASHClock|RNGClock|OTGFSClock => {
-        let val = reg::RCC.AHB2ENR();
-        reg::RCC.set_AHB2ENR((val & mask) | bit);
+        reg::RCC.ahb2enr.set_enable((reg::RCC.ahb2enr.enable() & mask) | bit);
       },
       FSMCClock => {
-        let val = reg::RCC.AHB3ENR();
-        reg::RCC.set_AHB3ENR((val & mask) | bit);
+        reg::RCC.ahb3enr.set_enable((reg::RCC.ahb3enr.enable() & mask) | bit);
       },
       TIM2Clock|TIM3Clock|TIM4Clock|TIM5Clock|TIM6Clock|TIM7Clock|TIM12Clock|
       TIM13Clock|TIM14Clock|WWDGClock|SPI2Clock|SPI3Clock|USART2Clock|
       USART3Clock|UART4Clock|UART5Clock|I2C1Clock|I2C2Clock|I2C3Clock|
       CAN1Clock|CAN2Clock|PWRClock|DACClock => {
-        let val = reg::RCC.APB1ENR();
-        reg::RCC.set_APB1ENR((val & mask) | bit);
+        reg::RCC.apb1enr.set_enable((reg::RCC.apb1enr.enable() & mask) | bit);
       },
       TIM1Clock|TIM8Clock|USART1Clock|USART6Clock|ADC1Clock|ADC2Clock|ADC3Clock|
       SDIOClock|SPI1Clock|SYSCFGClock|TIM9Clock|TIM10Clock|
       TIM11Clock => {
-        let val = reg::RCC.APB2ENR();
-        reg::RCC.set_AHB1ENR((val & mask) | bit);
+        reg::RCC.apb2enr.set_enable((reg::RCC.apb2enr.enable() & mask) | bit);
       },
     }
 
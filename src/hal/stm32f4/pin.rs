@@ -18,14 +18,16 @@
 //! Some pins that could be configured here may be missing from actual MCU
 //! depending on the package.
 
-use hal::pin::{Gpio, GpioDirection, GpioLevel};
+use hal::cortex_m4::nvic;
+use hal::pin::{Gpio, GpioDirection, GpioLevel, GpioPull, GpioSpeed,
+               GpioTrigger, InterruptHandler, InterruptPin, PinConfig};
+use super::exti;
 use super::peripheral_clock;
 use core::intrinsics::abort;
 
-use self::Port::*;
+pub use super::exti::dispatch_interrupts;
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
+use self::Port::*;
 
 /// Available port names.
 #[allow(missing_docs)]
@@ -101,12 +103,17 @@ impl Pin {
     gpreg.moder.set_mode(offset, val);
   }
 
-  /// Toggles the GPIO value
+  /// Toggles the GPIO value. BSRR only touches this pin, so other pins of the
+  /// port can be changed concurrently.
   pub fn toggle(&self) {
     let reg = self.get_reg();
     let offset = self.pin as usize;
 
-    reg.odr.set_od(offset, !reg.odr.od(offset));
+    if reg.odr.od(offset) {
+      reg.bsrr.set_br(offset, true);
+    } else {
+      reg.bsrr.set_bs(offset, true);
+    }
   }
 
   fn get_reg(&self) -> &reg::GPIO {
@@ -165,12 +172,71 @@ impl Gpio for Pin {
   }
 }
 
+impl PinConfig for Pin {
+  fn set_pull(&self, pull: GpioPull) {
+    use self::reg::GPIO_pupdr_pupd as RegPull;
+
+    self.get_reg().pupdr.set_pupd(self.pin as usize, match pull {
+      GpioPull::Floating => RegPull::None,
+      GpioPull::PullUp   => RegPull::PullUp,
+      GpioPull::PullDown => RegPull::PullDown,
+    });
+  }
+
+  fn set_open_drain(&self, enabled: bool) {
+    use self::reg::GPIO_otyper_ot as RegOutputType;
+
+    self.get_reg().otyper.set_ot(self.pin as usize, if enabled {
+      RegOutputType::OpenDrain
+    } else {
+      RegOutputType::PushPull
+    });
+  }
+
+  fn set_speed(&self, speed: GpioSpeed) {
+    use self::reg::GPIO_ospeedr_ospeed as RegSpeed;
+
+    self.get_reg().ospeedr.set_ospeed(self.pin as usize, match speed {
+      GpioSpeed::Low    => RegSpeed::Low,
+      GpioSpeed::Medium => RegSpeed::Medium,
+      GpioSpeed::High   => RegSpeed::High,
+    });
+  }
+}
+
+impl InterruptPin for Pin {
+  /// Routes the pin to its EXTI line and enables the line interrupt, only edge
+  /// triggers are supported.
+  fn enable_interrupt(&self, trigger: GpioTrigger) {
+    let line = self.pin as usize;
+    exti::select_port(line, self.port);
+    exti::enable(line, trigger);
+    nvic::enable_irq(exti::irq(line));
+  }
+
+  fn disable_interrupt(&self) {
+    exti::disable(self.pin as usize);
+  }
+
+  fn is_interrupt_pending(&self) -> bool {
+    exti::is_pending(self.pin as usize)
+  }
+
+  fn clear_interrupt(&self) {
+    exti::clear(self.pin as usize);
+  }
+
+  fn set_interrupt_handler(&self, handler: InterruptHandler) {
+    exti::set_handler(self.pin as usize, handler);
+  }
+}
+
 #[allow(dead_code)]
 mod reg {
   use core::ops::Drop;
   use volatile_cell::VolatileCell;
 
-  ioregs!(GPIO = {
+  ioregs!(GPIO : bitband = {
     0x0 => reg32 moder {
       0..31 => mode[16] {
         0 => Input,
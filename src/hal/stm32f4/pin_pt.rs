@@ -0,0 +1,248 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::rc::Rc;
+use syntax::ext::base::ExtCtxt;
+
+use builder::{Builder, TokenString, add_node_dependency};
+use builder::resources::Resource;
+use gpio_pt;
+use node;
+
+pub fn attach(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.materializer.set(Some(verify as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  for port_node in node.subnodes().iter() {
+    port_node.materializer.set(Some(verify as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+    add_node_dependency(&node, port_node);
+    for pin_node in port_node.subnodes().iter() {
+      pin_node.materializer.set(Some(build_pin as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+      add_node_dependency(port_node, pin_node);
+      super::add_node_dependency_on_clock(builder, pin_node);
+      gpio_pt::claim_gpio_pin(builder, cx, pin_node);
+    }
+  }
+}
+
+pub fn verify(_: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.expect_no_attributes(cx);
+}
+
+fn get_port_id(s: &str) -> Option<char> {
+  match s.len() {
+    1 => match s.chars().nth(0).unwrap().to_uppercase().nth(0).unwrap() {
+      p @ 'A'...'I' => Some(p),
+      _             => None,
+    },
+    _ => None,
+  }
+}
+
+/// Returns the name of the interrupt vector shared by EXTI line `line`.
+fn exti_irq(line: usize) -> String {
+  match line {
+    0...4 => format!("exti{}", line),
+    5...9 => "exti9_5".to_string(),
+    _     => "exti15_10".to_string(),
+  }
+}
+
+fn build_pin(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
+  let port_node = node.parent.clone().unwrap().upgrade().unwrap();
+  let ref port_path = port_node.path;
+
+  let port_str = format!("Port{}", match get_port_id(port_path.as_str()) {
+    Some(port) => port,
+    None => {
+      cx.parse_sess().span_diagnostic.span_err(port_node.path_span,
+          format!("unknown port `{}`, allowed values: a...i",
+              port_path).as_str());
+      return;
+    }
+  });
+  let port = TokenString(port_str);
+
+  if node.name.is_none() {
+    cx.parse_sess().span_diagnostic.span_err(node.name_span,
+        "pin node must have a name");
+    return;
+  }
+
+  if !node.expect_attributes(cx, &[("direction", node::StrAttribute)]) {
+    return;
+  }
+
+  let function_str = match node.get_string_attr("direction").unwrap().as_str() {
+    "out" => "GPIOOut",
+    "in"  => "GPIOIn",
+    other => {
+      cx.parse_sess().span_diagnostic.span_err(
+          node.get_attr("direction").value_span,
+          format!("unknown direction `{}`, allowed values: `in`, `out`",
+              other).as_str());
+      return;
+    }
+  };
+  let function = TokenString(function_str.to_string());
+
+  let line = match node.path.as_str().parse::<usize>() {
+    Ok(i @ 0...15) => i,
+    _ => {
+      cx.parse_sess().span_diagnostic.span_err(node.path_span,
+          format!("unknown pin `{}`, allowed values: 0...15",
+              node.path).as_str());
+      return;
+    }
+  };
+
+  let pin = TokenString(format!("{}u8", line));
+  let pin_name = TokenString(node.name.clone().unwrap());
+
+  node.set_type_name("zinc::hal::stm32f4::pin::Pin".to_string());
+
+  let st = quote_stmt!(&*cx,
+      let $pin_name = zinc::hal::stm32f4::pin::Pin {
+        port: zinc::hal::stm32f4::pin::Port::$port,
+        pin: $pin,
+        function: zinc::hal::stm32f4::pin::Function::$function,
+      };
+  ).unwrap();
+  builder.add_main_statement(st);
+
+  let st = quote_stmt!(&*cx, $pin_name.setup();).unwrap();
+  builder.add_main_statement(st);
+
+  // Pins of the same index on all ports share their EXTI line, and lines 5...9
+  // and 10...15 share their interrupt vectors.
+  if node.get_string_attr("interrupt").is_some() {
+    if !builder.claim_resource(cx,
+        Resource::Irq(format!("exti_line{}", line)),
+        &node, node.get_attr("interrupt").key_span) {
+      return;
+    }
+  }
+  let gpio_node = port_node.parent.clone().unwrap().upgrade().unwrap();
+  gpio_pt::build_interrupt(builder, cx, &node, &gpio_node,
+      exti_irq(line).as_str());
+}
+
+#[cfg(test)]
+mod test {
+  use builder::Builder;
+  use builder::resources::Resource;
+  use test_helpers::{assert_equal_source, with_parsed};
+
+  #[test]
+  fn builds_output_gpio() {
+    with_parsed("
+      gpio {
+        d {
+          led@12 { direction = \"out\"; }
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_pin(&mut builder, cx, pt.get_by_name("led").unwrap());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 2);
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "let led = zinc::hal::stm32f4::pin::Pin {
+               port: zinc::hal::stm32f4::pin::Port::PortD,
+               pin: 12u8,
+               function: zinc::hal::stm32f4::pin::Function::GPIOOut,
+             };");
+      assert_equal_source(&builder.main_stmts()[1], "led.setup();");
+    });
+  }
+
+  #[test]
+  fn builds_interrupt_gpio() {
+    with_parsed("
+      gpio {
+        a {
+          button@0 {
+            direction = \"in\";
+            interrupt = \"rising\";
+            interrupt_handler = \"on_button\";
+          }
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_pin(&mut builder, cx, pt.get_by_name("button").unwrap());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 4);
+
+      assert_equal_source(&builder.main_stmts()[2],
+          "zinc::hal::pin::InterruptPin::set_interrupt_handler(
+               &button, on_button);");
+      assert_equal_source(&builder.main_stmts()[3],
+          "zinc::hal::pin::InterruptPin::enable_interrupt(
+               &button, zinc::hal::pin::GpioTrigger::RisingEdge);");
+      assert!(builder.resources().owner_of(&Resource::Irq("exti0".to_string()))
+          == Some("gpio".to_string()));
+    });
+  }
+
+  #[test]
+  fn builds_interrupts_sharing_exti_vector() {
+    with_parsed("
+      gpio {
+        a {
+          up@5 { direction = \"in\"; interrupt = \"falling\"; }
+        }
+        b {
+          down@6 { direction = \"in\"; interrupt = \"falling\"; }
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_pin(&mut builder, cx, pt.get_by_name("up").unwrap());
+      super::build_pin(&mut builder, cx, pt.get_by_name("down").unwrap());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 6);
+    });
+  }
+
+  #[test]
+  fn fails_to_build_interrupts_sharing_exti_line() {
+    with_parsed("
+      gpio {
+        a {
+          up@5 { direction = \"in\"; interrupt = \"falling\"; }
+        }
+        b {
+          down@5 { direction = \"in\"; interrupt = \"falling\"; }
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_pin(&mut builder, cx, pt.get_by_name("up").unwrap());
+      super::build_pin(&mut builder, cx, pt.get_by_name("down").unwrap());
+      assert!(unsafe{*failed} == true);
+    });
+  }
+
+  #[test]
+  fn fails_to_build_unknown_port() {
+    with_parsed("
+      gpio {
+        z {
+          led@1 { direction = \"out\"; }
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_pin(&mut builder, cx, pt.get_by_name("led").unwrap());
+      assert!(unsafe{*failed} == true);
+      assert!(builder.main_stmts().len() == 0);
+    });
+  }
+}
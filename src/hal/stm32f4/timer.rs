@@ -15,13 +15,24 @@
 
 //! Timer configuration for ST STM32F4.
 //!
-//! This code supports only TIM2 at the moment.
+//! This code supports only TIM2 at the moment, with input capture and one
+//! pulse output on its channels 1 to 4.
 
+use core::intrinsics::abort;
+use core::option::Option;
+
+use hal::cortex_m4::nvic;
 use super::peripheral_clock;
-use hal::timer;
+use hal::timer::{self, CaptureEdge};
+
+/// IRQ of TIM2.
+const TIM2_IRQ: usize = 28;
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
+/// CCMR channel mode: input, mapped to its own pin.
+const CCMR_INPUT: u32 = 0b01;
+
+/// CCMR channel mode: output, PWM mode 2, active from the compare value on.
+const CCMR_PWM2: u32 = 0b111 << 4;
 
 /// Available timer peripherals.
 #[allow(missing_docs)]
@@ -33,7 +44,7 @@ pub enum TimerPeripheral {
 /// Structure describing a Timer.
 #[derive(Clone, Copy)]
 pub struct Timer {
-  reg: &'static reg::TIM2To5,
+  reg: &'static reg::TIM,
 }
 
 impl Timer {
@@ -46,9 +57,9 @@ impl Timer {
 
     clock.enable();
 
-    reg.set_PSC(counter - 1);
-    reg.set_CR1(1);
-    reg.set_EGR(1);
+    reg.psc.set_prescaler(counter - 1);
+    reg.cr1.set_counter_enabled(true);
+    reg.egr.set_update_generation(true);
 
     Timer {
       reg: reg,
@@ -59,37 +70,336 @@ impl Timer {
 impl timer::Timer for Timer {
   #[inline(always)]
   fn get_counter(&self) -> u32 {
-    self.reg.CNT()
+    self.reg.cnt.counter()
+  }
+}
+
+impl timer::OverflowTimer for Timer {
+  fn enable_overflow(&self) {
+    self.reg.dier.set_update_irq_enabled(true);
+    nvic::enable_irq(TIM2_IRQ);
+  }
+
+  fn disable_overflow(&self) {
+    self.reg.dier.set_update_irq_enabled(false);
+  }
+
+  fn clear_overflow(&self) {
+    self.reg.sr.clear_update_irq_flag();
+  }
+}
+
+impl Timer {
+  /// Sets the CCMR mode bits of `channel`, which must be disabled.
+  fn set_channel_mode(&self, channel: u8, mode: u32) {
+    let index = channel as usize - 1;
+    self.reg.ccmr[index / 2].set_mode(index % 2, mode);
+  }
+
+  /// Sets the CCER bits of `channel`.
+  fn set_channel(&self, channel: u8, enabled: bool, polarity: bool,
+      n_polarity: bool) {
+    let ccer = &self.reg.ccer;
+    match channel {
+      1 => ccer
+        .set_cc1_enabled(enabled)
+        .set_cc1_polarity(polarity)
+        .set_cc1n_polarity(n_polarity),
+      2 => ccer
+        .set_cc2_enabled(enabled)
+        .set_cc2_polarity(polarity)
+        .set_cc2n_polarity(n_polarity),
+      3 => ccer
+        .set_cc3_enabled(enabled)
+        .set_cc3_polarity(polarity)
+        .set_cc3n_polarity(n_polarity),
+      4 => ccer
+        .set_cc4_enabled(enabled)
+        .set_cc4_polarity(polarity)
+        .set_cc4n_polarity(n_polarity),
+      _ => unsafe { abort() },
+    };
+  }
+}
+
+impl timer::Capture for Timer {
+  fn enable_capture(&self, channel: u8, edge: CaptureEdge) {
+    let (polarity, n_polarity) = match edge {
+      CaptureEdge::Rising  => (false, false),
+      CaptureEdge::Falling => (true, false),
+      CaptureEdge::Both    => (true, true),
+    };
+    self.set_channel(channel, false, false, false);
+    self.set_channel_mode(channel, CCMR_INPUT);
+    self.set_channel(channel, true, polarity, n_polarity);
+  }
+
+  fn disable_capture(&self, channel: u8) {
+    self.set_channel(channel, false, false, false);
+  }
+
+  fn take_capture(&self, channel: u8) -> Option<u32> {
+    let sr = self.reg.sr.get();
+    let captured = match channel {
+      1 => sr.cc1_irq_flag(),
+      2 => sr.cc2_irq_flag(),
+      3 => sr.cc3_irq_flag(),
+      4 => sr.cc4_irq_flag(),
+      _ => unsafe { abort() },
+    };
+    if captured {
+      // Reading the capture clears the flag
+      Some(self.reg.ccr[channel as usize - 1].value())
+    } else {
+      None
+    }
+  }
+}
+
+impl timer::OnePulse for Timer {
+  /// Puts the timer in one pulse mode: the counter restarts from 0 and stops
+  /// after the pulse, so the timer can't be used for delays any more.
+  fn one_pulse(&self, channel: u8, delay: u32, width: u32) {
+    // The output is active while the counter is at or above the compare
+    // value, which must not be 0 for the output to go inactive once the
+    // counter stops at 0.
+    let delay = if delay == 0 { 1 } else { delay };
+
+    self.reg.cr1.set_counter_enabled(false);
+    self.set_channel(channel, false, false, false);
+    self.set_channel_mode(channel, CCMR_PWM2);
+    self.reg.ccr[channel as usize - 1].set_value(delay);
+    self.reg.arr.set_auto_reload(delay + width - 1);
+    self.reg.cnt.set_counter(0);
+    self.set_channel(channel, true, false, false);
+    self.reg.cr1
+      .set_one_pulse_mode(true)
+      .set_counter_enabled(true);
   }
 }
 
 mod reg {
   use volatile_cell::VolatileCell;
 
-  ioreg_old!(TIM2To5: u32, CR1, CR2, SMCR, DIER, SR, EGR, CCMR1, CCMR2, CCER, CNT,
-                       PSC, ARR, _pad_0, CCR1, CCR2, CCR3, CCR4, _pad_1, DCR,
-                       DMAR, OR);
-  reg_rw!(TIM2To5, u32, CR1,   set_CR1,   CR1);
-  reg_rw!(TIM2To5, u32, CR2,   set_CR2,   CR2);
-  reg_rw!(TIM2To5, u32, SMCR,  set_SMCR,  SMCR);
-  reg_rw!(TIM2To5, u32, DIER,  set_DIER,  DIER);
-  reg_rw!(TIM2To5, u32, SR,    set_SR,    SR);
-  reg_w!( TIM2To5, u32,        set_EGR,   EGR);
-  reg_rw!(TIM2To5, u32, CCMR1, set_CCMR1, CCMR1);
-  reg_rw!(TIM2To5, u32, CCMR2, set_CCMR2, CCMR2);
-  reg_rw!(TIM2To5, u32, CCER,  set_CCER,  CCER);
-  reg_rw!(TIM2To5, u32, CNT,   set_CNT,   CNT);
-  reg_rw!(TIM2To5, u32, PSC,   set_PSC,   PSC);
-  reg_rw!(TIM2To5, u32, ARR,   set_ARR,   ARR);
-  reg_rw!(TIM2To5, u32, CCR1,  set_CCR1,  CCR1);
-  reg_rw!(TIM2To5, u32, CCR2,  set_CCR2,  CCR2);
-  reg_rw!(TIM2To5, u32, CCR3,  set_CCR3,  CCR3);
-  reg_rw!(TIM2To5, u32, CCR4,  set_CCR4,  CCR4);
-  reg_rw!(TIM2To5, u32, DCR,   set_DCR,   DCR);
-  reg_rw!(TIM2To5, u32, DMAR,  set_DMAR,  DMAR);
-  reg_rw!(TIM2To5, u32, OR,    set_OR,    OR);
+  ioregs!(TIM : bitband = {
+    0x00 => reg32 cr1 {         // Control Register 1
+      9..8 => clock_divider : rw,
+      7    => auto_reload_buffered : rw,
+      6..5 => center_mode : rw,
+      4    => dir_down : rw,
+      3    => one_pulse_mode : rw,
+      2    => update_request_source : rw,
+      1    => uev_disabled : rw,
+      0    => counter_enabled : rw,
+    },
+    0x04 => reg32 cr2 {         // Control Register 2
+      7    => ti1_selection : rw,
+      6..4 => master_mode_selection : rw,
+      3    => cc_dma_selection : rw,
+    },
+    0x08 => reg32 smcr {        // Slave Mode Control Register
+      15     => ext_trigger_polarity_inverted : rw,
+      14     => ext_clock_enabled : rw,
+      13..12 => ext_trigger_prescaler : rw,
+      11..8  => ext_trigger_filter : rw,
+      7      => master_slave_mode : rw,
+      6..4   => trigger_selection : rw,
+      2..0   => slave_mode_selection : rw,
+    },
+    0x0c => reg32 dier {        // DMA/Interrupt Enable Register
+      14 => trigger_dma_req_enabled : rw,
+      12 => cc4_dma_req_enabled : rw,
+      11 => cc3_dma_req_enabled : rw,
+      10 => cc2_dma_req_enabled : rw,
+      9  => cc1_dma_req_enabled : rw,
+      8  => update_dma_req_enabled : rw,
+      6  => trigger_irq_enabled : rw,
+      4  => cc4_irq_enabled : rw,
+      3  => cc3_irq_enabled : rw,
+      2  => cc2_irq_enabled : rw,
+      1  => cc1_irq_enabled : rw,
+      0  => update_irq_enabled : rw,
+    },
+    0x10 => reg32 sr {          // Status Register
+      12 => cc4_overcapture_flag : zero_to_clear,
+      11 => cc3_overcapture_flag : zero_to_clear,
+      10 => cc2_overcapture_flag : zero_to_clear,
+      9  => cc1_overcapture_flag : zero_to_clear,
+      6  => trigger_irq_flag : zero_to_clear,
+      4  => cc4_irq_flag : zero_to_clear,
+      3  => cc3_irq_flag : zero_to_clear,
+      2  => cc2_irq_flag : zero_to_clear,
+      1  => cc1_irq_flag : zero_to_clear,
+      0  => update_irq_flag : zero_to_clear,
+    },
+    0x14 => reg32 egr {         // Event Generation Register
+      6 => trigger_generation : wo,
+      4 => cc4_generation : wo,
+      3 => cc3_generation : wo,
+      2 => cc2_generation : wo,
+      1 => cc1_generation : wo,
+      0 => update_generation : wo,
+    },
+    0x18 => reg32 ccmr[2] {     // Capture/Compare Mode Registers 1 and 2
+      0..15 => mode[2] : rw,    // Mode of each channel
+    },
+    0x20 => reg32 ccer {        // Capture/Compare Enable Register
+      15 => cc4n_polarity : rw,
+      13 => cc4_polarity : rw,
+      12 => cc4_enabled : rw,
+      11 => cc3n_polarity : rw,
+      9  => cc3_polarity : rw,
+      8  => cc3_enabled : rw,
+      7  => cc2n_polarity : rw,
+      5  => cc2_polarity : rw,
+      4  => cc2_enabled : rw,
+      3  => cc1n_polarity : rw,
+      1  => cc1_polarity : rw,
+      0  => cc1_enabled : rw,
+    },
+    0x24 => reg32 cnt {         // Counter Register
+      31..0 => counter : rw,
+    },
+    0x28 => reg32 psc {         // Prescaler Register
+      15..0 => prescaler : rw,
+    },
+    0x2c => reg32 arr {         // Auto-Reload Register
+      31..0 => auto_reload : rw,
+    },
+    0x34 => reg32 ccr[4] {      // Capture/Compare Registers 1 to 4
+      31..0 => value : rw,
+    },
+    0x48 => reg32 dcr {         // DMA Control Register
+      12..8 => dma_burst_len : rw,
+      4..0  => dma_base_addr : rw,
+    },
+    0x4c => reg32 dmar {        // DMA Address Register
+      15..0 => dma_burst_data : rw,
+    },
+    0x50 => reg32 optr {        // Option Register (TIM2 and TIM5 only)
+      11..10 => tim2_internal_trigger1_remap : rw,
+      7..6   => tim5_timer_input4_remap : rw,
+    },
+  });
 
   extern {
-    #[link_name="stm32f4_iomem_TIM2"] pub static TIM2: TIM2To5;
+    #[link_name="stm32f4_iomem_TIM2"] pub static TIM2: TIM;
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::{Timer, reg};
+  use hal::timer::Timer as TimerTrait;
+  use hal::timer::{Capture, CaptureEdge, OnePulse};
+  use volatile_cell::{VolatileCellReplayer, set_replayer};
+  use volatile_cell::models::{Clock, CounterModel};
+  use expectest::prelude::*;
+  use expectest;
+
+  #[test]
+  fn waits_on_simulated_counter() {
+    init_replayer!();
+    let clock = Clock::new();
+    // TIM2 CNT
+    add_volatile_model!(CounterModel::new(0x4000_0024, clock.clone(), 3));
+    let timer = Timer { reg: unsafe { &*(0x4000_0000 as *const reg::TIM) } };
+
+    timer.wait_us(100);
+
+    expect!(clock.now() >= 100 && clock.now() < 106).to(be_true());
+    expect_replayer_valid!();
+  }
+
+  fn tim2() -> Timer {
+    Timer { reg: unsafe { &*(0x4000_0000 as *const reg::TIM) } }
+  }
+
+  #[test]
+  fn captures_both_edges() {
+    init_replayer!();
+    let timer = tim2();
+
+    // CCER: disable channel 1, leaving channel 2 enabled
+    expect_volatile_read!(0x4000_0020, 0x0000_0010);
+    expect_volatile_write!(0x4000_0020, 0x0000_0010);
+    // CCMR1: channel 1 input on TI1, leaving channel 2 in PWM mode 2
+    expect_volatile_read!(0x4000_0018, 0x0000_7000);
+    expect_volatile_write!(0x4000_0018, 0x0000_7001);
+    // CCER: enable channel 1 with CC1P and CC1NP set
+    expect_volatile_read!(0x4000_0020, 0x0000_0010);
+    expect_volatile_write!(0x4000_0020, 0x0000_001b);
+    // SR: CC1IF set, then CCR1 read
+    expect_volatile_read!(0x4000_0010, 0x0000_0002);
+    expect_volatile_read!(0x4000_0034, 1234);
+    // SR: no capture
+    expect_volatile_read!(0x4000_0010, 0x0000_0001);
+
+    timer.enable_capture(1, CaptureEdge::Both);
+    expect!(timer.take_capture(1)).to(be_equal_to(Some(1234)));
+    expect!(timer.take_capture(1)).to(be_equal_to(None));
+
+    expect_replayer_valid!();
+  }
+
+  #[test]
+  fn outputs_one_pulse() {
+    init_replayer!();
+    let timer = tim2();
+
+    // CR1: stop the counter
+    expect_volatile_read!(0x4000_0000, 0x0000_0001);
+    expect_volatile_write!(0x4000_0000, 0x0000_0000);
+    // CCER: disable channel 2
+    expect_volatile_read!(0x4000_0020, 0x0000_0011);
+    expect_volatile_write!(0x4000_0020, 0x0000_0001);
+    // CCMR1: channel 2 in PWM mode 2, leaving channel 1 an input
+    expect_volatile_read!(0x4000_0018, 0x0000_0001);
+    expect_volatile_write!(0x4000_0018, 0x0000_7001);
+    // CCR2, ARR and CNT: active from 100 to 149
+    expect_volatile_read!(0x4000_0038, 0);
+    expect_volatile_write!(0x4000_0038, 100);
+    expect_volatile_read!(0x4000_002c, 0xffff_ffff);
+    expect_volatile_write!(0x4000_002c, 149);
+    expect_volatile_read!(0x4000_0024, 5000);
+    expect_volatile_write!(0x4000_0024, 0);
+    // CCER: enable channel 2
+    expect_volatile_read!(0x4000_0020, 0x0000_0001);
+    expect_volatile_write!(0x4000_0020, 0x0000_0011);
+    // CR1: one pulse mode, start the counter
+    expect_volatile_read!(0x4000_0000, 0x0000_0000);
+    expect_volatile_write!(0x4000_0000, 0x0000_0009);
+
+    timer.one_pulse(2, 100, 50);
+
+    expect_replayer_valid!();
+  }
+
+  #[test]
+  fn outputs_immediate_pulse_after_one_tick() {
+    init_replayer!();
+    let timer = tim2();
+
+    expect_volatile_read!(0x4000_0000, 0x0000_0001);
+    expect_volatile_write!(0x4000_0000, 0x0000_0000);
+    expect_volatile_read!(0x4000_0020, 0x0000_0000);
+    expect_volatile_write!(0x4000_0020, 0x0000_0000);
+    expect_volatile_read!(0x4000_0018, 0x0000_0000);
+    expect_volatile_write!(0x4000_0018, 0x0000_0070);
+    // A compare value of 0 would keep the output active once stopped
+    expect_volatile_read!(0x4000_0034, 0);
+    expect_volatile_write!(0x4000_0034, 1);
+    expect_volatile_read!(0x4000_002c, 0);
+    expect_volatile_write!(0x4000_002c, 10);
+    expect_volatile_read!(0x4000_0024, 0);
+    expect_volatile_write!(0x4000_0024, 0);
+    expect_volatile_read!(0x4000_0020, 0x0000_0000);
+    expect_volatile_write!(0x4000_0020, 0x0000_0001);
+    expect_volatile_read!(0x4000_0000, 0x0000_0000);
+    expect_volatile_write!(0x4000_0000, 0x0000_0009);
+
+    timer.one_pulse(1, 0, 10);
+
+    expect_replayer_valid!();
   }
 }
@@ -22,8 +22,6 @@
 use hal::mem_init::init_data;
 use core::intrinsics::abort;
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
 #[path="../../util/wait_for.rs"]
 #[macro_use] mod wait_for;
 
@@ -146,7 +144,7 @@ impl ClockConf {
         let sysfreq: u32 = match pll_conf.source {
           PLLClockHSE(freq) => freq,
           PLLClockHSI       => 16_000_000,
-        } as u32 * (pll_conf.n as u32 / pll_conf.m as u32) / pll_conf.p as u32;
+        } as u32 / pll_conf.m as u32 * pll_conf.n as u32 / pll_conf.p as u32;
         // system_stm32f4xx.c enables PWR and sets VOS to 1 here, but VOS
         // defaults to 1 so I see no real reason to do that.
         // peripheral_clock::PWRClock.enable();
@@ -15,7 +15,10 @@
 
 //! HAL for STM32F7.
 
+pub mod exti;
 pub mod init;
 pub mod peripheral_clock;
 pub mod pin;
+pub mod reset;
 pub mod timer;
+pub mod watchdog;
@@ -25,8 +25,6 @@ use core::marker::Copy;
 
 use self::PeripheralClock::*;
 
-#[path="../../util/ioreg.rs"] mod ioreg;
-
 /// Configures the state of peripheral clock.
 ///
 /// This enum contains all available clocks from both AHB and APB.
@@ -0,0 +1,31 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reset cause for ST STM32F7.
+
+use super::init::reg;
+
+#[path="../stm32_common/reset.rs"]
+mod common;
+
+pub use self::common::*;
+
+fn power_on_reset(csr: &reg::RCC_csr_Get) -> bool {
+  csr.por_pdr_reset()
+}
+
+fn brown_out_reset(csr: &reg::RCC_csr_Get) -> bool {
+  csr.bor_reset()
+}
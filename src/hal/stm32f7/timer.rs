@@ -18,11 +18,12 @@
 //!
 //! This code supports only TIM2 at the moment.
 
+use hal::cortex_m7::nvic;
 use super::peripheral_clock;
 use hal::timer;
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
+/// IRQ of TIM2.
+const TIM2_IRQ: usize = 28;
 
 /// Available timer peripherals.
 #[allow(missing_docs)]
@@ -64,6 +65,21 @@ impl timer::Timer for Timer {
   }
 }
 
+impl timer::OverflowTimer for Timer {
+  fn enable_overflow(&self) {
+    self.reg.dier.set_update_irq_enabled(true);
+    nvic::enable_irq(TIM2_IRQ);
+  }
+
+  fn disable_overflow(&self) {
+    self.reg.dier.set_update_irq_enabled(false);
+  }
+
+  fn clear_overflow(&self) {
+    self.reg.sr.clear_update_irq_flag();
+  }
+}
+
 mod reg {
   use volatile_cell::VolatileCell;
 
@@ -116,21 +132,21 @@ mod reg {
       0  => update_irq_enabled : rw,
     },
     0x10 => reg32 sr {          // Status Register              all
-      17 => cc6_irq_flag : rw,
-      16 => cc5_irq_flag : rw,
-      12 => cc4_overcapture_flag : rw,
-      11 => cc3_overcapture_flag : rw,
-      10 => cc2_overcapture_flag : rw,
-      9  => cc1_overcapture_flag : rw,
-      8  => break2_irq_flag : rw,
-      7  => break_irq_flag : rw,
-      6  => trigger_irq_flag : rw,
-      5  => com_irq_flag : rw,
-      4  => cc4_irq_flag : rw,
-      3  => cc3_irq_flag : rw,
-      2  => cc2_irq_flag : rw,
-      1  => cc1_irq_flag : rw,
-      0  => update_irq_flag : rw,
+      17 => cc6_irq_flag : zero_to_clear,
+      16 => cc5_irq_flag : zero_to_clear,
+      12 => cc4_overcapture_flag : zero_to_clear,
+      11 => cc3_overcapture_flag : zero_to_clear,
+      10 => cc2_overcapture_flag : zero_to_clear,
+      9  => cc1_overcapture_flag : zero_to_clear,
+      8  => break2_irq_flag : zero_to_clear,
+      7  => break_irq_flag : zero_to_clear,
+      6  => trigger_irq_flag : zero_to_clear,
+      5  => com_irq_flag : zero_to_clear,
+      4  => cc4_irq_flag : zero_to_clear,
+      3  => cc3_irq_flag : zero_to_clear,
+      2  => cc2_irq_flag : zero_to_clear,
+      1  => cc1_irq_flag : zero_to_clear,
+      0  => update_irq_flag : zero_to_clear,
     },
     0x14 => reg32 egr {         // Event Generation Register    all
       8 => break2_enabled : rw,
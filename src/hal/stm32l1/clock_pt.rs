@@ -0,0 +1,234 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::rc::Rc;
+use syntax::ext::base::ExtCtxt;
+use syntax::ext::build::AstBuilder;
+
+use builder::{Builder, TokenString};
+use builder::clock::{Factor, PllLimits, format_frequency};
+use builder::clock;
+use node;
+
+/// PLL limits, see RM0038 chapter 6.2.3.
+///
+/// `init::ClockConfig` switches to voltage range 1 and one flash wait state
+/// above 16MHz, which allows up to 32MHz.
+static PLL_LIMITS: PllLimits = PllLimits {
+  input_div: Factor::Range(1, 1),
+  mult: Factor::Set(&[3, 4, 6, 8, 12, 16, 24, 32, 48]),
+  output_div: Factor::Range(2, 4),
+  vco_scale: 1,
+  input_range: (2_000_000, 24_000_000),
+  vco_range: (6_000_000, 96_000_000),
+  max_output: 32_000_000,
+};
+
+const HSI_FREQUENCY: usize = 16_000_000;
+
+pub fn attach(_: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.materializer.set(Some(build_clock as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+}
+
+fn build_clock(builder: &mut Builder, cx: &mut ExtCtxt,
+    node: Rc<node::Node>) {
+  if !node.expect_attributes(cx, &[("source", node::StrAttribute)]) {
+    return;
+  }
+
+  let source = node.get_string_attr("source").unwrap();
+  let source_freq = match source.as_str() {
+    "hsi" => HSI_FREQUENCY,
+    "hse" => match node.get_required_int_attr(cx, "source_frequency") {
+      Some(freq) => freq,
+      None => return,
+    },
+    other => {
+      cx.span_err(node.get_attr("source").value_span,
+          format!("unknown oscillator value `{}`, allowed values: \
+                  `hsi`, `hse`", other).as_str());
+      return;
+    },
+  };
+
+  let (sysfreq, clock_source) = match node.get_int_attr("target_frequency") {
+    None => {
+      builder.add_report(format!("stm32l1 clock: sysclk = {} ({})",
+          format_frequency(source_freq), source));
+      (source_freq, if source == "hsi" {
+        "init::SystemClockSource::SystemClockHSI".to_string()
+      } else {
+        format!("init::SystemClockSource::SystemClockHSE({}u32)", source_freq)
+      })
+    },
+    Some(target) => {
+      let span = node.get_attr("target_frequency").value_span;
+      let solution = match clock::solve_pll(source_freq, target, &PLL_LIMITS) {
+        Some(solution) => solution,
+        None => {
+          cx.span_err(span,
+              format!("can't generate {} from {}, max sysclk is {}",
+                  format_frequency(target), format_frequency(source_freq),
+                  format_frequency(PLL_LIMITS.max_output)).as_str());
+          return;
+        },
+      };
+      if solution.output != target {
+        cx.parse_sess().span_diagnostic.span_warn(span,
+            format!("can't generate exactly {} from {}, using {}",
+                format_frequency(target), format_frequency(source_freq),
+                format_frequency(solution.output)).as_str());
+      }
+      builder.add_report(format!(
+          "stm32l1 clock: sysclk = {} (PLL from {}, mul = {}, div = {}, \
+          VCO = {})",
+          format_frequency(solution.output), source, solution.mult,
+          solution.output_div, format_frequency(solution.vco)));
+
+      let pll_source = if source == "hsi" {
+        "init::PllClockSource::PllSourceHSI".to_string()
+      } else {
+        format!("init::PllClockSource::PllSourceHSE({}u32)", source_freq)
+      };
+
+      // PLLDIV register encoding is divisor - 1.
+      (solution.output, format!(
+          "init::SystemClockSource::SystemClockPLL({}, {}u8, {}u8)",
+          pll_source, solution.mult, solution.output_div - 1))
+    },
+  };
+
+  node.attributes.borrow_mut().insert("system_frequency".to_string(),
+      Rc::new(node::Attribute::new_nosp(node::IntValue(sysfreq))));
+
+  let clock_source = TokenString(clock_source);
+  let ex = quote_expr!(&*cx,
+      {
+        use zinc::hal::stm32l1::init;
+        init::ClockConfig {
+          source: $clock_source,
+          ahb_shift: 0,
+          apb1_shift: 0,
+          apb2_shift: 0,
+          mco: core::option::Option::None,
+        }.setup();
+      }
+  );
+  builder.add_main_statement(cx.stmt_expr(ex));
+}
+
+#[cfg(test)]
+mod test {
+  use builder::Builder;
+  use test_helpers::{assert_equal_source, with_parsed};
+
+  #[test]
+  fn builds_pll_clock_for_target_frequency() {
+    with_parsed("
+      clock {
+        source = \"hsi\";
+        target_frequency = 16_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      let node = pt.get_by_path("clock").unwrap();
+      super::build_clock(&mut builder, cx, node.clone());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 1);
+      assert!(node.get_int_attr("system_frequency") == Some(16_000_000));
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "{
+            use zinc::hal::stm32l1::init;
+            init::ClockConfig {
+              source: init::SystemClockSource::SystemClockPLL(
+                init::PllClockSource::PllSourceHSI, 3u8, 2u8),
+              ahb_shift: 0,
+              apb1_shift: 0,
+              apb2_shift: 0,
+              mco: core::option::Option::None,
+            }.setup();
+          }");
+    });
+  }
+
+  #[test]
+  fn builds_32mhz_pll_clock() {
+    with_parsed("
+      clock {
+        source = \"hsi\";
+        target_frequency = 32_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      let node = pt.get_by_path("clock").unwrap();
+      super::build_clock(&mut builder, cx, node.clone());
+      assert!(unsafe{*failed} == false);
+      assert!(node.get_int_attr("system_frequency") == Some(32_000_000));
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "{
+            use zinc::hal::stm32l1::init;
+            init::ClockConfig {
+              source: init::SystemClockSource::SystemClockPLL(
+                init::PllClockSource::PllSourceHSI, 4u8, 1u8),
+              ahb_shift: 0,
+              apb1_shift: 0,
+              apb2_shift: 0,
+              mco: core::option::Option::None,
+            }.setup();
+          }");
+    });
+  }
+
+  #[test]
+  fn fails_to_build_target_frequency_above_limit() {
+    with_parsed("
+      clock {
+        source = \"hsi\";
+        target_frequency = 48_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_clock(&mut builder, cx, pt.get_by_path("clock").unwrap());
+      assert!(unsafe{*failed} == true);
+      assert!(builder.main_stmts().len() == 0);
+    });
+  }
+
+  #[test]
+  fn builds_pll_clock_from_hse() {
+    with_parsed("
+      clock {
+        source = \"hse\";
+        source_frequency = 8_000_000;
+        target_frequency = 32_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_clock(&mut builder, cx, pt.get_by_path("clock").unwrap());
+      assert!(unsafe{*failed} == false);
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "{
+            use zinc::hal::stm32l1::init;
+            init::ClockConfig {
+              source: init::SystemClockSource::SystemClockPLL(
+                init::PllClockSource::PllSourceHSE(8000000u32), 8u8, 1u8),
+              ahb_shift: 0,
+              apb1_shift: 0,
+              apb2_shift: 0,
+              mco: core::option::Option::None,
+            }.setup();
+          }");
+    });
+  }
+}
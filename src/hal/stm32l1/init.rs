@@ -24,6 +24,8 @@ use core::intrinsics::abort;
 use core::option::Option;
 use core::marker::Copy;
 
+use super::peripheral_clock::{PeripheralClock, BusApb1};
+
 use self::MsiSpeed::*;
 use self::SystemClockSource::*;
 
@@ -31,13 +33,12 @@ use self::SystemClockSource::*;
 #[macro_use] mod wait_for;
 
 /// Phase-locked loop clock source.
-#[repr(u8)]
 #[derive(Clone)]
 pub enum PllClockSource {
   /// Takes base clock from HSI.
-  PllSourceHSI = 0,
-  /// Takes base clock from HSE.
-  PllSourceHSE = 1,
+  PllSourceHSI,
+  /// Takes base clock from HSE with configurable frequency.
+  PllSourceHSE(u32),
 }
 
 impl Copy for PllClockSource {}
@@ -45,7 +46,7 @@ impl Copy for PllClockSource {}
 /// PLL multiplier: 3, 4, 6, 8, 12, 16, 24, 32, 48
 pub type PllMultiplier = u8;
 
-/// PLL divisor: 1, 2, 3, 4
+/// PLL divisor in register encoding: 1 => /2, 2 => /3, 3 => /4
 pub type PllDivisor = u8;
 
 /// Multi-speed internal clock divisor.
@@ -101,12 +102,22 @@ impl SystemClockSource {
         SystemClockMSI(Msi2097) => 2_097_000,
         SystemClockMSI(Msi4194) => 4_194_000,
         SystemClockHSI => 16_000_000,
-        SystemClockHSE(_) => unsafe { abort() }, //TODO(kvark)
-        SystemClockPLL(_, _, _) => unsafe { abort() }, //TODO(kvark)
+        SystemClockHSE(frequency) => frequency,
+        SystemClockPLL(pll_source, mul, div) => {
+          let input = match pll_source {
+            PllClockSource::PllSourceHSI => 16_000_000,
+            PllClockSource::PllSourceHSE(frequency) => frequency,
+          };
+          input * mul as u32 / (div as u32 + 1)
+        },
     }
   }
 }
 
+/// Highest system clock frequency without flash wait states and in voltage
+/// range 2, which is the reset default.
+const MAX_FREQUENCY_0WS: u32 = 16_000_000;
+
 impl Copy for SystemClockSource {}
 
 #[allow(missing_docs)]
@@ -173,6 +184,11 @@ impl ClockConfig {
   /// Set this configuration on the hardware.
   pub fn setup(&self) {
     let r = &reg::RCC;
+    let high_speed = self.source.frequency() > MAX_FREQUENCY_0WS;
+
+    if high_speed {
+      set_high_speed_mode(true);
+    }
 
     let source_type = match self.source {
       SystemClockMSI(msi) => {
@@ -193,9 +209,19 @@ impl ClockConfig {
         2
       },
       SystemClockPLL(pll_source, mul, div) => {
-        r.cr.set_pll_on(true);
-        wait_for!(r.cr.pll_ready());
-        r.cfgr.set_pll_clock_source(pll_source as usize != 0);
+        let hse = match pll_source {
+          PllClockSource::PllSourceHSI => {
+            r.cr.set_hsi_on(true);
+            wait_for!(r.cr.hsi_ready());
+            false
+          },
+          PllClockSource::PllSourceHSE(_) => {
+            r.cr.set_hse_on(true);
+            wait_for!(r.cr.hse_ready());
+            true
+          },
+        };
+        r.cfgr.set_pll_clock_source(hse);
         let factor = match mul {
           3 => 0,
           4 => 1,
@@ -210,6 +236,9 @@ impl ClockConfig {
         };
         r.cfgr.set_pll_mul_factor(factor);
         r.cfgr.set_pll_output_div(div as u32);
+        // PLL must be configured before it's enabled.
+        r.cr.set_pll_on(true);
+        wait_for!(r.cr.pll_ready());
         3
       }
     };
@@ -217,6 +246,10 @@ impl ClockConfig {
     r.cfgr.set_system_clock(source_type);
     wait_for!(r.cfgr.system_clock_status() == source_type);
 
+    if !high_speed {
+      set_high_speed_mode(false);
+    }
+
     if self.ahb_shift > 9 || self.apb1_shift > 4 || self.apb2_shift > 4 {
       unsafe { abort() } // not supported
     }
@@ -254,6 +287,35 @@ impl ClockConfig {
   }
 }
 
+/// Switches the core voltage and flash access to what the system clock needs
+/// above `MAX_FREQUENCY_0WS`: voltage range 1 (1.8 V), and one flash wait
+/// state with 64-bit access and prefetch. Raising the frequency must be done
+/// after enabling, and lowering it before disabling.
+fn set_high_speed_mode(enabled: bool) {
+  let f = &reg::FLASH;
+
+  if enabled {
+    PeripheralClock::Apb1(BusApb1::Pwr).enable();
+    wait_for!(!reg::PWR.csr.voltage_scaling_busy());
+    reg::PWR.cr.set_voltage_scaling(1);
+    wait_for!(!reg::PWR.csr.voltage_scaling_busy());
+
+    // Prefetch and latency can only be changed with 64-bit access on.
+    f.acr.set_access_64bit(true);
+    wait_for!(f.acr.access_64bit());
+    f.acr
+      .set_prefetch(true)
+      .set_latency(true);
+    wait_for!(f.acr.latency());
+  } else {
+    f.acr
+      .set_latency(false)
+      .set_prefetch(false);
+    wait_for!(!f.acr.latency());
+    f.acr.set_access_64bit(false);
+  }
+}
+
 // TODO(farcaller): this mod is pub as it's being used in peripheral_clock.rs.
 //                  This is not the best design solution and a good reason to
 //                  split RCC into distinct registers.
@@ -349,7 +411,11 @@ pub mod reg {
 
   ioregs!(FLASH = {
     0x00 => reg32 acr {     // access control
-      31..0 => access_control : rw,
+      0 => latency : rw,          // one wait state
+      1 => prefetch : rw,
+      2 => access_64bit : rw,
+      3 => sleep_power_down : rw, // flash off in sleep mode
+      4 => run_power_down : rw,   // flash off in run mode
     },
     0x04 => reg32 pecr {    // program/erase control
       31..0 => program_control : rw,
@@ -385,10 +451,28 @@ pub mod reg {
 
   ioregs!(PWR = {
     0x0 => reg32 cr {   // power control
-      31..0 => control : rw,
+      0 => low_power_deep_sleep : rw,
+      1 => power_down_deep_sleep : rw,
+      2 => clear_wakeup_flag : wo,
+      3 => clear_standby_flag : wo,
+      4 => voltage_detector_on : rw,
+      7..5 => voltage_detector_level : rw,
+      8 => backup_write_on : rw,
+      9 => ultra_low_power : rw,
+      10 => fast_wakeup : rw,
+      12..11 => voltage_scaling : rw,
+      14 => low_power_run : rw,
     },
     0x4 => reg32 csr {  // power control/status
-      31..0 => status : rw,
+      0 => wakeup_flag : ro,
+      1 => standby_flag : ro,
+      2 => voltage_detector_output : ro,
+      3 => vrefint_ready : ro,
+      4 => voltage_scaling_busy : ro,
+      5 => regulator_low_power : ro,
+      8 => wakeup_pin1_on : rw,
+      9 => wakeup_pin2_on : rw,
+      10 => wakeup_pin3_on : rw,
     },
   });
 
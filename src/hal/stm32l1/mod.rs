@@ -15,9 +15,14 @@
 
 //! HAL for STM32L1.
 
+pub mod exti;
 pub mod init;
 pub mod peripheral_clock;
 pub mod pin;
+pub mod power;
+pub mod reset;
+pub mod rtc;
 pub mod spi;
 pub mod timer;
 pub mod usart;
+pub mod watchdog;
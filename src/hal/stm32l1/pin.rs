@@ -18,10 +18,14 @@
 //! Some pins that could be configured here may be missing from actual MCU
 //! depending on the package.
 
+use hal::cortex_m3::nvic;
+use hal::pin::{GpioTrigger, InterruptHandler, InterruptPin};
+use super::exti;
 use super::peripheral_clock;
-use core::intrinsics::abort;
 use self::Port::*;
 
+pub use super::exti::dispatch_interrupts;
+
 /// Available port names.
 #[allow(missing_docs)]
 #[repr(u8)]
@@ -109,6 +113,8 @@ pub enum Mode {
 pub struct Pin {
   /// Pin index.
   pub index: u8,
+  /// Pin port.
+  port: Port,
   /// GPIO register
   reg: &'static reg::GPIO,
 }
@@ -182,6 +188,7 @@ impl Pin {
 
     Pin {
       index: pin_index,
+      port: port,
       reg: reg,
     }
   }
@@ -207,9 +214,80 @@ impl ::hal::pin::Gpio for Pin {
     }
   }
 
-  fn set_direction(&self, _new_mode: ::hal::pin::GpioDirection) {
-    //TODO(kvark)
-    unsafe { abort() }
+  fn set_direction(&self, new_mode: ::hal::pin::GpioDirection) {
+    let offset2 = self.index as usize * 2;
+    let fun: u32 = match new_mode {
+      ::hal::pin::In  => 0b00,
+      ::hal::pin::Out => 0b01,
+    };
+    let mode: u32 = self.reg.moder.mode() & !(0b11 << offset2);
+    self.reg.moder.set_mode(mode | (fun << offset2));
+  }
+}
+
+impl ::hal::pin::PinConfig for Pin {
+  fn set_pull(&self, pull: ::hal::pin::GpioPull) {
+    use hal::pin::GpioPull;
+
+    let offset2 = self.index as usize * 2;
+    let pull_type = match pull {
+      GpioPull::Floating => PullType::PullNone,
+      GpioPull::PullUp   => PullType::PullUp,
+      GpioPull::PullDown => PullType::PullDown,
+    };
+    let v: u32 = self.reg.pupdr.mode() & !(0b11 << offset2);
+    self.reg.pupdr.set_mode(v | ((pull_type as u32) << offset2));
+  }
+
+  fn set_open_drain(&self, enabled: bool) {
+    let offset1 = self.index as usize;
+    let otype = if enabled {
+      OutputType::OutOpenDrain
+    } else {
+      OutputType::OutPushPull
+    };
+    let v: u16 = self.reg.otyper.otype() & !(0b1u16 << offset1);
+    self.reg.otyper.set_otype(v | ((otype as u16) << offset1));
+  }
+
+  fn set_speed(&self, speed: ::hal::pin::GpioSpeed) {
+    use hal::pin::GpioSpeed;
+
+    let offset2 = self.index as usize * 2;
+    let speed = match speed {
+      GpioSpeed::Low    => Speed::VeryLow,
+      GpioSpeed::Medium => Speed::Medium,
+      GpioSpeed::High   => Speed::High,
+    };
+    let v: u32 = self.reg.ospeedr.speed() & !(0b11 << offset2);
+    self.reg.ospeedr.set_speed(v | ((speed as u32) << offset2));
+  }
+}
+
+impl InterruptPin for Pin {
+  /// Routes the pin to its EXTI line and enables the line interrupt, only edge
+  /// triggers are supported.
+  fn enable_interrupt(&self, trigger: GpioTrigger) {
+    let line = self.index as usize;
+    exti::select_port(line, self.port);
+    exti::enable(line, trigger);
+    nvic::enable_irq(exti::irq(line));
+  }
+
+  fn disable_interrupt(&self) {
+    exti::disable(self.index as usize);
+  }
+
+  fn is_interrupt_pending(&self) -> bool {
+    exti::is_pending(self.index as usize)
+  }
+
+  fn clear_interrupt(&self) {
+    exti::clear(self.index as usize);
+  }
+
+  fn set_interrupt_handler(&self, handler: InterruptHandler) {
+    exti::set_handler(self.index as usize, handler);
   }
 }
 
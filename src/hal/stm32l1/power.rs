@@ -0,0 +1,160 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Low-power modes for ST STM32L1.
+//!
+//! Stop mode keeps RAM and registers, the MCU wakes up on any EXTI line
+//! enabled by `enable_wakeup` and runs from MSI afterwards, so `stop` restores
+//! the clock configuration. Standby mode loses everything but the backup
+//! domain, the MCU wakes up through a reset, either from a wakeup pin or the
+//! RTC.
+
+use hal::cortex_m3::scb;
+use hal::pin::GpioTrigger;
+use util::support::wfi;
+use super::exti;
+use super::init::ClockConfig;
+use super::init::reg::PWR;
+use super::peripheral_clock::{PeripheralClock, BusApb1};
+use super::pin::Port;
+
+/// Voltage regulator mode in stop mode.
+#[derive(Clone, Copy)]
+pub enum Regulator {
+  /// Main regulator, faster to wake up.
+  Main,
+  /// Low-power regulator, lower consumption.
+  LowPower,
+}
+
+/// Wakeup pins, wake the MCU up from standby on a rising edge.
+#[allow(missing_docs)]
+#[derive(Clone, Copy)]
+pub enum WakeupPin {
+  /// PA0
+  Wkup1,
+  /// PC13
+  Wkup2,
+  /// PE6
+  Wkup3,
+}
+
+/// Sources waking the MCU up from stop mode.
+#[derive(Clone, Copy)]
+pub enum WakeupSource {
+  /// Edge on a GPIO pin, sharing its EXTI line with the pins of the same index
+  /// on other ports.
+  Gpio(Port, u8, GpioTrigger),
+  /// RTC alarm, EXTI line 17.
+  RtcAlarm,
+  /// RTC wakeup timer, EXTI line 20.
+  RtcWakeup,
+}
+
+impl WakeupSource {
+  fn line(&self) -> usize {
+    match *self {
+      WakeupSource::Gpio(_, pin, _) => pin as usize,
+      WakeupSource::RtcAlarm => 17,
+      WakeupSource::RtcWakeup => 20,
+    }
+  }
+}
+
+/// Enables the EXTI line of `source`. Its interrupt must also be enabled in
+/// the NVIC to wake the core up, and the line cleared by the handler with
+/// `clear_wakeup`.
+pub fn enable_wakeup(source: WakeupSource) {
+  let line = source.line();
+  let trigger = match source {
+    WakeupSource::Gpio(port, _, trigger) => {
+      exti::select_port(line, port);
+      trigger
+    },
+    // RTC events are signalled with a rising edge
+    _ => GpioTrigger::RisingEdge,
+  };
+  exti::enable(line, trigger);
+}
+
+/// Disables the EXTI line of `source`.
+pub fn disable_wakeup(source: WakeupSource) {
+  exti::disable(source.line());
+}
+
+/// Clears the pending EXTI line of `source`.
+pub fn clear_wakeup(source: WakeupSource) {
+  exti::clear(source.line());
+}
+
+/// Enables or disables a wakeup pin for standby mode.
+pub fn set_wakeup_pin(pin: WakeupPin, enabled: bool) {
+  enable_pwr();
+  match pin {
+    WakeupPin::Wkup1 => PWR.csr.set_wakeup_pin1_on(enabled),
+    WakeupPin::Wkup2 => PWR.csr.set_wakeup_pin2_on(enabled),
+    WakeupPin::Wkup3 => PWR.csr.set_wakeup_pin3_on(enabled),
+  };
+}
+
+/// Enters stop mode until a wakeup source fires, then restores `clock`.
+pub fn stop(regulator: Regulator, clock: &ClockConfig) {
+  enable_pwr();
+  PWR.cr
+    .set_power_down_deep_sleep(false)
+    .set_low_power_deep_sleep(match regulator {
+      Regulator::Main => false,
+      Regulator::LowPower => true,
+    })
+    .set_clear_wakeup_flag(true);
+
+  scb::set_sleep_deep(true);
+  wfi();
+  scb::set_sleep_deep(false);
+
+  clock.setup();
+}
+
+/// Enters standby mode. The MCU wakes up through a reset, `woke_from_standby`
+/// tells such resets apart.
+pub fn standby() -> ! {
+  enable_pwr();
+  PWR.cr
+    .set_power_down_deep_sleep(true)
+    .set_clear_wakeup_flag(true);
+
+  scb::set_sleep_deep(true);
+  loop {
+    wfi();
+  }
+}
+
+/// Returns true if the MCU was reset by leaving standby mode.
+pub fn woke_from_standby() -> bool {
+  enable_pwr();
+  PWR.csr.standby_flag()
+}
+
+/// Clears the standby and wakeup flags.
+pub fn clear_standby_flags() {
+  enable_pwr();
+  PWR.cr
+    .set_clear_standby_flag(true)
+    .set_clear_wakeup_flag(true);
+}
+
+fn enable_pwr() {
+  PeripheralClock::Apb1(BusApb1::Pwr).enable();
+}
@@ -0,0 +1,298 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Real-time clock for ST STM32L1.
+//!
+//! The RTC and its backup registers are in the backup domain, which is kept
+//! over resets and standby, and on the battery supply on parts that have one.
+//! The alarm wakes the MCU up from stop mode through EXTI line 17, enabled
+//! with `power::enable_wakeup(WakeupSource::RtcAlarm)`, and from standby.
+//! The calendar counts years 2000 to 2099, other years are rejected with
+//! `OutOfRange`.
+
+use core::result::Result;
+use core::result::Result::{Ok, Err};
+
+use hal::rtc::{self, DateTime};
+use super::init::reg::{RCC, PWR};
+use super::peripheral_clock::{PeripheralClock, BusApb1};
+
+#[path="../../util/wait_for.rs"]
+#[macro_use] mod wait_for;
+
+/// Number of backup registers present on all STM32L1 parts.
+const BACKUP_REGISTERS: usize = 20;
+
+/// Years are counted from 2000.
+const BASE_YEAR: u16 = 2000;
+
+/// Last year the calendar counts.
+const LAST_YEAR: u16 = 2099;
+
+/// RTC clock source.
+#[derive(Clone, Copy)]
+pub enum RtcClock {
+  /// 32.768 kHz external crystal.
+  Lse,
+  /// About 37 kHz internal oscillator, not accurate.
+  Lsi,
+}
+
+impl RtcClock {
+  fn selection(&self) -> u32 {
+    match *self {
+      RtcClock::Lse => 0b01,
+      RtcClock::Lsi => 0b10,
+    }
+  }
+
+  /// Returns the asynchronous and synchronous prescalers dividing the clock
+  /// down to 1 Hz.
+  fn prescalers(&self) -> (u32, u32) {
+    match *self {
+      RtcClock::Lse => (127, 255),
+      RtcClock::Lsi => (127, 288),
+    }
+  }
+}
+
+/// Real-time clock.
+#[derive(Clone, Copy)]
+pub struct Rtc {
+  clock: RtcClock,
+}
+
+impl Rtc {
+  /// Enables writes to the backup domain and starts the RTC from `clock`,
+  /// unless it is already running.
+  pub fn new(clock: RtcClock) -> Rtc {
+    PeripheralClock::Apb1(BusApb1::Pwr).enable();
+    PWR.cr.set_backup_write_on(true);
+
+    match clock {
+      RtcClock::Lse => {
+        RCC.csr.set_lse_on(true);
+        wait_for!(RCC.csr.lse_ready());
+      },
+      RtcClock::Lsi => {
+        RCC.csr.set_lsi_on(true);
+        wait_for!(RCC.csr.lsi_ready());
+      },
+    }
+
+    if !RCC.csr.rtc_on() {
+      RCC.csr.set_rtc_source(clock.selection());
+      RCC.csr.set_rtc_on(true);
+    }
+
+    Rtc { clock: clock }
+  }
+
+  /// Waits for the calendar registers to be updated, to be called after
+  /// waking up from stop mode before reading the time.
+  pub fn resync(&self) {
+    unlock();
+    reg::RTC.isr.clear_rsf();
+    lock();
+    wait_for!(reg::RTC.isr.rsf());
+  }
+}
+
+impl rtc::Rtc for Rtc {
+  fn set_time(&self, time: &DateTime) -> Result<(), rtc::Error> {
+    let r = &reg::RTC;
+    let (prediv_a, prediv_s) = self.clock.prescalers();
+    try!(check_year(time));
+
+    unlock();
+    r.isr.set_init(true);
+    wait_for!(r.isr.initf());
+
+    r.prer.set_prediv_s(prediv_s);
+    r.prer.set_prediv_a(prediv_a);
+    r.tr
+      .set_hours(to_bcd(time.hour))
+      .set_minutes(to_bcd(time.minute))
+      .set_seconds(to_bcd(time.second))
+      .set_pm(false);
+    r.dr
+      .set_year(to_bcd((time.year - BASE_YEAR) as u8))
+      .set_weekday(time.weekday().number_from_monday() as u32)
+      .set_month(to_bcd(time.month))
+      .set_day(to_bcd(time.day));
+    r.cr.set_fmt(false);
+
+    r.isr.set_init(false);
+    lock();
+    Ok(())
+  }
+
+  fn time(&self) -> DateTime {
+    // Reading the time register locks the date register until it is read
+    let tr = reg::RTC.tr.get();
+    let dr = reg::RTC.dr.get();
+    DateTime {
+      year: BASE_YEAR + from_bcd(dr.year()) as u16,
+      month: from_bcd(dr.month()),
+      day: from_bcd(dr.day()),
+      hour: from_bcd(tr.hours()),
+      minute: from_bcd(tr.minutes()),
+      second: from_bcd(tr.seconds()),
+    }
+  }
+
+  /// Matches the day of the month and the time, so alarms must be less than
+  /// a month ahead.
+  fn set_alarm(&self, time: &DateTime) -> Result<(), rtc::Error> {
+    let r = &reg::RTC;
+    try!(check_year(time));
+
+    unlock();
+    r.cr.set_alrae(false);
+    wait_for!(r.isr.alrawf());
+    r.alrmar
+      .set_day(to_bcd(time.day))
+      .set_hours(to_bcd(time.hour))
+      .set_minutes(to_bcd(time.minute))
+      .set_seconds(to_bcd(time.second))
+      .set_wdsel(false)
+      .set_pm(false)
+      .set_mask_day(false)
+      .set_mask_hours(false)
+      .set_mask_minutes(false)
+      .set_mask_seconds(false);
+    r.cr
+      .set_alrae(true)
+      .set_alraie(true);
+    lock();
+    Ok(())
+  }
+
+  fn disable_alarm(&self) {
+    unlock();
+    reg::RTC.cr
+      .set_alrae(false)
+      .set_alraie(false);
+    lock();
+  }
+
+  fn alarm_pending(&self) -> bool {
+    reg::RTC.isr.alraf()
+  }
+
+  fn clear_alarm(&self) {
+    reg::RTC.isr.clear_alraf();
+  }
+}
+
+impl rtc::BackupRegisters for Rtc {
+  fn backup_len(&self) -> usize {
+    BACKUP_REGISTERS
+  }
+
+  fn read_backup(&self, index: usize) -> u32 {
+    reg::RTC.bkpr[index].value()
+  }
+
+  fn write_backup(&self, index: usize, value: u32) {
+    reg::RTC.bkpr[index].set_value(value);
+  }
+}
+
+/// Disables the write protection of the RTC registers.
+fn unlock() {
+  reg::RTC.wpr.set_key(0xca);
+  reg::RTC.wpr.set_key(0x53);
+}
+
+/// Enables the write protection of the RTC registers.
+fn lock() {
+  reg::RTC.wpr.set_key(0xff);
+}
+
+/// Fails if the calendar doesn't count the year of `time`.
+fn check_year(time: &DateTime) -> Result<(), rtc::Error> {
+  if time.year < BASE_YEAR || time.year > LAST_YEAR {
+    Err(rtc::Error::OutOfRange)
+  } else {
+    Ok(())
+  }
+}
+
+fn to_bcd(value: u8) -> u32 {
+  ((value / 10) << 4 | value % 10) as u32
+}
+
+fn from_bcd(value: u32) -> u8 {
+  ((value >> 4) * 10 + (value & 0xf)) as u8
+}
+
+mod reg {
+  use volatile_cell::VolatileCell;
+  use core::ops::Drop;
+
+  ioregs!(RTC = {
+    0x00 => reg32 tr {          // time, in BCD
+      6..0 => seconds : rw,
+      14..8 => minutes : rw,
+      21..16 => hours : rw,
+      22 => pm : rw,
+    },
+    0x04 => reg32 dr {          // date, in BCD
+      5..0 => day : rw,
+      12..8 => month : rw,
+      15..13 => weekday : rw,   // 1 is Monday
+      23..16 => year : rw,
+    },
+    0x08 => reg32 cr {          // control
+      6 => fmt : rw,            // 12 hour format
+      8 => alrae : rw,          // alarm A enable
+      12 => alraie : rw,        // alarm A interrupt enable
+    },
+    0x0C => reg32 isr {         // initialization and status
+      0 => alrawf : ro,         // alarm A write allowed
+      5 => rsf : zero_to_clear, // registers synchronized
+      6 => initf : ro,          // initialization allowed
+      7 => init : rw,           // initialization mode
+      8 => alraf : zero_to_clear, // alarm A flag
+    },
+    0x10 => reg32 prer {        // prescaler
+      14..0 => prediv_s : rw,
+      22..16 => prediv_a : rw,
+    },
+    0x1C => reg32 alrmar {      // alarm A, in BCD
+      6..0 => seconds : rw,
+      7 => mask_seconds : rw,
+      14..8 => minutes : rw,
+      15 => mask_minutes : rw,
+      21..16 => hours : rw,
+      22 => pm : rw,
+      23 => mask_hours : rw,
+      29..24 => day : rw,
+      30 => wdsel : rw,         // day is a weekday
+      31 => mask_day : rw,
+    },
+    0x24 => reg32 wpr {         // write protection
+      7..0 => key : wo,
+    },
+    0x50 => reg32 bkpr[20] {    // backup registers
+      31..0 => value : rw,
+    },
+  });
+
+  extern {
+    #[link_name="stm32l1_iomem_RTC"] pub static RTC: RTC;
+  }
+}
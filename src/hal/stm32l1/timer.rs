@@ -15,9 +15,31 @@
 
 //! Timer configuration for ST STM32L1.
 //!
-//! This code supports only TIM2 at the moment.
+//! This code supports only TIM2 at the moment, with input capture and one
+//! pulse output on its channels 1 to 4.
 
-#[path="../../util/ioreg.rs"] mod ioreg;
+use core::intrinsics::abort;
+use core::option::Option;
+
+use hal::cortex_m3::nvic;
+use hal::timer::CaptureEdge;
+
+/// IRQ of TIM2.
+const TIM2_IRQ: usize = 28;
+/// DIER and SR bit: update, raised when the counter overflows.
+const UPDATE: u16 = 1 << 0;
+
+/// CCMR channel mode: input, mapped to its own pin.
+const CCMR_INPUT: u16 = 0b01;
+/// CCMR channel mode: output, PWM mode 2, active from the compare value on.
+const CCMR_PWM2: u16 = 0b111 << 4;
+
+/// CCER channel bit: capture/compare enabled.
+const CCER_ENABLE: u16 = 1 << 0;
+/// CCER channel bit: capture on falling edges.
+const CCER_POLARITY: u16 = 1 << 1;
+/// CCER channel bit: with CCER_POLARITY, capture on both edges.
+const CCER_N_POLARITY: u16 = 1 << 3;
 
 /// Available timer peripherals.
 #[allow(missing_docs)]
@@ -59,6 +81,111 @@ impl ::hal::timer::Timer for Timer {
   fn get_counter(&self) -> u32 {
     self.reg.cnt.counter() as u32
   }
+
+  fn counter_bits(&self) -> u32 {
+    16
+  }
+}
+
+impl ::hal::timer::OverflowTimer for Timer {
+  fn enable_overflow(&self) {
+    let enable = self.reg.dier.enable();
+    self.reg.dier.set_enable(enable | UPDATE);
+    nvic::enable_irq(TIM2_IRQ);
+  }
+
+  fn disable_overflow(&self) {
+    let enable = self.reg.dier.enable();
+    self.reg.dier.set_enable(enable & !UPDATE);
+  }
+
+  fn clear_overflow(&self) {
+    // Status flags are cleared by writing 0, writing 1 leaves them unchanged
+    self.reg.sr.set_status(!UPDATE);
+  }
+}
+
+impl Timer {
+  /// Returns the index of `channel`, from 1 to 4, in the register arrays.
+  fn channel_index(channel: u8) -> usize {
+    match channel {
+      1...4 => channel as usize - 1,
+      _ => unsafe { abort() },
+    }
+  }
+
+  /// Sets the CCMR mode bits of `channel`, which must be disabled.
+  fn set_channel_mode(&self, channel: u8, mode: u16) {
+    let index = Timer::channel_index(channel);
+    let shift = index % 2 * 8;
+    let update = |modes: u16| (modes & !(0xff << shift)) | (mode << shift);
+    if index < 2 {
+      let modes = self.reg.ccmr1.mode();
+      self.reg.ccmr1.set_mode(update(modes));
+    } else {
+      let modes = self.reg.ccmr2.mode();
+      self.reg.ccmr2.set_mode(update(modes));
+    }
+  }
+
+  /// Sets the CCER bits of `channel`.
+  fn set_channel(&self, channel: u8, bits: u16) {
+    let shift = Timer::channel_index(channel) * 4;
+    let enable = self.reg.ccer.enable();
+    self.reg.ccer.set_enable((enable & !(0xf << shift)) | (bits << shift));
+  }
+}
+
+impl ::hal::timer::Capture for Timer {
+  fn enable_capture(&self, channel: u8, edge: CaptureEdge) {
+    let polarity = match edge {
+      CaptureEdge::Rising  => 0,
+      CaptureEdge::Falling => CCER_POLARITY,
+      CaptureEdge::Both    => CCER_POLARITY | CCER_N_POLARITY,
+    };
+    self.set_channel(channel, 0);
+    self.set_channel_mode(channel, CCMR_INPUT);
+    self.set_channel(channel, CCER_ENABLE | polarity);
+  }
+
+  fn disable_capture(&self, channel: u8) {
+    self.set_channel(channel, 0);
+  }
+
+  fn take_capture(&self, channel: u8) -> Option<u32> {
+    let index = Timer::channel_index(channel);
+    // CCxIF flags are bits 1 to 4
+    if self.reg.sr.status() & (1 << (index + 1)) != 0 {
+      // Reading the capture clears the flag
+      Some(self.reg.ccr[index].cc())
+    } else {
+      None
+    }
+  }
+}
+
+impl ::hal::timer::OnePulse for Timer {
+  /// Puts the timer in one pulse mode: the counter restarts from 0 and stops
+  /// after the pulse, so the timer can't be used for delays any more.
+  /// `delay + width` must fit in the 16 bit counter.
+  fn one_pulse(&self, channel: u8, delay: u32, width: u32) {
+    // The output is active while the counter is at or above the compare
+    // value, which must not be 0 for the output to go inactive once the
+    // counter stops at 0.
+    let delay = if delay == 0 { 1 } else { delay };
+    let index = Timer::channel_index(channel);
+
+    self.reg.cr1.set_counter_enable(false);
+    self.set_channel(channel, 0);
+    self.set_channel_mode(channel, CCMR_PWM2);
+    self.reg.ccr[index].set_cc(delay);
+    self.reg.arr.set_reload(delay + width - 1);
+    self.reg.cnt.set_counter(0);
+    self.set_channel(channel, CCER_ENABLE);
+    self.reg.cr1
+      .set_one_pulse_mode(true)
+      .set_counter_enable(true);
+  }
 }
 
 mod reg {
@@ -109,16 +236,7 @@ mod reg {
     0x2C => reg32 arr {      // auto-reload
       31..0 => reload : rw,
     },
-    0x34 => reg32 ccr1 {     // capture/compare 1
-      31..0 => cc : rw,
-    },
-    0x38 => reg32 ccr2 {     // capture/compare 2
-      31..0 => cc : rw,
-    },
-    0x3C => reg32 ccr3 {     // capture/compare 3
-      31..0 => cc : rw,
-    },
-    0x40 => reg32 ccr4 {     // capture/compare 4
+    0x34 => reg32 ccr[4] {   // capture/compare 1 to 4
       31..0 => cc : rw,
     },
     0x48 => reg16 dcr {      // DMA control
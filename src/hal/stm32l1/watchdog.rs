@@ -0,0 +1,40 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Independent and window watchdogs for ST STM32L1.
+//!
+//! `Wwdg::new` takes the APB1 frequency, see `ClockConfig::get_apb1_frequency`.
+
+use super::peripheral_clock::{PeripheralClock, BusApb1};
+
+#[path="../../util/wait_for.rs"]
+#[macro_use] mod wait_for;
+
+#[path="../stm32_common/watchdog.rs"]
+mod common;
+
+pub use self::common::{Iwdg, Wwdg};
+
+/// Nominal frequency of the LSI oscillator clocking the independent watchdog.
+const LSI_HZ: u32 = 37000;
+
+fn enable_wwdg_clock() {
+  PeripheralClock::Apb1(BusApb1::Wwdg).enable();
+}
+
+extern {
+  #[link_name="stm32l1_iomem_IWDG"] static IWDG: common::reg::IWDG;
+  #[link_name="stm32l1_iomem_WWDG"] static WWDG: common::reg::WWDG;
+}
@@ -0,0 +1,395 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/*!
+Monotonic time.
+
+`Clock` extends the microsecond counter of a `Timer` to 64 bits, so that
+`Instant`s can be compared and waited for without caring about the counter
+wrapping around. The counter is extended every time the clock is read, so it
+must be read at least once per counter period: about 71 minutes for 32-bit
+counters, but only 65 milliseconds for 16-bit ones. Programs that may go
+longer without looking at the time should enable the timer overflow interrupt
+with `Clock::enable_overflow` and call `Clock::on_overflow` from its ISR.
+
+Arithmetic on `Duration`s and `Instant`s saturates instead of overflowing.
+*/
+
+use core::ops::{Add, Sub};
+use core::option::Option;
+
+use hal::timer::{OverflowTimer, Timer, counter_mask};
+use util::shared::{NoInterrupts, Shared};
+
+#[path="../util/wait_for.rs"]
+#[macro_use] mod wait_for;
+
+/// A span of time, with microsecond resolution.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Duration {
+  us: u64,
+}
+
+impl Duration {
+  /// Creates a duration of `us` microseconds.
+  pub fn from_us(us: u64) -> Duration {
+    Duration { us: us }
+  }
+
+  /// Creates a duration of `ms` milliseconds.
+  pub fn from_ms(ms: u64) -> Duration {
+    Duration { us: ms.saturating_mul(1000) }
+  }
+
+  /// Creates a duration of `s` seconds.
+  pub fn from_secs(s: u64) -> Duration {
+    Duration { us: s.saturating_mul(1000000) }
+  }
+
+  /// Returns the duration in microseconds.
+  pub fn as_us(&self) -> u64 {
+    self.us
+  }
+
+  /// Returns the duration in whole milliseconds.
+  pub fn as_ms(&self) -> u64 {
+    self.us / 1000
+  }
+
+  /// Returns the sum of the durations, or None if it overflows.
+  pub fn checked_add(&self, other: Duration) -> Option<Duration> {
+    self.us.checked_add(other.us).map(|us| Duration { us: us })
+  }
+
+  /// Returns the difference of the durations, or None if `other` is longer.
+  pub fn checked_sub(&self, other: Duration) -> Option<Duration> {
+    self.us.checked_sub(other.us).map(|us| Duration { us: us })
+  }
+}
+
+impl Add for Duration {
+  type Output = Duration;
+
+  /// Saturates at the longest duration.
+  fn add(self, other: Duration) -> Duration {
+    Duration { us: self.us.saturating_add(other.us) }
+  }
+}
+
+impl Sub for Duration {
+  type Output = Duration;
+
+  /// Saturates at a zero duration.
+  fn sub(self, other: Duration) -> Duration {
+    Duration { us: self.us.saturating_sub(other.us) }
+  }
+}
+
+/// A point in time of a `Clock`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Instant {
+  us: u64,
+}
+
+impl Instant {
+  /// Returns the instant in microseconds, the low `counter_bits()` bits are
+  /// the value of the timer counter at this instant.
+  pub fn as_us(&self) -> u64 {
+    self.us
+  }
+
+  /// Returns the time passed from `earlier` to this instant, or a zero
+  /// duration if `earlier` is later.
+  pub fn duration_since(&self, earlier: Instant) -> Duration {
+    Duration { us: self.us.saturating_sub(earlier.us) }
+  }
+
+  /// Returns the instant `duration` after this one, or None if it overflows.
+  pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+    self.us.checked_add(duration.us).map(|us| Instant { us: us })
+  }
+
+  /// Returns the instant `duration` before this one, or None if it would be
+  /// before the clock started.
+  pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+    self.us.checked_sub(duration.us).map(|us| Instant { us: us })
+  }
+}
+
+impl Add<Duration> for Instant {
+  type Output = Instant;
+
+  /// Saturates at the latest instant, which is never reached.
+  fn add(self, other: Duration) -> Instant {
+    Instant { us: self.us.saturating_add(other.us) }
+  }
+}
+
+impl Sub<Duration> for Instant {
+  type Output = Instant;
+
+  /// Saturates at the start of the clock.
+  fn sub(self, other: Duration) -> Instant {
+    Instant { us: self.us.saturating_sub(other.us) }
+  }
+}
+
+impl Sub for Instant {
+  type Output = Duration;
+
+  fn sub(self, other: Instant) -> Duration {
+    self.duration_since(other)
+  }
+}
+
+struct ClockState {
+  last: u32,
+  wraps: u64,
+}
+
+/// A 64-bit monotonic clock driven by a microsecond `Timer`.
+pub struct Clock<'a, T: 'a> {
+  timer: &'a T,
+  state: Shared<ClockState>,
+}
+
+impl<'a, T: Timer> Clock<'a, T> {
+  /// Creates a clock reading the counter of `timer`.
+  pub fn new(timer: &'a T) -> Clock<'a, T> {
+    Clock {
+      timer: timer,
+      state: Shared::new(ClockState {
+        last: timer.get_counter() & counter_mask(timer.counter_bits()),
+        wraps: 0,
+      }),
+    }
+  }
+
+  /// Returns the current time.
+  pub fn now(&self) -> Instant {
+    let crit = NoInterrupts::new();
+    let mut state = self.state.borrow(&crit);
+
+    let bits = self.timer.counter_bits();
+    let counter = self.timer.get_counter() & counter_mask(bits);
+    if counter < state.last {
+      state.wraps += 1;
+    }
+    state.last = counter;
+
+    Instant { us: (state.wraps << bits) | counter as u64 }
+  }
+
+  /// Extends the counter, to be called from an interrupt firing at least once
+  /// per counter period. See `on_overflow` for timers with an overflow
+  /// interrupt.
+  pub fn update(&self) {
+    self.now();
+  }
+
+  /// Returns the time passed since `earlier`.
+  pub fn elapsed(&self, earlier: Instant) -> Duration {
+    self.now().duration_since(earlier)
+  }
+
+  /// Waits until `deadline` has passed.
+  pub fn wait_until(&self, deadline: Instant) {
+    wait_for!(self.now() >= deadline);
+  }
+
+  /// Waits for `duration`.
+  pub fn wait(&self, duration: Duration) {
+    let deadline = self.now() + duration;
+    self.wait_until(deadline);
+  }
+
+  /// Waits while `condition` holds. Returns false if `deadline` passed
+  /// before the condition changed.
+  pub fn wait_while<F: Fn() -> bool>(&self, deadline: Instant, condition: F)
+      -> bool {
+    while condition() {
+      if self.now() >= deadline {
+        return false;
+      }
+    }
+    true
+  }
+}
+
+impl<'a, T: OverflowTimer> Clock<'a, T> {
+  /// Enables the timer overflow interrupt, so that the clock keeps time
+  /// however long it goes unread. The ISR must call `on_overflow`.
+  pub fn enable_overflow(&self) {
+    self.timer.enable_overflow();
+  }
+
+  /// Acknowledges the timer overflow and extends the counter, to be called
+  /// from the timer overflow interrupt.
+  pub fn on_overflow(&self) {
+    self.timer.clear_overflow();
+    self.update();
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use core::cell::Cell;
+  use super::{Clock, Duration, Instant};
+  use hal::timer::{OverflowTimer, Timer};
+  use volatile_cell::{VolatileCell, VolatileCellReplayer, set_replayer};
+  use volatile_cell::models::{Clock as ModelClock, CounterModel};
+  use expectest::prelude::*;
+  use expectest;
+
+  struct CounterTimer {
+    counter: VolatileCell<u32>,
+  }
+
+  impl Timer for CounterTimer {
+    fn get_counter(&self) -> u32 {
+      self.counter.get()
+    }
+  }
+
+  fn counter_of(timer: &CounterTimer) -> usize {
+    &timer.counter as *const VolatileCell<u32> as usize
+  }
+
+  #[test]
+  fn extends_counter_across_wrap() {
+    init_replayer!();
+    let timer = CounterTimer { counter: VolatileCell::new(0) };
+    let counter = counter_of(&timer);
+
+    expect_volatile_read!(counter, 0xffff_fff0);
+    expect_volatile_read!(counter, 0xffff_fff8);
+    expect_volatile_read!(counter, 0x10);
+
+    let clock = Clock::new(&timer);
+    let before = clock.now();
+    let after = clock.now();
+
+    expect!((after - before).as_us()).to(be_equal_to(0x18));
+    expect!(after > before).to(be_true());
+    expect_replayer_valid!();
+  }
+
+  struct NarrowTimer {
+    counter: VolatileCell<u32>,
+    overflow_cleared: Cell<bool>,
+  }
+
+  impl Timer for NarrowTimer {
+    fn get_counter(&self) -> u32 {
+      self.counter.get()
+    }
+
+    fn counter_bits(&self) -> u32 {
+      16
+    }
+  }
+
+  impl OverflowTimer for NarrowTimer {
+    fn enable_overflow(&self) {}
+
+    fn disable_overflow(&self) {}
+
+    fn clear_overflow(&self) {
+      self.overflow_cleared.set(true);
+    }
+  }
+
+  #[test]
+  fn extends_narrow_counter_across_wrap() {
+    init_replayer!();
+    let timer = NarrowTimer {
+      counter: VolatileCell::new(0),
+      overflow_cleared: Cell::new(false),
+    };
+    let counter = &timer.counter as *const VolatileCell<u32> as usize;
+
+    expect_volatile_read!(counter, 0xfff0);
+    expect_volatile_read!(counter, 0xfff8);
+    expect_volatile_read!(counter, 0x10);
+    expect_volatile_read!(counter, 0x20);
+
+    let clock = Clock::new(&timer);
+    let before = clock.now();
+    clock.on_overflow();
+    let after = clock.now();
+
+    expect!(timer.overflow_cleared.get()).to(be_true());
+    expect!((after - before).as_us()).to(be_equal_to(0x28));
+    expect!(after.as_us()).to(be_equal_to(0x10020));
+    expect_replayer_valid!();
+  }
+
+  #[test]
+  fn waits_for_duration_across_wrap() {
+    init_replayer!();
+    let timer = CounterTimer { counter: VolatileCell::new(0) };
+    let clock = ModelClock::new();
+    add_volatile_model!(CounterModel::new(counter_of(&timer), clock.clone(), 7));
+    clock.advance(0xffff_fff0 - 7);
+
+    let time = Clock::new(&timer);
+    let start = time.now();
+    time.wait(Duration::from_ms(1));
+
+    expect!(time.elapsed(start) >= Duration::from_ms(1)).to(be_true());
+    expect!(clock.now() < 0xffff_fff0 + 1000 + 5 * 7).to(be_true());
+  }
+
+  #[test]
+  fn wait_while_times_out() {
+    init_replayer!();
+    let timer = CounterTimer { counter: VolatileCell::new(0) };
+    let clock = ModelClock::new();
+    add_volatile_model!(CounterModel::new(counter_of(&timer), clock.clone(), 1));
+
+    let time = Clock::new(&timer);
+    let deadline = time.now() + Duration::from_us(50);
+
+    expect!(time.wait_while(deadline, || true)).to(be_equal_to(false));
+    expect!(time.wait_while(deadline, || false)).to(be_true());
+  }
+
+  #[test]
+  fn converts_durations() {
+    expect!(Duration::from_secs(5000).as_us()).to(be_equal_to(5000000000));
+    expect!(Duration::from_ms(3).as_us()).to(be_equal_to(3000));
+    expect!((Duration::from_ms(2) - Duration::from_us(500)).as_ms())
+        .to(be_equal_to(1));
+  }
+
+  #[test]
+  fn saturates_arithmetic() {
+    let max = Duration::from_us(!0);
+    expect!(Duration::from_secs(!0)).to(be_equal_to(max));
+    expect!(max + Duration::from_us(1)).to(be_equal_to(max));
+    expect!(Duration::from_us(1) - Duration::from_us(2))
+        .to(be_equal_to(Duration::from_us(0)));
+    expect!(max.checked_add(Duration::from_us(1))).to(be_equal_to(None));
+    expect!(Duration::from_us(1).checked_sub(Duration::from_us(2)))
+        .to(be_equal_to(None));
+
+    let start = Instant { us: 10 };
+    expect!((start - Duration::from_us(20)).as_us()).to(be_equal_to(0));
+    expect!((start + max).as_us()).to(be_equal_to(!0));
+    expect!(start.checked_sub(Duration::from_us(20))).to(be_equal_to(None));
+    expect!(start.checked_add(Duration::from_us(5)))
+        .to(be_equal_to(Some(Instant { us: 15 })));
+  }
+}
@@ -18,9 +18,18 @@ Timer interface.
 
 TimerConf is a MCU-specific struct.
 
-Timers provide a simple way to delay program execution for some time.
+Timers provide a simple way to delay program execution for some time. See
+`hal::time` for a monotonic clock that doesn't wrap. Counters may be narrower
+than 32 bits, `Timer::counter_bits` tells how many bits are significant.
+
+`Capture` timers latch their counter on edges of input pins, to timestamp
+edges and measure pulses, and `OnePulse` timers output single pulses.
 */
 
+use core::option::Option;
+
+use hal::pin::GpioLevel;
+
 #[path="../util/wait_for.rs"]
 #[macro_use] mod wait_for;
 
@@ -29,22 +38,355 @@ pub trait Timer {
   /// Implementation-specific method to wait a given number of microseconds.
   fn get_counter(&self) -> u32;
 
+  /// Returns the number of significant bits of the counter, which wraps
+  /// around to zero after `2^counter_bits() - 1`.
+  fn counter_bits(&self) -> u32 {
+    32
+  }
+
   #[inline(always)]
   /// Waits for specified number of microseconds.
   fn wait_us(&self, us: u32) {
-    let start = self.get_counter();
-    wait_for!((self.get_counter() - start) >= us);
+    // Sum up the ticks between reads, so that counters narrower than 32 bits
+    // can wait for longer than their period
+    let mut last = self.get_counter();
+    let mut elapsed = 0u64;
+    wait_for!({
+      let now = self.get_counter();
+      elapsed += ticks_between(self, last, now) as u64;
+      last = now;
+      elapsed >= us as u64
+    });
   }
 
   #[inline(always)]
   /// Waits for specified number of milliseconds.
   fn wait_ms(&self, ms: u32) {
-    self.wait_us(ms * 1000);
+    // Wait a second at a time, so that `ms * 1000` can't overflow
+    for _ in 0..(ms / 1000) {
+      self.wait_us(1000000);
+    }
+    self.wait_us((ms % 1000) * 1000);
   }
 
   #[inline(always)]
   /// Waits for specified number of seconds.
   fn wait(&self, s: u32) {
-    self.wait_us(s * 1000000);
+    for _ in 0..s {
+      self.wait_us(1000000);
+    }
+  }
+}
+
+/// Returns the ticks from counter value `start` to `end` of `timer`,
+/// accounting for counters narrower than 32 bits.
+pub fn ticks_between<T: Timer + ?Sized>(timer: &T, start: u32, end: u32) -> u32 {
+  end.wrapping_sub(start) & counter_mask(timer.counter_bits())
+}
+
+/// Returns the mask of the significant bits of a `bits` wide counter.
+pub fn counter_mask(bits: u32) -> u32 {
+  if bits >= 32 { !0 } else { (1 << bits) - 1 }
+}
+
+/// A timer with an overflow interrupt, raised when the counter wraps around
+/// to zero. Used by `hal::time::Clock` to keep time without being read.
+pub trait OverflowTimer: Timer {
+  /// Enables the overflow interrupt.
+  fn enable_overflow(&self);
+
+  /// Disables the overflow interrupt.
+  fn disable_overflow(&self);
+
+  /// Acknowledges the overflow interrupt, to be called from its ISR.
+  fn clear_overflow(&self);
+}
+
+/// A timer with a compare interrupt, used by `hal::alarm`.
+pub trait CompareTimer: Timer {
+  /// Raises the compare interrupt when `get_counter()` reaches `counter`.
+  fn set_compare(&self, counter: u32);
+
+  /// Disables the compare interrupt.
+  fn disable_compare(&self);
+
+  /// Acknowledges the compare interrupt, to be called from its ISR.
+  fn clear_compare(&self);
+}
+
+/// Input edges latched by a `Capture` timer.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CaptureEdge {
+  /// Low to high.
+  Rising,
+  /// High to low.
+  Falling,
+  /// Either.
+  Both,
+}
+
+/// A timer latching its counter on edges of input pins. Captured values and
+/// measurements are in counter ticks, that is microseconds for timers set up
+/// for `Timer` unless the MCU `timer` module says otherwise, and have
+/// `counter_bits()` significant bits. The pins must be routed to the timer
+/// inputs.
+pub trait Capture: Timer {
+  /// Starts latching the counter on `edge`s of input `channel`.
+  fn enable_capture(&self, channel: u8, edge: CaptureEdge);
+
+  /// Stops capturing on `channel`.
+  fn disable_capture(&self, channel: u8);
+
+  /// Returns the counter latched by the last edge on `channel`, or None if
+  /// there was no edge since the last call.
+  fn take_capture(&self, channel: u8) -> Option<u32>;
+
+  /// Waits up to `timeout` ticks for an edge on `channel` and returns its
+  /// capture.
+  fn wait_capture(&self, channel: u8, timeout: u32) -> Option<u32> {
+    let start = self.get_counter();
+    loop {
+      if let Some(capture) = self.take_capture(channel) {
+        return Some(capture);
+      }
+      if ticks_between(self, start, self.get_counter()) >= timeout {
+        return None;
+      }
+    }
+  }
+
+  /// Measures the width of the next pulse at `level` on `channel`, waiting
+  /// up to `timeout` ticks for each of its edges. The capture is switched to
+  /// the trailing edge after the leading one, so very short pulses are
+  /// missed.
+  fn measure_pulse(&self, channel: u8, level: GpioLevel, timeout: u32)
+      -> Option<u32> {
+    let (leading, trailing) = match level {
+      GpioLevel::High => (CaptureEdge::Rising, CaptureEdge::Falling),
+      GpioLevel::Low => (CaptureEdge::Falling, CaptureEdge::Rising),
+    };
+
+    self.enable_capture(channel, leading);
+    // Drop edges captured before the call
+    self.take_capture(channel);
+    let result = match self.wait_capture(channel, timeout) {
+      Some(start) => {
+        self.enable_capture(channel, trailing);
+        self.wait_capture(channel, timeout)
+            .map(|end| ticks_between(self, start, end))
+      },
+      None => None,
+    };
+    self.disable_capture(channel);
+    result
+  }
+
+  /// Measures the period of the signal on `channel`, between two rising
+  /// edges, waiting up to `timeout` ticks for each of them.
+  fn measure_period(&self, channel: u8, timeout: u32) -> Option<u32> {
+    self.enable_capture(channel, CaptureEdge::Rising);
+    self.take_capture(channel);
+    let result = match self.wait_capture(channel, timeout) {
+      Some(start) => self.wait_capture(channel, timeout)
+          .map(|end| ticks_between(self, start, end)),
+      None => None,
+    };
+    self.disable_capture(channel);
+    result
+  }
+
+  /// Measures the frequency of the signal on `channel` in Hz. Implemented for
+  /// a timer counting microseconds, MCUs counting other ticks override it.
+  fn measure_frequency(&self, channel: u8, timeout: u32) -> Option<u32> {
+    match self.measure_period(channel, timeout) {
+      Some(period) if period > 0 => Some(1000000 / period),
+      _ => None,
+    }
+  }
+}
+
+/// A timer outputting single pulses.
+pub trait OnePulse {
+  /// Outputs a pulse of `width` ticks on `channel`, starting `delay` ticks
+  /// from now.
+  fn one_pulse(&self, channel: u8, delay: u32, width: u32);
+}
+
+#[cfg(test)]
+mod test {
+  use core::cell::Cell;
+  use super::{Capture, CaptureEdge, Timer};
+  use hal::pin::GpioLevel;
+  use volatile_cell::{VolatileCell, VolatileCellReplayer, set_replayer};
+  use volatile_cell::models::{Clock as ModelClock, CounterModel};
+  use expectest::prelude::*;
+  use expectest;
+
+  struct CounterTimer {
+    counter: VolatileCell<u32>,
+  }
+
+  impl Timer for CounterTimer {
+    fn get_counter(&self) -> u32 {
+      self.counter.get()
+    }
+  }
+
+  #[test]
+  fn waits_until_counter_advances() {
+    init_replayer!();
+    let timer = CounterTimer { counter: VolatileCell::new(0) };
+    let counter = &timer.counter as *const VolatileCell<u32> as usize;
+
+    expect_volatile_read!(counter, 100);
+    expect_volatile_poll!(counter, 105, 110, 5);
+
+    timer.wait_us(10);
+
+    expect_replayer_valid!();
+  }
+
+  #[test]
+  fn waits_across_counter_wrap() {
+    init_replayer!();
+    let timer = CounterTimer { counter: VolatileCell::new(0) };
+    let counter = &timer.counter as *const VolatileCell<u32> as usize;
+
+    expect_volatile_read!(counter, 0xffff_fffb);
+    expect_volatile_poll!(counter, 0xffff_fffe, 5, 3);
+
+    timer.wait_us(10);
+
+    expect_replayer_valid!();
+  }
+
+  struct NarrowTimer {
+    counter: VolatileCell<u32>,
+  }
+
+  impl Timer for NarrowTimer {
+    fn get_counter(&self) -> u32 {
+      self.counter.get() & 0xffff
+    }
+
+    fn counter_bits(&self) -> u32 {
+      16
+    }
+  }
+
+  #[test]
+  fn waits_across_narrow_counter_wrap() {
+    init_replayer!();
+    let timer = NarrowTimer { counter: VolatileCell::new(0) };
+    let counter = &timer.counter as *const VolatileCell<u32> as usize;
+
+    expect_volatile_read!(counter, 0xfffb);
+    expect_volatile_poll!(counter, 0xfffe, 5, 3);
+
+    timer.wait_us(10);
+
+    expect_replayer_valid!();
+  }
+
+  #[test]
+  fn waits_longer_than_narrow_counter_period() {
+    init_replayer!();
+    let timer = NarrowTimer { counter: VolatileCell::new(0) };
+    let clock = ModelClock::new();
+    add_volatile_model!(CounterModel::new(
+        &timer.counter as *const VolatileCell<u32> as usize, clock.clone(), 1000));
+
+    timer.wait_ms(200);
+
+    expect!(clock.now() >= 200000).to(be_true());
+    expect!(clock.now() <= 201000).to(be_true());
+  }
+
+  /// Timer running one tick per read, with input edges at given ticks.
+  struct EdgeTimer {
+    now: Cell<u32>,
+    /// Edges as (tick, rising).
+    edges: [(u32, bool); 4],
+    next_edge: Cell<usize>,
+    capture: Cell<Option<CaptureEdge>>,
+  }
+
+  impl EdgeTimer {
+    fn new(edges: [(u32, bool); 4]) -> EdgeTimer {
+      EdgeTimer {
+        now: Cell::new(0),
+        edges: edges,
+        next_edge: Cell::new(0),
+        capture: Cell::new(None),
+      }
+    }
+  }
+
+  impl Timer for EdgeTimer {
+    fn get_counter(&self) -> u32 {
+      self.now.set(self.now.get() + 1);
+      self.now.get()
+    }
+
+    fn counter_bits(&self) -> u32 {
+      16
+    }
+  }
+
+  impl Capture for EdgeTimer {
+    fn enable_capture(&self, _: u8, edge: CaptureEdge) {
+      self.capture.set(Some(edge));
+    }
+
+    fn disable_capture(&self, _: u8) {
+      self.capture.set(None);
+    }
+
+    fn take_capture(&self, _: u8) -> Option<u32> {
+      let mut latched = None;
+      while self.next_edge.get() < self.edges.len() {
+        let (tick, rising) = self.edges[self.next_edge.get()];
+        if tick > self.now.get() {
+          break;
+        }
+        let matches = match self.capture.get() {
+          Some(CaptureEdge::Rising) => rising,
+          Some(CaptureEdge::Falling) => !rising,
+          Some(CaptureEdge::Both) => true,
+          None => false,
+        };
+        if matches {
+          latched = Some(tick & 0xffff);
+        }
+        self.next_edge.set(self.next_edge.get() + 1);
+      }
+      latched
+    }
+  }
+
+  #[test]
+  fn measures_pulses() {
+    let timer = EdgeTimer::new(
+        [(10, false), (20, true), (45, false), (60, true)]);
+    expect!(timer.measure_pulse(0, GpioLevel::High, 100))
+        .to(be_equal_to(Some(25)));
+    expect!(timer.measure_pulse(0, GpioLevel::Low, 100))
+        .to(be_equal_to(None));
+  }
+
+  #[test]
+  fn measures_period_across_wrap() {
+    let timer = EdgeTimer::new(
+        [(0xfff0, true), (0xfff8, false), (0x10020, true), (0x10030, false)]);
+    timer.now.set(0xffe0);
+    expect!(timer.measure_period(0, 0x100)).to(be_equal_to(Some(0x30)));
+  }
+
+  #[test]
+  fn times_out_without_edges() {
+    let timer = EdgeTimer::new(
+        [(1000, true), (1001, false), (1002, true), (1003, false)]);
+    expect!(timer.wait_capture(0, 50)).to(be_equal_to(None));
+    expect!(timer.now.get() < 100).to(be_true());
   }
 }
@@ -18,8 +18,24 @@ use syntax::ext::base::ExtCtxt;
 use syntax::ext::build::AstBuilder;
 
 use builder::{Builder, TokenString};
+use builder::clock::{Factor, PllLimits, format_frequency};
+use builder::clock;
 use node;
 
+/// The PLL runs at 400MHz and is divided by SYSDIV2/SYSDIV2LSB (DIV400 mode),
+/// see TM4C123GH6PM datasheet 5.3.
+static PLL_LIMITS: PllLimits = PllLimits {
+  input_div: Factor::Range(1, 1),
+  mult: Factor::Range(1, 1),
+  output_div: Factor::Range(5, 128),
+  vco_scale: 1,
+  input_range: (PLL_FREQUENCY, PLL_FREQUENCY),
+  vco_range: (PLL_FREQUENCY, PLL_FREQUENCY),
+  max_output: 80_000_000,
+};
+
+const PLL_FREQUENCY: usize = 400_000_000;
+
 pub fn attach(_: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
   node.materializer.set(Some(build_clock as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
 }
@@ -33,8 +49,47 @@ fn build_clock(builder: &mut Builder,
 
   let source = TokenString(node.get_string_attr("source").unwrap());
 
-  let use_pll = node.get_bool_attr("pll").unwrap_or(false);
-  let div     = node.get_int_attr("div").unwrap_or(1);
+  let (use_pll, div) = match node.get_int_attr("target_frequency") {
+    Some(target) => {
+      for attr in ["pll", "div"].iter() {
+        if node.attributes.borrow().contains_key(*attr) {
+          cx.parse_sess().span_diagnostic.span_err(
+              node.get_attr(attr).key_span,
+              format!("attribute `{}` can't be used together with \
+                      `target_frequency`", attr).as_str());
+          return;
+        }
+      }
+      let span = node.get_attr("target_frequency").value_span;
+      match clock::solve_pll(PLL_FREQUENCY, target, &PLL_LIMITS) {
+        Some(solution) => {
+          if solution.output != target {
+            cx.parse_sess().span_diagnostic.span_warn(span,
+                format!("can't generate exactly {}, using {}",
+                    format_frequency(target),
+                    format_frequency(solution.output)).as_str());
+          }
+          (true, solution.output_div)
+        },
+        None => {
+          cx.parse_sess().span_diagnostic.span_err(span,
+              format!("can't generate {}, max sysclk is {}",
+                  format_frequency(target),
+                  format_frequency(PLL_LIMITS.max_output)).as_str());
+          return;
+        },
+      }
+    },
+    None => (node.get_bool_attr("pll").unwrap_or(false),
+             node.get_int_attr("div").unwrap_or(1)),
+  };
+
+  if use_pll {
+    builder.add_report(format!("tiva_c clock: sysclk = {} (PLL {} / {})",
+        format_frequency(PLL_FREQUENCY / div),
+        format_frequency(PLL_FREQUENCY), div));
+  }
+
   let xtal    = TokenString(node.get_string_attr("xtal")
                             .unwrap_or("X16_0MHz".to_string()));
 
@@ -52,3 +107,52 @@ fn build_clock(builder: &mut Builder,
   );
   builder.add_main_statement(cx.stmt_expr(ex));
 }
+
+#[cfg(test)]
+mod test {
+  use builder::Builder;
+  use test_helpers::{assert_equal_source, with_parsed};
+
+  #[test]
+  fn builds_clock_for_target_frequency() {
+    with_parsed("
+      clock {
+        source = \"MOSC\";
+        xtal = \"X16_0MHz\";
+        target_frequency = 50_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_clock(&mut builder, cx, pt.get_by_path("clock").unwrap());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 1);
+      assert!(builder.reports().len() == 1);
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "{
+            use zinc::hal::tiva_c::sysctl::clock;
+            use core::option::Option::Some;
+
+            clock::sysclk_configure(
+              zinc::hal::tiva_c::sysctl::clock::ClockSource::MOSC,
+              Some(zinc::hal::tiva_c::sysctl::clock::MOSCFreq::X16_0MHz),
+              true,
+              Some(8usize));
+          }");
+    });
+  }
+
+  #[test]
+  fn fails_to_build_target_frequency_with_div() {
+    with_parsed("
+      clock {
+        source = \"MOSC\";
+        div = 5;
+        target_frequency = 80_000_000;
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_clock(&mut builder, cx, pt.get_by_path("clock").unwrap());
+      assert!(unsafe{*failed} == true);
+      assert!(builder.main_stmts().len() == 0);
+    });
+  }
+}
@@ -18,10 +18,10 @@
 
 pub mod sysctl;
 pub mod pin;
+pub mod reset;
 pub mod timer;
 pub mod uart;
 pub mod spi;
 pub mod pwm;
 pub mod qei;
-
-#[path="../../util/ioreg.rs"] mod util;
+pub mod watchdog;
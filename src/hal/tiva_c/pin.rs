@@ -21,6 +21,8 @@
 
 use hal::cortex_m4::nvic;
 use hal::pin::{Gpio, GpioDirection, In, Out, GpioLevel, High, Low};
+use hal::pin::{GpioPull, GpioSpeed, GpioTrigger, InterruptHandler, InterruptPin,
+               PinConfig};
 use hal::tiva_c::sysctl;
 
 macro_rules! pin {
@@ -151,15 +153,12 @@ pub trait Pin {
       }
     }
 
-    // We can chose to drive each GPIO at either 2, 4 or 8mA. Default to 2mA for
-    // now.
-    // TODO(simias): make that configurable
+    // We can chose to drive each GPIO at either 2, 4 or 8mA. Default to 2mA,
+    // `PinConfig::set_speed` selects the others.
     self.regs().dr2r.set_dr2r(self.index(), true);
     self.regs().dr4r.set_dr4r(self.index(), false);
     self.regs().dr8r.set_dr8r(self.index(), false);
 
-    // TODO(simias): configure open drain/pull up/pull down/slew rate if necessary
-
     self.regs().odr.set_odr(self.index(), false);
     self.regs().pur.set_pur(self.index(), false);
     self.regs().pdr.set_pdr(self.index(), false);
@@ -184,14 +183,13 @@ pub trait Pin {
     self.regs().pdr.set_pdr(self.index(), enabled);
   }
 
-  fn enable_interrupt(&self) {
-    nvic::enable_irq(self.irq_num() - 16);
-    self.regs().ibe.set_ibe(self.index(), reg::Port_ibe_ibe::BothEdges);
-    self.regs().im.set_ime(self.index(), reg::Port_im_ime::Enable);
-  }
-
-  fn clear_interrupt(&self) {
-    self.regs().icr.set_ic(self.index(), reg::Port_icr_ic::Clear);
+  /// Index of the pin in the interrupt handler table.
+  fn handler_slot(&self) -> usize {
+    let port = match self.irq_num() {
+      46 => 5,
+      n  => n - 16,
+    };
+    port * 8 + self.index()
   }
 }
 
@@ -229,6 +227,103 @@ impl<T: Pin> Gpio for T {
   }
 }
 
+impl<T: Pin> PinConfig for T {
+  fn set_pull(&self, pull: GpioPull) {
+    let (up, down) = match pull {
+      GpioPull::Floating => (false, false),
+      GpioPull::PullUp   => (true,  false),
+      GpioPull::PullDown => (false, true),
+    };
+    self.set_pull_up(up);
+    self.set_pull_down(down);
+  }
+
+  fn set_open_drain(&self, enabled: bool) {
+    self.regs().odr.set_odr(self.index(), enabled);
+  }
+
+  /// Selects the 2, 4 or 8mA drive. Setting one of the drive registers clears
+  /// the other two.
+  fn set_speed(&self, speed: GpioSpeed) {
+    match speed {
+      GpioSpeed::Low    => self.regs().dr2r.set_dr2r(self.index(), true),
+      GpioSpeed::Medium => self.regs().dr4r.set_dr4r(self.index(), true),
+      GpioSpeed::High   => {
+        self.regs().dr8r.set_dr8r(self.index(), true);
+        self.regs().slr.set_slr(self.index(), false);
+      },
+    };
+  }
+}
+
+/// Interrupt handlers of the pins of ports A to F.
+static mut INTERRUPT_HANDLERS: [Option<InterruptHandler>; 48] = [None; 48];
+
+impl<T: Pin> InterruptPin for T {
+  fn enable_interrupt(&self, trigger: GpioTrigger) {
+    use self::reg::Port_is_is as is;
+    use self::reg::Port_ibe_ibe as ibe;
+    use self::reg::Port_iev_iev as iev;
+
+    let (sense, both, event) = match trigger {
+      GpioTrigger::RisingEdge  => (is::Edge,  ibe::SingleEdge, iev::High),
+      GpioTrigger::FallingEdge => (is::Edge,  ibe::SingleEdge, iev::Low),
+      GpioTrigger::BothEdges   => (is::Edge,  ibe::BothEdges,  iev::Low),
+      GpioTrigger::LowLevel    => (is::Level, ibe::SingleEdge, iev::Low),
+      GpioTrigger::HighLevel   => (is::Level, ibe::SingleEdge, iev::High),
+    };
+
+    // Mask the interrupt while changing the sense, as it may trigger spurious
+    // interrupts
+    self.regs().im.set_ime(self.index(), reg::Port_im_ime::Mask);
+    self.regs().is.set_is(self.index(), sense);
+    self.regs().ibe.set_ibe(self.index(), both);
+    self.regs().iev.set_iev(self.index(), event);
+    self.regs().icr.set_ic(self.index(), reg::Port_icr_ic::Clear);
+    self.regs().im.set_ime(self.index(), reg::Port_im_ime::Enable);
+    nvic::enable_irq(self.irq_num() - 16);
+  }
+
+  fn disable_interrupt(&self) {
+    self.regs().im.set_ime(self.index(), reg::Port_im_ime::Mask);
+  }
+
+  fn is_interrupt_pending(&self) -> bool {
+    self.regs().mis.mis(self.index())
+  }
+
+  fn clear_interrupt(&self) {
+    self.regs().icr.set_ic(self.index(), reg::Port_icr_ic::Clear);
+  }
+
+  fn set_interrupt_handler(&self, handler: InterruptHandler) {
+    unsafe { INTERRUPT_HANDLERS[self.handler_slot()] = Some(handler) };
+  }
+}
+
+/// Calls the handlers of pins with pending interrupts and clears them. To be
+/// called from the GPIO port interrupt service routines.
+pub fn dispatch_interrupts() {
+  use util::support::get_reg_ref;
+
+  let ports = [reg::PORT_A, reg::PORT_B, reg::PORT_C, reg::PORT_D,
+               reg::PORT_E, reg::PORT_F];
+  for (port, regs) in ports.iter().enumerate() {
+    let regs = get_reg_ref(*regs);
+    let pending = regs.mis.get().raw();
+    for pin in 0..8 {
+      if pending & (1 << pin) == 0 {
+        continue;
+      }
+      regs.icr.set_ic(pin, reg::Port_icr_ic::Clear);
+      match unsafe { INTERRUPT_HANDLERS[port * 8 + pin] } {
+        Some(handler) => handler(),
+        None => (),
+      }
+    }
+  }
+}
+
 pub mod reg {
   //! Pin registers definition
   use volatile_cell::VolatileCell;
@@ -248,6 +343,14 @@ pub mod reg {
       }
     }
 
+    0x404 => reg32 is {
+      //! Interrupt sense
+      7..0 => is[8] {
+        0 => Edge,
+        1 => Level,
+      }
+    }
+
     0x408 => reg32 ibe {
       7..0 => ibe[8] {
         0 => SingleEdge,
@@ -255,6 +358,14 @@ pub mod reg {
       }
     }
 
+    0x40C => reg32 iev {
+      //! Interrupt event
+      7..0 => iev[8] {
+        0 => Low,
+        1 => High,
+      }
+    }
+
     0x410 => reg32 im {
       //! Interrupt mask enable
       7..0 => ime[8] {
@@ -263,6 +374,16 @@ pub mod reg {
       }
     }
 
+    0x414 => reg32 ris {
+      //! Raw interrupt status
+      7..0 => ris[8]: ro
+    }
+
+    0x418 => reg32 mis {
+      //! Masked interrupt status
+      7..0 => mis[8]: ro
+    }
+
     0x41C => reg32 icr {
       //! Interrupt clear
       7..0 => ic[8] {
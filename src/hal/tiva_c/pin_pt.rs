@@ -17,9 +17,10 @@ use std::rc::Rc;
 use syntax::ext::base::ExtCtxt;
 
 use builder::{Builder, TokenString, add_node_dependency};
+use gpio_pt;
 use node;
 
-pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
+pub fn attach(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
   node.materializer.set(Some(verify as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
   for port_node in node.subnodes().iter() {
     port_node.materializer.set(Some(verify as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
@@ -28,6 +29,7 @@ pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
       pin_node.materializer.set(Some(build_pin as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
       add_node_dependency(port_node, pin_node);
       super::add_node_dependency_on_clock(builder, pin_node);
+      gpio_pt::claim_gpio_pin(builder, cx, pin_node);
     }
   }
 }
@@ -112,4 +114,8 @@ fn build_pin(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
           $function);
   );
   builder.add_main_statement(st.unwrap());
+
+  // Each port has its own interrupt vector
+  let irq = format!("gpio_port_{}", port_path.to_lowercase());
+  gpio_pt::build_interrupt(builder, cx, &node, &port_node, irq.as_str());
 }
@@ -21,6 +21,7 @@ use node;
 
 mod clock_pt;
 mod pin_pt;
+mod spi_pt;
 mod timer_pt;
 mod uart_pt;
 
@@ -32,6 +33,7 @@ pub fn attach(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
     match sub.path.as_str() {
       "clock" => clock_pt::attach(builder, cx, sub.clone()),
       "gpio"  => pin_pt  ::attach(builder, cx, sub.clone()),
+      "spi"   => spi_pt  ::attach(builder, cx, sub.clone()),
       "timer" => timer_pt::attach(builder, cx, sub.clone()),
       "uart"  => uart_pt ::attach(builder, cx, sub.clone()),
       _       => (),
@@ -41,7 +43,7 @@ pub fn attach(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
 
 fn verify(_: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
   node.expect_no_attributes(cx);
-  node.expect_subnodes(cx, &["clock", "gpio", "timer", "uart"]);
+  node.expect_subnodes(cx, &["clock", "gpio", "spi", "timer", "uart"]);
 }
 
 pub fn add_node_dependency_on_clock(builder: &mut Builder,
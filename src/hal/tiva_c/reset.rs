@@ -0,0 +1,46 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reset cause for TI TM4C123GH6PM.
+
+use hal::reset::{ResetCause, ResetFlags};
+use hal::tiva_c::sysctl;
+use util::support::get_reg_ref;
+
+/// Returns the cause of the last reset. Lockup resets aren't reported and
+/// main oscillator failures are reported as `Unknown`.
+pub fn reset_cause() -> ResetCause {
+  let resc = get_reg_ref(sysctl::reg::SYSCTL).resc.get();
+  ResetFlags {
+    power_on: resc.por(),
+    brown_out: resc.bor(),
+    watchdog: resc.wdt0() || resc.wdt1(),
+    software: resc.sw(),
+    pin: resc.ext(),
+    lockup: false,
+  }.cause()
+}
+
+/// Clears the reset flags.
+pub fn clear_reset_cause() {
+  get_reg_ref(sysctl::reg::SYSCTL).resc
+    .set_ext(false)
+    .set_por(false)
+    .set_bor(false)
+    .set_wdt0(false)
+    .set_sw(false)
+    .set_wdt1(false)
+    .set_moscfail(false);
+}
@@ -22,8 +22,6 @@ use core::intrinsics::abort;
 use hal::tiva_c::sysctl;
 use util::support::get_reg_ref;
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
 #[path="../../util/wait_for.rs"]
 #[macro_use] mod wait_for;
 
@@ -0,0 +1,123 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Lionel Flandrin <lionel@svkt.org>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::rc::Rc;
+use syntax::ext::base::ExtCtxt;
+
+use builder::{Builder, TokenString, add_node_dependency};
+use builder::resources::Resource;
+use node;
+
+pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.materializer.set(Some(verify as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+  for spi_node in node.subnodes().iter() {
+    spi_node.materializer.set(Some(build_spi as fn(&mut Builder, &mut ExtCtxt, Rc<node::Node>)));
+    add_node_dependency(&node, spi_node);
+    super::add_node_dependency_on_clock(builder, spi_node);
+  }
+}
+
+pub fn verify(_: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
+  node.expect_no_attributes(cx);
+}
+
+fn build_spi(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
+  if !node.expect_no_subnodes(cx) {return}
+
+  if !node.expect_attributes(cx, &[("frequency", node::IntAttribute)]) {
+    return
+  }
+
+  if node.name.is_none() {
+    cx.parse_sess().span_diagnostic.span_err(node.name_span,
+        "SPI node must have a name");
+    return
+  }
+
+  let spi_index = match node.path.as_str().parse::<usize>() {
+    Ok(i @ 0...3) => i,
+    _ => {
+      cx.parse_sess().span_diagnostic.span_err(node.path_span,
+          format!("unknown SPI `{}`, allowed values: 0, 1, 2, 3",
+              node.path).as_str());
+      return
+    }
+  };
+
+  if !builder.claim_resource(cx,
+      Resource::Peripheral(format!("ssi{}", spi_index)),
+      &node, node.path_span) {
+    return
+  }
+
+  let spi_peripheral = TokenString(format!(
+      "zinc::hal::tiva_c::spi::SpiId::Spi{}", spi_index));
+  let name = TokenString(node.name.clone().unwrap());
+  let frequency = node.get_int_attr("frequency").unwrap() as u32;
+
+  node.set_type_name("zinc::hal::tiva_c::spi::Spi".to_string());
+
+  let st = quote_stmt!(&*cx,
+      let $name = zinc::hal::tiva_c::spi::Spi::new(
+          zinc::hal::tiva_c::spi::SpiConf {
+            peripheral: $spi_peripheral,
+            frequency: $frequency,
+          });
+  ).unwrap();
+  builder.add_main_statement(st);
+}
+
+#[cfg(test)]
+mod test {
+  use builder::Builder;
+  use test_helpers::{assert_equal_source, with_parsed};
+
+  #[test]
+  fn builds_spi() {
+    with_parsed("
+      spi {
+        bus@0 {
+          frequency = 4_000_000;
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_spi(&mut builder, cx, pt.get_by_name("bus").unwrap());
+      assert!(unsafe{*failed} == false);
+      assert!(builder.main_stmts().len() == 1);
+
+      assert_equal_source(&builder.main_stmts()[0],
+          "let bus = zinc::hal::tiva_c::spi::Spi::new(
+              zinc::hal::tiva_c::spi::SpiConf {
+                peripheral: zinc::hal::tiva_c::spi::SpiId::Spi0,
+                frequency: 4000000u32,
+              });");
+    });
+  }
+
+  #[test]
+  fn fails_to_build_unknown_spi() {
+    with_parsed("
+      spi {
+        bus@4 {
+          frequency = 4_000_000;
+        }
+      }", |cx, failed, pt| {
+      let mut builder = Builder::new(pt.clone(), cx);
+      super::build_spi(&mut builder, cx, pt.get_by_name("bus").unwrap());
+      assert!(unsafe{*failed} == true);
+      assert!(builder.main_stmts().len() == 0);
+    });
+  }
+}
@@ -341,6 +341,17 @@ pub mod periph {
     }
   }
 
+  pub mod watchdog {
+    //! Watchdog timers
+
+    const CLASS: u8 = 0x0 / 4;
+
+    pub const WATCHDOG_0: super::PeripheralClock =
+      super::PeripheralClock { class: CLASS, id: 0 };
+    pub const WATCHDOG_1: super::PeripheralClock =
+      super::PeripheralClock { class: CLASS, id: 1 };
+  }
+
   pub mod gpio {
     //! GPIO system control peripherals. Split into ports of 8 GPIO each.
 
@@ -468,6 +479,15 @@ pub mod reg {
       10 => vddaris:    ro,  //= VDDA Power OK event raw interrupt status
       11 => bor0ris:    ro,  //= VDD under BOR0 raw interrupt status
     }
+    0x05C => reg32 resc {
+      0  => ext,             //= External reset
+      1  => por,             //= Power-on reset
+      2  => bor,             //= Brown-out reset
+      3  => wdt0,            //= Watchdog timer 0 reset
+      4  => sw,              //= Software reset
+      5  => wdt1,            //= Watchdog timer 1 reset
+      16 => moscfail,        //= Main oscillator failure reset
+    }
     0x060 => reg32 rcc {
       0      => mosdis,      //= Main oscillator disable
       4..5   => oscsrc,      //= Oscillator source
@@ -17,8 +17,17 @@
 
 //! Timer configuration
 //! This code should support both standand and wide timers
+//! Input capture uses timer A in EdgeTime mode as channel 0. In that mode the
+//! prescaler extends the counter, so captures are in system clock ticks
+//! rather than microseconds, and `prescale` must be called again after
+//! `disable_capture` to use the timer for delays. One pulse output uses timer
+//! A in one-shot PWM mode as channel 0, on its CCP pin, also in system clock
+//! ticks.
 
-use hal::timer;
+use core::intrinsics::abort;
+use core::option::Option;
+
+use hal::timer::{self, CaptureEdge};
 use hal::cortex_m4::nvic;
 use hal::tiva_c::sysctl;
 
@@ -122,7 +131,8 @@ pub trait TivaTimer {
   }
 
   /// Configure timer registers
-  /// TODO(simias): Only Periodic and OneShot modes are implemented so far
+  /// TODO(simias): Only Periodic, OneShot and EdgeTime modes are implemented
+  /// so far
   fn configure(&self, cfg: reg::Timer_cfg_cfg, mode: Mode) {
     self.periph().ensure_enabled();
 
@@ -135,12 +145,17 @@ pub trait TivaTimer {
     self.regs().mr[0].set_pwmie(true);
     self.regs().mr[1].set_pwmie(true);
 
+    let (mr, capture) = match mode {
+      Mode::OneShot  => (reg::Timer_mr_mr::OneShot, false),
+      Mode::Periodic => (reg::Timer_mr_mr::Periodic, false),
+      Mode::EdgeTime => (reg::Timer_mr_mr::Capture, true),
+      _              => panic!("Unimplemented timer mode"),
+    };
+
     self.regs().mr[0]
-      .set_mr(match mode {
-        Mode::OneShot  => reg::Timer_mr_mr::OneShot,
-        Mode::Periodic => reg::Timer_mr_mr::Periodic,
-        _              => panic!("Unimplemented timer mode"),
-      })
+      .set_mr(mr)
+      .set_cmr(capture)
+      .set_ams(false)
       // We need to count down in order for the prescaler to work as a
       // prescaler. If we count up it becomes a timer extension (i.e. it becomes
       // the MSBs of the counter).
@@ -218,6 +233,137 @@ impl<T: TivaTimer> timer::Timer for T {
     // so we just complement the value to get an increasing counter.
     !self.regs().tav.v()
   }
+
+  fn counter_bits(&self) -> u32 {
+    // Half width timers count 16 bits plus 8 bits of prescaler
+    match self.regs().cfg.cfg() {
+      reg::Timer_cfg_cfg::HalfWidth if !self.wide() => 24,
+      _ => 32,
+    }
+  }
+}
+
+impl<T: TivaTimer> timer::OverflowTimer for T {
+  /// The counter overflows on timer A time-outs.
+  fn enable_overflow(&self) {
+    self.a_enable_timeout_interrupt();
+  }
+
+  fn disable_overflow(&self) {
+    self.regs().imr.set_tatoim(false);
+  }
+
+  fn clear_overflow(&self) {
+    self.a_clear_interrupt();
+  }
+}
+
+impl<T: TivaTimer> timer::Capture for T {
+  /// Timer A must have been configured in `Mode::EdgeTime`. Overrides the
+  /// prescale, which is used as the upper bits of the counter.
+  fn enable_capture(&self, channel: u8, edge: CaptureEdge) {
+    if channel != 0 {
+      unsafe { abort() };
+    }
+
+    self.regs().ctl.set_taen(false);
+
+    // Count over the whole range so that captures can be compared, at the
+    // system clock rate
+    self.regs().tailr.set_tailr(0xffffffff);
+    self.regs().apr.set_psr(if self.wide() { 0xffff } else { 0xff });
+
+    self.regs().ctl.set_taevent(match edge {
+      CaptureEdge::Rising  => reg::Timer_ctl_taevent::PosEdge,
+      CaptureEdge::Falling => reg::Timer_ctl_taevent::NegEdge,
+      CaptureEdge::Both    => reg::Timer_ctl_taevent::AnyEdge,
+    });
+    self.regs().icr.set_caecint(true);
+    self.regs().ctl.set_taen(true);
+  }
+
+  fn disable_capture(&self, channel: u8) {
+    if channel != 0 {
+      unsafe { abort() };
+    }
+    self.regs().ctl.set_taen(false);
+  }
+
+  fn take_capture(&self, channel: u8) -> Option<u32> {
+    if channel != 0 {
+      unsafe { abort() };
+    }
+    if self.regs().ris.caeris() {
+      self.regs().icr.set_caecint(true);
+      // The counter is complemented, see `get_counter`
+      Some(!self.regs().tar.tar())
+    } else {
+      None
+    }
+  }
+
+  /// Captures count system clock ticks, not microseconds.
+  fn measure_frequency(&self, channel: u8, timeout: u32) -> Option<u32> {
+    match self.measure_period(channel, timeout) {
+      Some(period) if period > 0 =>
+        Some(sysctl::clock::sysclk_get() as u32 / period),
+      _ => None,
+    }
+  }
+}
+
+impl<T: TivaTimer> timer::OnePulse for T {
+  /// Counts down once from `delay + width`, which must fit in 16 bits for
+  /// half width timers, and stops: the timer can't be used for delays any
+  /// more until it is configured again.
+  fn one_pulse(&self, channel: u8, delay: u32, width: u32) {
+    if channel != 0 {
+      unsafe { abort() };
+    }
+
+    self.regs().ctl.set_taen(false);
+    self.regs().mr[0]
+      .set_mr(reg::Timer_mr_mr::OneShot)
+      .set_cmr(false)
+      .set_ams(true)
+      .set_cdir(reg::Timer_mr_cdir::Down);
+    self.regs().apr.set_psr(0);
+
+    // The PWM output is asserted from the load until the match, inverted it
+    // goes high at the match, `width` ticks before the time-out, and low
+    // again when the counter stops
+    self.regs().ctl.set_tapwml(true);
+    self.regs().tailr.set_tailr(delay + width);
+    self.regs().tamatchr.set_tamr(width);
+    self.regs().ctl.set_taen(true);
+  }
+}
+
+impl<T: TivaTimer> timer::CompareTimer for T {
+  fn set_compare(&self, counter: u32) {
+    // The counter is complemented, see `get_counter`
+    let value = !counter;
+    if self.counter_bits() < 32 {
+      // Half width timers match bits 16 to 23 in the prescale match register
+      self.regs().tamatchr.set_tamr(value & 0xffff);
+      self.regs().apmr.set_psmr((value >> 16) & 0xff);
+    } else {
+      self.regs().tamatchr.set_tamr(value);
+    }
+    // The match interrupt is only raised with TAMIE set
+    self.regs().mr[0].set_mie(true);
+    self.regs().imr.set_tamim(true);
+    nvic::enable_irq(self.irq_num() - 16);
+  }
+
+  fn disable_compare(&self) {
+    self.regs().imr.set_tamim(false);
+    self.regs().mr[0].set_mie(false);
+  }
+
+  fn clear_compare(&self) {
+    self.regs().icr.set_tamcint(true);
+  }
 }
 
 
@@ -266,7 +412,7 @@ pub mod reg {
       },
       4      => rtcen,     //= RTC stall enable
       5      => taote,     //= Timer B output trigger enable
-      6      => tapwml,    //= Timer B PWM output level
+      6      => tapwml,    //= Timer A PWM output level
 
       8      => tben,      //= Timer B enable
       9      => tbstall,   //= Timer B stall enable
@@ -286,8 +432,13 @@ pub mod reg {
       1 => camim, //= Timer A capture mode match interrupt mask
       0 => tatoim, //= Timer A time-out interrupt mask
     }
+    0x1C => reg32 ris {
+      2 => caeris, //= Timer A capture mode event raw interrupt
+    }
     0x24 => reg32 icr {
       0 => tatocint, //= Timer A time-out raw interrupt
+      2 => caecint,  //= Timer A capture mode event raw interrupt
+      4 => tamcint,  //= Timer A match raw interrupt
     }
     0x28 => reg32 tailr {
       0..31 => tailr,      //= Timer A interval load
@@ -299,6 +450,13 @@ pub mod reg {
       0..15 => psr,        //= Timer A prescale value
                            //= Only 8bit for 16/32bit timers
     }
+    0x40 => reg32 apmr {
+      0..15 => psmr,       //= Timer A prescale match
+                           //= Only 8bit for 16/32bit timers
+    }
+    0x48 => reg32 tar {
+      0..31 => tar,        //= Timer A value, last capture in EdgeTime mode
+    }
     0x50 => reg32 tav {
       0..31 => v,          // Timer A counter value
     }
@@ -18,6 +18,7 @@ use syntax::ext::base::ExtCtxt;
 use regex::Regex;
 
 use builder::{Builder, TokenString, add_node_dependency};
+use builder::resources::Resource;
 use node;
 
 pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
@@ -98,6 +99,13 @@ fn build_timer(builder: &mut Builder, cx: &mut ExtCtxt, node: Rc<node::Node>) {
               "Timer"
             }, id.unwrap()));
 
+  if !builder.claim_resource(cx,
+      Resource::Peripheral(format!("timer{}{}",
+          if wide_timer {"w"} else {""}, id.unwrap())),
+      &node, node.path_span) {
+    return
+  }
+
   node.set_type_name("zinc::hal::tiva_c::timer::Timer".to_string());
 
   let st = quote_stmt!(&*cx,
@@ -23,8 +23,6 @@ use util::support::get_reg_ref;
 use drivers::chario::CharIO;
 use hal::uart;
 
-#[path="../../util/ioreg.rs"]
-#[macro_use] mod ioreg;
 #[path="../../util/wait_for.rs"]
 #[macro_use] mod wait_for;
 
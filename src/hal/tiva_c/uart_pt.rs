@@ -18,6 +18,7 @@ use syntax::ext::base::ExtCtxt;
 use regex::Regex;
 
 use builder::{Builder, TokenString, add_node_dependency};
+use builder::resources::Resource;
 use node;
 
 pub fn attach(builder: &mut Builder, _: &mut ExtCtxt, node: Rc<node::Node>) {
@@ -100,6 +101,12 @@ pub fn build_uart(builder: &mut Builder,
     s  => s.parse::<u8>().unwrap(),
   };
 
+  if !builder.claim_resource(cx,
+      Resource::Peripheral(format!("uart{}", sub.path)),
+      &sub, sub.path_span) {
+    return
+  }
+
   sub.set_type_name("zinc::hal::tiva_c::uart::Uart".to_string());
   let uart_name = TokenString(sub.name.clone().unwrap());
 
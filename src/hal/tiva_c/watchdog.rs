@@ -0,0 +1,132 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Watchdog timers.
+//!
+//! The watchdog raises an interrupt at the first time-out and resets the MCU
+//! at the second one, so the counter is loaded with half of the timeout.
+
+use hal::tiva_c::sysctl;
+use hal::watchdog;
+use util::support::get_reg_ref;
+
+#[path="../../util/wait_for.rs"]
+#[macro_use] mod wait_for;
+
+/// Frequency of the precision internal oscillator clocking watchdog 1.
+const PIOSC_HZ: u32 = 16000000;
+
+/// Watchdog timer instances.
+#[derive(Clone, Copy)]
+pub enum Wdt {
+  /// Watchdog 0, clocked by the system clock.
+  Wdt0,
+  /// Watchdog 1, clocked by the precision internal oscillator.
+  Wdt1,
+}
+
+impl Wdt {
+  fn periph(&self) -> sysctl::periph::PeripheralClock {
+    match *self {
+      Wdt::Wdt0 => sysctl::periph::watchdog::WATCHDOG_0,
+      Wdt::Wdt1 => sysctl::periph::watchdog::WATCHDOG_1,
+    }
+  }
+
+  fn regs(&self) -> &'static reg::Watchdog {
+    match *self {
+      Wdt::Wdt0 => get_reg_ref(reg::WATCHDOG_0),
+      Wdt::Wdt1 => get_reg_ref(reg::WATCHDOG_1),
+    }
+  }
+
+  fn clock_hz(&self) -> u32 {
+    match *self {
+      Wdt::Wdt0 => sysctl::clock::sysclk_get() as u32,
+      Wdt::Wdt1 => PIOSC_HZ,
+    }
+  }
+
+  /// Watchdog 1 is in another clock domain, register writes must wait for
+  /// the previous one to complete.
+  fn sync(&self) {
+    match *self {
+      Wdt::Wdt0 => (),
+      Wdt::Wdt1 => wait_for!(self.regs().ctl.wrc()),
+    }
+  }
+}
+
+impl watchdog::Watchdog for Wdt {
+  fn start(&self, timeout_ms: u32) {
+    self.periph().ensure_enabled();
+
+    let ticks = timeout_ms as u64 * self.clock_hz() as u64 / 1000 / 2;
+    self.sync();
+    self.regs().load.set_load(if ticks > 0xffffffff {
+      0xffffffff
+    } else {
+      ticks as u32
+    });
+    self.sync();
+    self.regs().ctl
+      .set_resen(true)
+      .set_inten(true);
+  }
+
+  fn feed(&self) {
+    // Clearing the interrupt reloads the counter
+    self.sync();
+    self.regs().icr.set_clear(1);
+  }
+
+  fn caused_reset(&self) -> bool {
+    let sysctl = get_reg_ref(sysctl::reg::SYSCTL);
+    match *self {
+      Wdt::Wdt0 => sysctl.resc.wdt0(),
+      Wdt::Wdt1 => sysctl.resc.wdt1(),
+    }
+  }
+}
+
+pub mod reg {
+  //! Watchdog registers definition
+  use volatile_cell::VolatileCell;
+  use core::ops::Drop;
+
+  ioregs!(Watchdog = {
+    0x000 => reg32 load {
+      0..31 => load,         //= Counter load value
+    }
+    0x004 => reg32 value {
+      0..31 => value: ro,    //= Current counter value
+    }
+    0x008 => reg32 ctl {
+      0  => inten,           //= Interrupt and counter enable, can't be cleared
+      1  => resen,           //= Reset enable
+      2  => inttype,         //= Non-maskable interrupt
+      31 => wrc: ro,         //= Write complete, watchdog 1 only
+    }
+    0x00C => reg32 icr {
+      0..31 => clear: wo,    //= Any write clears the interrupt and reloads
+    }
+    0x010 => reg32 ris {
+      0 => wdtris: ro,       //= Raw interrupt status
+    }
+  });
+
+  pub const WATCHDOG_0: *const Watchdog = 0x40000000 as *const Watchdog;
+  pub const WATCHDOG_1: *const Watchdog = 0x40001000 as *const Watchdog;
+}
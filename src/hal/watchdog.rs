@@ -0,0 +1,188 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/*!
+Watchdog interface.
+
+A watchdog resets the MCU unless it is fed regularly. `Supervisor` feeds a
+watchdog only when every registered task has checked in since the last feed,
+so that a single stuck task resets the MCU.
+*/
+
+use core::option::Option;
+
+use util::shared::{NoInterrupts, Shared};
+
+/// Watchdog timer.
+pub trait Watchdog {
+  /// Starts the watchdog, the MCU resets unless it is fed within
+  /// `timeout_ms`. Most watchdogs can't be stopped once started.
+  fn start(&self, timeout_ms: u32);
+
+  /// Feeds the watchdog, restarting the timeout.
+  fn feed(&self);
+
+  /// Returns true if the last reset was caused by this watchdog.
+  fn caused_reset(&self) -> bool;
+}
+
+/// Watchdog which also resets the MCU when fed too early.
+pub trait WindowedWatchdog: Watchdog {
+  /// Rejects feeding until `min_ms` have passed since the last feed.
+  fn set_window(&self, min_ms: u32);
+}
+
+/// Identifies a task registered with a `Supervisor`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TaskId(u32);
+
+struct SupervisorState {
+  registered: u32,
+  checked_in: u32,
+}
+
+/// Feeds a watchdog when all registered tasks have checked in. Up to 32 tasks
+/// can be registered.
+pub struct Supervisor<'a, W: 'a> {
+  watchdog: &'a W,
+  state: Shared<SupervisorState>,
+}
+
+impl<'a, W: Watchdog> Supervisor<'a, W> {
+  /// Creates a supervisor of an already started `watchdog`.
+  pub fn new(watchdog: &'a W) -> Supervisor<'a, W> {
+    Supervisor {
+      watchdog: watchdog,
+      state: Shared::new(SupervisorState {
+        registered: 0,
+        checked_in: 0,
+      }),
+    }
+  }
+
+  /// Registers a task, which must then call `check_in` regularly. Returns
+  /// None if 32 tasks are already registered.
+  pub fn register(&self) -> Option<TaskId> {
+    let crit = NoInterrupts::new();
+    let mut state = self.state.borrow(&crit);
+    match (0..32).find(|&i| state.registered & (1 << i) == 0) {
+      Some(i) => {
+        state.registered |= 1 << i;
+        Some(TaskId(i))
+      },
+      None => None,
+    }
+  }
+
+  /// Stops supervising a task.
+  pub fn unregister(&self, task: TaskId) {
+    let crit = NoInterrupts::new();
+    let mut state = self.state.borrow(&crit);
+    state.registered &= !(1 << task.0);
+    state.checked_in &= !(1 << task.0);
+  }
+
+  /// Reports that `task` is alive.
+  pub fn check_in(&self, task: TaskId) {
+    let crit = NoInterrupts::new();
+    let mut state = self.state.borrow(&crit);
+    state.checked_in |= 1 << task.0;
+  }
+
+  /// Feeds the watchdog if all tasks have checked in since the last feed and
+  /// returns true if it did. To be called more often than the watchdog
+  /// timeout, e.g. from a periodic alarm.
+  pub fn poll(&self) -> bool {
+    let fed = {
+      let crit = NoInterrupts::new();
+      let mut state = self.state.borrow(&crit);
+      if state.checked_in & state.registered == state.registered {
+        state.checked_in = 0;
+        true
+      } else {
+        false
+      }
+    };
+    if fed {
+      self.watchdog.feed();
+    }
+    fed
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use core::cell::Cell;
+  use super::{Supervisor, Watchdog};
+  use expectest::prelude::*;
+
+  struct CountingWatchdog {
+    feeds: Cell<u32>,
+  }
+
+  impl Watchdog for CountingWatchdog {
+    fn start(&self, _: u32) {
+    }
+
+    fn feed(&self) {
+      self.feeds.set(self.feeds.get() + 1);
+    }
+
+    fn caused_reset(&self) -> bool {
+      false
+    }
+  }
+
+  #[test]
+  fn feeds_when_all_tasks_checked_in() {
+    let watchdog = CountingWatchdog { feeds: Cell::new(0) };
+    let supervisor = Supervisor::new(&watchdog);
+    let first = supervisor.register().unwrap();
+    let second = supervisor.register().unwrap();
+
+    supervisor.check_in(first);
+    expect!(supervisor.poll()).to(be_equal_to(false));
+
+    supervisor.check_in(second);
+    expect!(supervisor.poll()).to(be_true());
+    expect!(watchdog.feeds.get()).to(be_equal_to(1));
+
+    // Check-ins are consumed by the feed
+    expect!(supervisor.poll()).to(be_equal_to(false));
+    expect!(watchdog.feeds.get()).to(be_equal_to(1));
+  }
+
+  #[test]
+  fn ignores_unregistered_tasks() {
+    let watchdog = CountingWatchdog { feeds: Cell::new(0) };
+    let supervisor = Supervisor::new(&watchdog);
+    let first = supervisor.register().unwrap();
+    let stuck = supervisor.register().unwrap();
+
+    supervisor.check_in(first);
+    supervisor.unregister(stuck);
+    expect!(supervisor.poll()).to(be_true());
+  }
+
+  #[test]
+  fn registers_up_to_32_tasks() {
+    let watchdog = CountingWatchdog { feeds: Cell::new(0) };
+    let supervisor = Supervisor::new(&watchdog);
+    for _ in 0..32 {
+      expect!(supervisor.register().is_some()).to(be_true());
+    }
+    expect!(supervisor.register().is_none()).to(be_true());
+  }
+}
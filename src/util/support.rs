@@ -70,6 +70,18 @@ pub fn wfi() {
 pub fn wfi() {
 }
 
+#[cfg(target_arch = "arm")]
+#[inline(always)]
+/// DSB instruction
+pub fn dsb() {
+    unsafe { asm!("dsb" :::: "volatile"); }
+}
+
+#[cfg(not(target_arch = "arm"))]
+/// DSB instruction (mock)
+pub fn dsb() {
+}
+
 /// Hack to get a static 'ioreg' reference from a raw pointer to the register
 /// base
 pub fn get_reg_ref<T>(t: *const T) -> &'static T {
@@ -19,12 +19,17 @@ if [ "$PLATFORM" == "native" ]; then
   cargo test --features test --lib --verbose
   echo " * building ioreg"
   (cd ./ioreg; cargo build --verbose; cargo test --verbose)
+  python support/regdecode_test.py
+  echo " * building volatile_cell"
+  (cd ./volatile_cell; cargo test --features replayer --verbose)
   echo " * building platformtree"
   (cd ./platformtree; cargo build --verbose; cargo test --verbose)
   echo " * building platformtree macro"
   (cd ./macro_platformtree; cargo build --verbose; cargo test --verbose)
   echo " * building zinc macro"
   (cd ./macro_zinc; cargo test --verbose)
+  echo " * building svd2ioregs"
+  (cd ./svd2ioregs; cargo test --verbose)
 
   echo " * generating coverage data"
   if [ "$TRAVIS_JOB_ID" != "" ]; then
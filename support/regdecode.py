@@ -0,0 +1,54 @@
+#!/usr/bin/env python
+
+# Decodes a raw register value using the description exported by ioregs!
+# when building with IOREGS_EXPORT_DIR set.
+#
+# Usage: regdecode.py app.uart.UART.json cr 0x1a01
+#        regdecode.py app.timer.TIMER.json channel.cr 0x3
+
+import json, sys
+
+def find_register(block, path):
+  reg = block
+  for name in path.split('.'):
+    regs = [r for r in reg.get('registers', []) if r['name'] == name]
+    if not regs:
+      raise RuntimeError('no register "{}" in "{}"'.format(name, reg['name']))
+    reg = regs[0]
+  if 'fields' not in reg:
+    raise RuntimeError('"{}" is a register group'.format(path))
+  return reg
+
+def decode_value(field, value):
+  if field['type'] == 'enum':
+    for variant in field['variants']:
+      if variant['value'] == value:
+        return variant['name']
+    return '<unknown {}>'.format(value)
+  return str(value)
+
+def decode(reg, raw):
+  parts = []
+  for field in reg['fields']:
+    if field['access'] == 'wo':
+      continue
+    mask = (1 << field['width']) - 1
+    for i in range(field['count']):
+      value = (raw >> (field['low_bit'] + i * field['width'])) & mask
+      name = field['name']
+      if field['count'] > 1:
+        name = '{}[{}]'.format(name, i)
+      parts.append('{}={}'.format(name, decode_value(field, value)))
+  return ' '.join(parts)
+
+def main():
+  if len(sys.argv) != 4:
+    print('usage: {} BLOCK.json REGISTER VALUE'.format(sys.argv[0]))
+    sys.exit(2)
+  with open(sys.argv[1]) as f:
+    block = json.load(f)
+  reg = find_register(block, sys.argv[2])
+  print(decode(reg, int(sys.argv[3], 0)))
+
+if __name__ == '__main__':
+  main()
@@ -0,0 +1,47 @@
+#!/usr/bin/env python
+
+# Tests for regdecode.py, run with `python support/regdecode_test.py`.
+
+import unittest
+
+import regdecode
+
+BLOCK = {
+  'name': 'UART', 'offset': 0, 'count': 1,
+  'registers': [{
+    'name': 'cr', 'offset': 4, 'count': 1, 'width': 16,
+    'fields': [
+      {'name': 'enable', 'low_bit': 0, 'width': 1, 'count': 1,
+       'access': 'rw', 'type': 'bool'},
+      {'name': 'mode', 'low_bit': 4, 'width': 2, 'count': 2,
+       'access': 'rw', 'type': 'uint'},
+      {'name': 'go', 'low_bit': 12, 'width': 1, 'count': 1,
+       'access': 'wo', 'type': 'bool'},
+      {'name': 'speed', 'low_bit': 8, 'width': 2, 'count': 1,
+       'access': 'ro', 'type': 'enum',
+       'variants': [{'name': 'Slow', 'value': 0},
+                    {'name': 'Fast', 'value': 1}]},
+    ],
+  }, {
+    'name': 'channel', 'offset': 8, 'count': 2,
+    'registers': [],
+  }],
+}
+
+class RegDecodeTest(unittest.TestCase):
+  def test_decodes_fields_arrays_and_enums(self):
+    reg = regdecode.find_register(BLOCK, 'cr')
+    self.assertEqual(regdecode.decode(reg, 0x1171),
+        'enable=1 mode[0]=3 mode[1]=1 speed=Fast')
+
+  def test_reports_unknown_enum_values(self):
+    reg = regdecode.find_register(BLOCK, 'cr')
+    self.assertEqual(regdecode.decode(reg, 0x300),
+        'enable=0 mode[0]=0 mode[1]=0 speed=<unknown 3>')
+
+  def test_rejects_unknown_registers_and_groups(self):
+    self.assertRaises(RuntimeError, regdecode.find_register, BLOCK, 'sr')
+    self.assertRaises(RuntimeError, regdecode.find_register, BLOCK, 'channel')
+
+if __name__ == '__main__':
+  unittest.main()
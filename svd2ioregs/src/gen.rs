@@ -0,0 +1,245 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2015 zinc developers <http://zinc.rs>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `ioregs!` code generation.
+
+use svd::{Access, Cluster, Device, Field, Peripheral, Register, RegisterItem};
+
+static HEADER: &'static str = "\
+// Zinc, the bare metal stack for rust.
+// Copyright 2015 zinc developers <http://zinc.rs>
+//
+// Licensed under the Apache License, Version 2.0 (the \"License\");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an \"AS IS\" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// THIS FILE IS AUTOMATICALLY GENERATED. DO NOT MODIFY IT DIRECTLY, UPDATE THE
+// SVD DEFINITION IN SUPPORT/SVD/DATA AND RE-GENERATE, ADDING THE CHANGES MADE
+// INTO THE RELEVANT CHANGELOG ENTRY.
+";
+
+/// Emits a standalone module source with `ioregs!` blocks for all the
+/// peripherals of the device.
+pub fn emit_module(device: &Device, source: &str) -> String {
+  let mut out = String::from(HEADER);
+  out.push_str(format!("
+//! ioregs definition based on {}
+
+use volatile_cell::VolatileCell;
+use core::ops::Drop;
+
+", source).as_str());
+  out.push_str(emit_blocks(device).as_str());
+  out
+}
+
+/// Emits `ioregs!` blocks for all the peripherals of the device.
+///
+/// The output can be `include!`d into a module that imports `VolatileCell`
+/// and `Drop`.
+pub fn emit_blocks(device: &Device) -> String {
+  let mut out = String::new();
+  for peripheral in device.peripherals.iter() {
+    emit_peripheral(&mut out, peripheral);
+  }
+  out
+}
+
+fn emit_peripheral(out: &mut String, peripheral: &Peripheral) {
+  out.push_str(format!("ioregs! ({} @ {} = {{{}\n", peripheral.name,
+      hex(peripheral.base_address),
+      doc("//!", &peripheral.description)).as_str());
+  emit_registers(out, &peripheral.registers, 1);
+  out.push_str("});\n");
+}
+
+fn emit_registers(out: &mut String, items: &[RegisterItem], depth: usize) {
+  for item in items.iter() {
+    match *item {
+      RegisterItem::Register(ref register) =>
+          emit_register(out, register, depth),
+      RegisterItem::Cluster(ref cluster) => emit_cluster(out, cluster, depth),
+    }
+  }
+}
+
+fn emit_cluster(out: &mut String, cluster: &Cluster, depth: usize) {
+  let indent = indent(depth);
+  out.push_str(format!("{}{} => group {}{} {{{}\n", indent, hex(cluster.offset),
+      cluster.name, count(cluster.count),
+      doc("//!", &cluster.description)).as_str());
+  emit_registers(out, &cluster.registers, depth + 1);
+  out.push_str(format!("{}}},\n", indent).as_str());
+}
+
+fn emit_register(out: &mut String, register: &Register, depth: usize) {
+  let indent = indent(depth);
+  let reset = match register.reset_value {
+    Some(value) => format!(" = {:#x}", value),
+    None => String::new(),
+  };
+  out.push_str(format!("{}{} => reg{} {}{}{} {{{}\n", indent,
+      hex(register.offset), register.size, register.name,
+      count(register.count), reset,
+      doc("//!", &register.description)).as_str());
+  for field in register.fields.iter() {
+    emit_field(out, field, depth + 1);
+  }
+  out.push_str(format!("{}}},\n", indent).as_str());
+}
+
+fn emit_field(out: &mut String, field: &Field, depth: usize) {
+  let indent = indent(depth);
+  let bits = if field.low_bit == field.high_bit {
+    format!("{}", field.low_bit)
+  } else {
+    format!("{}..{}", field.low_bit, field.high_bit)
+  };
+  let access = match field.access {
+    Access::ReadWrite => "",
+    Access::ReadOnly => ": ro",
+    Access::WriteOnly => ": wo",
+    Access::SetToClear => ": set_to_clear",
+    Access::ZeroToClear => ": zero_to_clear",
+    Access::ReadToClear => ": read_to_clear",
+  };
+
+  if field.values.is_empty() {
+    out.push_str(format!("{}{} => {}{},{}\n", indent, bits, field.name, access,
+        doc("//=", &field.description)).as_str());
+  } else {
+    out.push_str(format!("{}{} => {}{} {{{}\n", indent, bits, field.name,
+        access, doc("//!", &field.description)).as_str());
+    for value in field.values.iter() {
+      out.push_str(format!("{}  {} => {},{}\n", indent, value.value, value.name,
+          doc("//=", &value.description)).as_str());
+    }
+    out.push_str(format!("{}}}\n", indent).as_str());
+  }
+}
+
+fn indent(depth: usize) -> String {
+  (0..depth).map(|_| "  ").collect()
+}
+
+fn hex(value: u64) -> String {
+  format!("0x{:02x}", value)
+}
+
+fn count(count: u32) -> String {
+  if count > 1 {
+    format!("[{}]", count)
+  } else {
+    String::new()
+  }
+}
+
+fn doc(kind: &str, text: &str) -> String {
+  if text.is_empty() {
+    String::new()
+  } else {
+    format!("  {} {}", kind, text)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use svd::{Access, Cluster, Device, EnumValue, Field, Peripheral, Register,
+      RegisterItem};
+  use super::emit_blocks;
+
+  fn field(name: &str, low_bit: u32, high_bit: u32, access: Access) -> Field {
+    Field {
+      name: name.to_string(),
+      description: String::new(),
+      low_bit: low_bit,
+      high_bit: high_bit,
+      access: access,
+      values: vec!(),
+    }
+  }
+
+  #[test]
+  fn emits_ioregs_blocks() {
+    let mut mode = field("mode", 8, 9, Access::ReadWrite);
+    mode.values.push(EnumValue {
+      name: "OFF".to_string(),
+      description: "Disabled".to_string(),
+      value: 0,
+    });
+    let cr = Register {
+      name: "cr".to_string(),
+      description: "Control".to_string(),
+      offset: 0,
+      size: 32,
+      count: 1,
+      reset_value: Some(0x100),
+      fields: vec!(field("en", 0, 0, Access::ReadWrite),
+          field("status", 4, 7, Access::ReadOnly), mode),
+    };
+    let data = Register {
+      name: "data".to_string(),
+      description: String::new(),
+      offset: 0x10,
+      size: 8,
+      count: 4,
+      reset_value: None,
+      fields: vec!(field("d", 0, 7, Access::WriteOnly)),
+    };
+    let device = Device {
+      name: "TEST".to_string(),
+      peripherals: vec!(Peripheral {
+        name: "UART".to_string(),
+        description: "UART".to_string(),
+        base_address: 0x40000000,
+        registers: vec!(
+          RegisterItem::Register(cr.clone()),
+          RegisterItem::Cluster(Cluster {
+            name: "ch".to_string(),
+            description: String::new(),
+            offset: 0x20,
+            count: 2,
+            registers: vec!(RegisterItem::Register(data)),
+          }),
+        ),
+      }),
+    };
+
+    assert_eq!(emit_blocks(&device), "\
+ioregs! (UART @ 0x40000000 = {  //! UART
+  0x00 => reg32 cr = 0x100 {  //! Control
+    0 => en,
+    4..7 => status: ro,
+    8..9 => mode {
+      0 => OFF,  //= Disabled
+    }
+  },
+  0x20 => group ch[2] {
+    0x10 => reg8 data[4] {
+      0..7 => d: wo,
+    },
+  },
+});
+");
+  }
+}
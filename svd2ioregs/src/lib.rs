@@ -0,0 +1,107 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2015 zinc developers <http://zinc.rs>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CMSIS-SVD to `ioregs!` generator.
+//!
+//! Parses a CMSIS-SVD device description (including `derivedFrom`, `dim`
+//! lists and arrays, clusters and enumerated values) and emits `ioregs!`
+//! blocks for all of its peripherals. It can be used from `build.rs`:
+//!
+//! ```ignore
+//! extern crate svd2ioregs;
+//!
+//! fn main() {
+//!   let out = Path::new(&env::var("OUT_DIR").unwrap()).join("regs.rs");
+//!   svd2ioregs::generate("support/svd/data/NXP/LPC11xx-v6-z0.xml", out)
+//!       .unwrap();
+//! }
+//! ```
+//!
+//! and the output included next to the required imports:
+//!
+//! ```ignore
+//! use volatile_cell::VolatileCell;
+//! use core::ops::Drop;
+//!
+//! include!(concat!(env!("OUT_DIR"), "/regs.rs"));
+//! ```
+
+use std::error;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::io::{Read, Write};
+use std::path::Path;
+
+pub mod gen;
+pub mod svd;
+pub mod xml;
+
+#[derive(Debug)]
+pub enum Error {
+  Io(io::Error),
+  /// Malformed XML.
+  Xml(String),
+  /// Valid XML that is not a supported SVD document.
+  Svd(String),
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      Error::Io(ref e) => write!(f, "{}", e),
+      Error::Xml(ref e) => write!(f, "malformed XML: {}", e),
+      Error::Svd(ref e) => write!(f, "bad SVD: {}", e),
+    }
+  }
+}
+
+impl error::Error for Error {
+  fn description(&self) -> &str {
+    match *self {
+      Error::Io(ref e) => e.description(),
+      Error::Xml(ref e) => e.as_str(),
+      Error::Svd(ref e) => e.as_str(),
+    }
+  }
+}
+
+impl From<io::Error> for Error {
+  fn from(e: io::Error) -> Error {
+    Error::Io(e)
+  }
+}
+
+/// Parses an SVD document.
+pub fn parse(src: &str) -> Result<svd::Device, Error> {
+  let root = try!(xml::parse(src).map_err(Error::Xml));
+  svd::parse_device(&root)
+}
+
+/// Reads and parses an SVD file.
+pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<svd::Device, Error> {
+  let mut src = String::new();
+  try!(try!(File::open(path)).read_to_string(&mut src));
+  parse(src.as_str())
+}
+
+/// Generates `ioregs!` blocks from the SVD file, see `gen::emit_blocks`.
+pub fn generate<P: AsRef<Path>, Q: AsRef<Path>>(svd: P, out: Q)
+    -> Result<(), Error> {
+  let device = try!(parse_file(svd));
+  let mut file = try!(File::create(out));
+  try!(file.write_all(gen::emit_blocks(&device).as_bytes()));
+  Ok(())
+}
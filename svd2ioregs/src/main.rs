@@ -0,0 +1,40 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2015 zinc developers <http://zinc.rs>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Prints a register module generated from an SVD file.
+//!
+//! Usage: `svd2ioregs support/svd/data/NXP/LPC11xx-v6-z0.xml > regs.rs`
+
+extern crate svd2ioregs;
+
+use std::env;
+use std::io::Write;
+use std::process;
+
+fn main() {
+  let args: Vec<String> = env::args().collect();
+  if args.len() != 2 {
+    println!("usage: {} <file.svd>", args[0]);
+    process::exit(2);
+  }
+
+  match svd2ioregs::parse_file(&args[1]) {
+    Ok(device) => print!("{}", svd2ioregs::gen::emit_module(&device, &args[1])),
+    Err(e) => {
+      writeln!(&mut std::io::stderr(), "{}: {}", args[1], e).unwrap();
+      process::exit(1);
+    },
+  }
+}
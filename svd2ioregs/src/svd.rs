@@ -0,0 +1,743 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2015 zinc developers <http://zinc.rs>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! CMSIS-SVD device model.
+//!
+//! `derivedFrom` attributes are resolved and `dim` lists of registers and
+//! clusters are expanded while parsing, so the model only contains concrete
+//! registers and register arrays supported by `ioregs!`.
+
+use std::collections::HashMap;
+
+use xml::Element;
+use Error;
+
+/// Field access type.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Access {
+  ReadWrite,
+  ReadOnly,
+  WriteOnly,
+  /// Cleared by writing one (`modifiedWriteValues` of `oneToClear`).
+  SetToClear,
+  /// Cleared by writing zero (`modifiedWriteValues` of `zeroToClear`).
+  ZeroToClear,
+  /// Cleared by reading (`readAction` of `clear`).
+  ReadToClear,
+}
+
+impl Access {
+  fn from_svd(access: &str) -> Result<Access, Error> {
+    match access {
+      "read-write" | "read-writeOnce" => Ok(Access::ReadWrite),
+      "read-only" => Ok(Access::ReadOnly),
+      "write-only" | "writeOnce" => Ok(Access::WriteOnly),
+      other => Err(Error::Svd(format!("unknown access type `{}`", other))),
+    }
+  }
+
+  /// Refines the access type with the field's side effects, if any.
+  fn with_side_effects(self, elem: &Element) -> Access {
+    match (elem.child_text("modifiedWriteValues"),
+        elem.child_text("readAction")) {
+      (Some(ref w), _) if w.as_str() == "oneToClear" => Access::SetToClear,
+      (Some(ref w), _) if w.as_str() == "zeroToClear" => Access::ZeroToClear,
+      (_, Some(ref r)) if r.as_str() == "clear" => Access::ReadToClear,
+      _ => self,
+    }
+  }
+}
+
+#[derive(Clone, Debug)]
+pub struct Device {
+  pub name: String,
+  pub peripherals: Vec<Peripheral>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Peripheral {
+  pub name: String,
+  pub description: String,
+  pub base_address: u64,
+  pub registers: Vec<RegisterItem>,
+}
+
+/// An entry of a register block.
+#[derive(Clone, Debug)]
+pub enum RegisterItem {
+  Register(Register),
+  Cluster(Cluster),
+}
+
+#[derive(Clone, Debug)]
+pub struct Register {
+  pub name: String,
+  pub description: String,
+  /// Offset from the start of the enclosing peripheral or cluster.
+  pub offset: u64,
+  /// Register width in bits.
+  pub size: u32,
+  /// Number of consecutive registers for register arrays, 1 otherwise.
+  pub count: u32,
+  /// Value after reset, if the SVD gives one.
+  pub reset_value: Option<u64>,
+  pub fields: Vec<Field>,
+}
+
+/// A group of registers, possibly replicated `count` times.
+#[derive(Clone, Debug)]
+pub struct Cluster {
+  pub name: String,
+  pub description: String,
+  pub offset: u64,
+  pub count: u32,
+  pub registers: Vec<RegisterItem>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Field {
+  pub name: String,
+  pub description: String,
+  pub low_bit: u32,
+  pub high_bit: u32,
+  pub access: Access,
+  pub values: Vec<EnumValue>,
+}
+
+#[derive(Clone, Debug)]
+pub struct EnumValue {
+  pub name: String,
+  pub description: String,
+  pub value: u64,
+}
+
+/// Register properties inherited from the enclosing element.
+#[derive(Clone, Copy)]
+struct Defaults {
+  size: u32,
+  access: Access,
+  reset_value: Option<u64>,
+}
+
+impl Defaults {
+  fn inherit(&self, elem: &Element) -> Result<Defaults, Error> {
+    Ok(Defaults {
+      size: match elem.child_text("size") {
+        Some(size) => try!(parse_int(size.as_str())) as u32,
+        None => self.size,
+      },
+      access: match elem.child_text("access") {
+        Some(access) => try!(Access::from_svd(access.as_str())),
+        None => self.access,
+      },
+      reset_value: match elem.child_text("resetValue") {
+        Some(value) => Some(try!(parse_int(value.as_str()))),
+        None => self.reset_value,
+      },
+    })
+  }
+}
+
+/// Parses the SVD document.
+pub fn parse_device(root: &Element) -> Result<Device, Error> {
+  let defaults = try!(Defaults {
+    size: 32,
+    access: Access::ReadWrite,
+    reset_value: None,
+  }
+      .inherit(root));
+  let mut enums = HashMap::new();
+  let mut peripherals: Vec<Peripheral> = vec!();
+
+  let elems = match root.child("peripherals") {
+    Some(elem) => elem.children_named("peripheral"),
+    None => vec!(),
+  };
+  for elem in elems.iter() {
+    let parent = match elem.attribute("derivedFrom") {
+      Some(name) => match peripherals.iter().find(|p| p.name == name) {
+        Some(parent) => Some(parent.clone()),
+        None => return Err(Error::Svd(format!(
+            "peripheral derived from unknown `{}`", name))),
+      },
+      None => None,
+    };
+
+    let name = match (elem.child_text("name"), &parent) {
+      (Some(name), _) => name,
+      (None, &Some(ref parent)) => parent.name.clone(),
+      (None, &None) => return Err(Error::Svd("peripheral without a name".to_string())),
+    };
+    let description = match (elem.child_text("description"), &parent) {
+      (Some(description), _) => description,
+      (None, &Some(ref parent)) => parent.description.clone(),
+      (None, &None) => String::new(),
+    };
+    let base_address = match (elem.child_text("baseAddress"), &parent) {
+      (Some(address), _) => try!(parse_int(address.as_str())),
+      (None, &Some(ref parent)) => parent.base_address,
+      (None, &None) => return Err(Error::Svd(format!(
+          "peripheral `{}` has no base address", name))),
+    };
+    let registers = match (elem.child("registers"), &parent) {
+      (Some(registers), _) => try!(parse_registers(registers,
+          try!(defaults.inherit(elem)), &mut enums)),
+      (None, &Some(ref parent)) => parent.registers.clone(),
+      (None, &None) => vec!(),
+    };
+
+    peripherals.push(Peripheral {
+      name: name,
+      description: description,
+      base_address: base_address,
+      registers: registers,
+    });
+  }
+
+  Ok(Device {
+    name: root.child_text("name").unwrap_or(String::new()),
+    peripherals: peripherals,
+  })
+}
+
+fn parse_registers(elem: &Element, defaults: Defaults,
+    enums: &mut HashMap<String, Vec<EnumValue>>)
+    -> Result<Vec<RegisterItem>, Error> {
+  let mut items = vec!();
+  // Registers by their SVD name, for `derivedFrom`.
+  let mut by_name: HashMap<String, Register> = HashMap::new();
+
+  for child in elem.children.iter() {
+    match child.name.as_str() {
+      "register" => {
+        let parent = match child.attribute("derivedFrom") {
+          Some(name) => match by_name.get(name) {
+            Some(parent) => Some(parent.clone()),
+            None => return Err(Error::Svd(format!(
+                "register derived from unknown `{}`", name))),
+          },
+          None => None,
+        };
+        let register = try!(parse_register(child, parent, defaults, enums));
+        match child.child_text("name") {
+          Some(name) => { by_name.insert(name, register.clone()); },
+          None => (),
+        }
+        for (name, offset, count) in try!(expand_dim(child, &register.name,
+            register.offset, register.size as u64 / 8)) {
+          items.push(RegisterItem::Register(Register {
+            name: sanitize_ident(name.as_str(), "r_"),
+            offset: offset,
+            count: count,
+            ..register.clone()
+          }));
+        }
+      },
+      "cluster" => {
+        let cluster = try!(parse_cluster(child, defaults, enums));
+        let size = cluster_size(&cluster.registers);
+        for (name, offset, count) in try!(expand_dim(child, &cluster.name,
+            cluster.offset, size)) {
+          items.push(RegisterItem::Cluster(Cluster {
+            name: sanitize_ident(name.as_str(), "r_"),
+            offset: offset,
+            count: count,
+            ..cluster.clone()
+          }));
+        }
+      },
+      _ => (),
+    }
+  }
+  Ok(items)
+}
+
+fn parse_register(elem: &Element, parent: Option<Register>, defaults: Defaults,
+    enums: &mut HashMap<String, Vec<EnumValue>>) -> Result<Register, Error> {
+  let defaults = try!(defaults.inherit(elem));
+  let name = match (elem.child_text("name"), &parent) {
+    (Some(name), _) => name.to_lowercase(),
+    (None, &Some(ref parent)) => parent.name.clone(),
+    (None, &None) => return Err(Error::Svd("register without a name".to_string())),
+  };
+  let description = match (elem.child_text("description"), &parent) {
+    (Some(description), _) => description,
+    (None, &Some(ref parent)) => parent.description.clone(),
+    (None, &None) => String::new(),
+  };
+  let offset = match (elem.child_text("addressOffset"), &parent) {
+    (Some(offset), _) => try!(parse_int(offset.as_str())),
+    (None, &Some(ref parent)) => parent.offset,
+    (None, &None) => return Err(Error::Svd(format!(
+        "register `{}` has no address offset", name))),
+  };
+  let size = match (elem.child("size"), &parent) {
+    (None, &Some(ref parent)) => parent.size,
+    _ => defaults.size,
+  };
+  if size != 8 && size != 16 && size != 32 {
+    return Err(Error::Svd(format!(
+        "register `{}` has unsupported size {}", name, size)));
+  }
+  let reset_value = match (elem.child("resetValue"), &parent) {
+    (None, &Some(ref parent)) => parent.reset_value,
+    _ => defaults.reset_value,
+  }.map(|value| value & ((1u64 << size) - 1));
+  let fields = match (elem.child("fields"), &parent) {
+    (Some(fields), _) => try!(parse_fields(fields, defaults.access, enums)),
+    (None, &Some(ref parent)) => parent.fields.clone(),
+    (None, &None) => vec!(),
+  };
+
+  Ok(Register {
+    name: name,
+    description: description,
+    offset: offset,
+    size: size,
+    count: 1,
+    reset_value: reset_value,
+    fields: fields,
+  })
+}
+
+fn parse_cluster(elem: &Element, defaults: Defaults,
+    enums: &mut HashMap<String, Vec<EnumValue>>) -> Result<Cluster, Error> {
+  let defaults = try!(defaults.inherit(elem));
+  let name = match elem.child_text("name") {
+    Some(name) => name.to_lowercase(),
+    None => return Err(Error::Svd("cluster without a name".to_string())),
+  };
+  let offset = match elem.child_text("addressOffset") {
+    Some(offset) => try!(parse_int(offset.as_str())),
+    None => return Err(Error::Svd(format!(
+        "cluster `{}` has no address offset", name))),
+  };
+
+  Ok(Cluster {
+    name: name,
+    description: elem.child_text("description").unwrap_or(String::new()),
+    offset: offset,
+    count: 1,
+    registers: try!(parse_registers(elem, defaults, enums)),
+  })
+}
+
+fn parse_fields(elem: &Element, access: Access,
+    enums: &mut HashMap<String, Vec<EnumValue>>) -> Result<Vec<Field>, Error> {
+  let mut fields: Vec<Field> = vec!();
+  for child in elem.children_named("field").iter() {
+    let parent = match child.attribute("derivedFrom") {
+      Some(name) => {
+        let name = name.to_lowercase();
+        match fields.iter().find(|f| f.name == name) {
+          Some(parent) => Some(parent.clone()),
+          None => return Err(Error::Svd(format!(
+              "field derived from unknown `{}`", name))),
+        }
+      },
+      None => None,
+    };
+
+    let name = match (child.child_text("name"), &parent) {
+      (Some(name), _) => name.to_lowercase(),
+      (None, &Some(ref parent)) => parent.name.clone(),
+      (None, &None) => return Err(Error::Svd("field without a name".to_string())),
+    };
+    if name == "reserved" {
+      continue;
+    }
+    let (low_bit, high_bit) = match (try!(parse_bit_range(child)), &parent) {
+      (Some(range), _) => range,
+      (None, &Some(ref parent)) => (parent.low_bit, parent.high_bit),
+      (None, &None) => return Err(Error::Svd(format!(
+          "field `{}` has no bit range", name))),
+    };
+    let access = match (child.child_text("access"), &parent) {
+      (Some(access), _) => try!(Access::from_svd(access.as_str())),
+      (None, &Some(ref parent)) => parent.access,
+      (None, &None) => access,
+    }.with_side_effects(child);
+    let values = match (child.child("enumeratedValues"), &parent) {
+      (Some(values), _) => try!(parse_enumerated_values(values, enums)),
+      (None, &Some(ref parent)) => parent.values.clone(),
+      (None, &None) => vec!(),
+    };
+
+    fields.push(Field {
+      name: sanitize_ident(name.as_str(), "f_"),
+      description: child.child_text("description")
+          .or(parent.map(|p| p.description))
+          .unwrap_or(String::new()),
+      low_bit: low_bit,
+      high_bit: high_bit,
+      access: access,
+      values: values,
+    });
+  }
+  Ok(fields)
+}
+
+/// Returns (lsb, msb) from any of the three SVD bit range notations.
+fn parse_bit_range(elem: &Element) -> Result<Option<(u32, u32)>, Error> {
+  match (elem.child_text("bitRange"), elem.child_text("bitOffset"),
+      elem.child_text("lsb")) {
+    (Some(range), _, _) => {
+      let bits: Vec<&str> = range.trim_matches(|c: char| c == '[' || c == ']')
+          .split(':').collect();
+      if bits.len() != 2 {
+        return Err(Error::Svd(format!("invalid bit range `{}`", range)));
+      }
+      let msb = try!(parse_int(bits[0])) as u32;
+      let lsb = try!(parse_int(bits[1])) as u32;
+      Ok(Some((lsb, msb)))
+    },
+    (None, Some(offset), _) => {
+      let offset = try!(parse_int(offset.as_str())) as u32;
+      let width = match elem.child_text("bitWidth") {
+        Some(width) => try!(parse_int(width.as_str())) as u32,
+        None => 1,
+      };
+      Ok(Some((offset, offset + width - 1)))
+    },
+    (None, None, Some(lsb)) => {
+      let msb = match elem.child_text("msb") {
+        Some(msb) => msb,
+        None => return Err(Error::Svd("`lsb` without `msb`".to_string())),
+      };
+      Ok(Some((try!(parse_int(lsb.as_str())) as u32,
+          try!(parse_int(msb.as_str())) as u32)))
+    },
+    (None, None, None) => Ok(None),
+  }
+}
+
+fn parse_enumerated_values(elem: &Element,
+    enums: &mut HashMap<String, Vec<EnumValue>>)
+    -> Result<Vec<EnumValue>, Error> {
+  match elem.attribute("derivedFrom") {
+    Some(path) => {
+      // Only the last component of `peripheral.register.field.values` paths is
+      // used for lookup.
+      let name = path.split('.').last().unwrap();
+      return match enums.get(name) {
+        Some(values) => Ok(values.clone()),
+        None => Err(Error::Svd(format!(
+            "enumerated values derived from unknown `{}`", path))),
+      };
+    },
+    None => (),
+  }
+
+  let mut values = vec!();
+  for child in elem.children_named("enumeratedValue").iter() {
+    let name = match child.child_text("name") {
+      Some(name) => name,
+      None => return Err(Error::Svd("enumerated value without a name".to_string())),
+    };
+    // `isDefault` values have no value and can't be represented.
+    let value = match child.child_text("value") {
+      Some(value) => try!(parse_enum_value(value.as_str())),
+      None => continue,
+    };
+    if name.contains("RESERVED") {
+      continue;
+    }
+    values.push(EnumValue {
+      name: sanitize_ident(name.as_str(), "E_"),
+      description: child.child_text("description").unwrap_or(String::new()),
+      value: value,
+    });
+  }
+
+  match elem.child_text("name") {
+    Some(name) => { enums.insert(name, values.clone()); },
+    None => (),
+  }
+  Ok(values)
+}
+
+/// Parses decimal, `0x` hexadecimal and `#` binary numbers.
+pub fn parse_int(s: &str) -> Result<u64, Error> {
+  let s = s.trim();
+  let parsed = if s.starts_with("0x") || s.starts_with("0X") {
+    u64::from_str_radix(&s[2..], 16)
+  } else if s.starts_with("#") {
+    u64::from_str_radix(&s[1..], 2)
+  } else {
+    s.parse()
+  };
+  parsed.map_err(|_| Error::Svd(format!("invalid number `{}`", s)))
+}
+
+/// Enumerated values may use `x` for don't care bits in binary notation, those
+/// are treated as zeros.
+fn parse_enum_value(s: &str) -> Result<u64, Error> {
+  if s.trim().starts_with("#") {
+    parse_int(s.replace("x", "0").as_str())
+  } else {
+    parse_int(s)
+  }
+}
+
+/// Expands `dim` element lists.
+///
+/// Returns name, offset and count of each resulting item. `name[%s]` lists
+/// that are laid out back to back become a single array, other lists are
+/// expanded into separate items with `%s` replaced by the index.
+fn expand_dim(elem: &Element, name: &str, offset: u64, item_size: u64)
+    -> Result<Vec<(String, u64, u32)>, Error> {
+  let dim = match elem.child_text("dim") {
+    Some(dim) => try!(parse_int(dim.as_str())) as u32,
+    None => return Ok(vec!((name.to_string(), offset, 1))),
+  };
+  let increment = match elem.child_text("dimIncrement") {
+    Some(increment) => try!(parse_int(increment.as_str())),
+    None => return Err(Error::Svd(format!("`{}` has no dimIncrement", name))),
+  };
+
+  if name.contains("[%s]") && increment == item_size {
+    return Ok(vec!((name.replace("[%s]", ""), offset, dim)));
+  }
+
+  let indices = try!(dim_indices(elem.child_text("dimIndex"), dim));
+  Ok(indices.iter().enumerate().map(|(i, idx)| {
+    let name = name.replace("[%s]", idx.as_str()).replace("%s", idx.as_str());
+    (name.to_lowercase(), offset + i as u64 * increment, 1)
+  }).collect())
+}
+
+fn dim_indices(dim_index: Option<String>, dim: u32) -> Result<Vec<String>, Error> {
+  let indices: Vec<String> = match dim_index {
+    None => (0..dim).map(|i| i.to_string()).collect(),
+    Some(ref index) if index.contains(',') =>
+        index.split(',').map(|i| i.trim().to_string()).collect(),
+    Some(ref index) if index.contains('-') => {
+      let bounds: Vec<&str> = index.split('-').map(|i| i.trim()).collect();
+      match (bounds[0].parse::<u32>(), bounds[1].parse::<u32>()) {
+        (Ok(from), Ok(to)) => (from..to + 1).map(|i| i.to_string()).collect(),
+        _ => {
+          let from = bounds[0].chars().next().unwrap_or('A') as u8;
+          let to = bounds[1].chars().next().unwrap_or('A') as u8;
+          (from..to + 1).map(|c| (c as char).to_string()).collect()
+        },
+      }
+    },
+    Some(ref index) => vec!(index.clone()),
+  };
+  if indices.len() != dim as usize {
+    return Err(Error::Svd(format!("dimIndex `{}` doesn't match dim {}",
+        dim_index.unwrap_or(String::new()), dim)));
+  }
+  Ok(indices)
+}
+
+/// Returns the span of a register block in bytes.
+fn cluster_size(items: &[RegisterItem]) -> u64 {
+  items.iter().map(|item| match *item {
+    RegisterItem::Register(ref r) => r.offset + (r.size as u64 / 8) * r.count as u64,
+    RegisterItem::Cluster(ref c) =>
+        c.offset + cluster_size(&c.registers) * c.count as u64,
+  }).max().unwrap_or(0)
+}
+
+static KEYWORDS: &'static [&'static str] = &[
+  "abstract", "as", "box", "break", "const", "continue", "crate", "do", "else",
+  "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let",
+  "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+  "ref", "return", "self", "static", "struct", "super", "trait", "true",
+  "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while",
+  "yield",
+];
+
+/// Turns an SVD name into a valid identifier, adding `prefix` to names that
+/// start with a digit or are keywords.
+fn sanitize_ident(name: &str, prefix: &str) -> String {
+  let ident: String = name.chars()
+      .map(|c| if c.is_alphanumeric() || c == '_' { c } else { '_' })
+      .collect();
+  let starts_with_digit = ident.chars().next().map_or(true, |c| c.is_digit(10));
+  if starts_with_digit || KEYWORDS.contains(&ident.as_str()) {
+    format!("{}{}", prefix, ident)
+  } else {
+    ident
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use xml;
+  use super::{Access, RegisterItem, parse_device, parse_int};
+
+  fn parse(src: &str) -> super::Device {
+    parse_device(&xml::parse(src).unwrap()).unwrap()
+  }
+
+  fn register(item: &RegisterItem) -> &super::Register {
+    match *item {
+      RegisterItem::Register(ref r) => r,
+      RegisterItem::Cluster(_) => panic!("expected a register"),
+    }
+  }
+
+  #[test]
+  fn parses_numbers() {
+    assert_eq!(parse_int("12").unwrap(), 12);
+    assert_eq!(parse_int("0x20").unwrap(), 32);
+    assert_eq!(parse_int("#101").unwrap(), 5);
+    assert!(parse_int("ten").is_err());
+  }
+
+  #[test]
+  fn parses_registers_and_fields() {
+    let device = parse("<device><size>0x20</size><resetValue>0x0</resetValue><peripherals>
+      <peripheral>
+        <name>UART</name>
+        <baseAddress>0x40000000</baseAddress>
+        <registers>
+          <register>
+            <name>CR</name>
+            <description>Control</description>
+            <addressOffset>0x4</addressOffset>
+            <resetValue>0x300</resetValue>
+            <fields>
+              <field><name>EN</name><bitOffset>0</bitOffset><bitWidth>1</bitWidth></field>
+              <field><name>RESERVED</name><bitRange>[3:1]</bitRange></field>
+              <field><name>Match</name><lsb>4</lsb><msb>7</msb><access>read-only</access></field>
+              <field><name>TXE</name><bitOffset>10</bitOffset><bitWidth>1</bitWidth>
+                <modifiedWriteValues>oneToClear</modifiedWriteValues></field>
+              <field><name>RXNE</name><bitOffset>11</bitOffset><bitWidth>1</bitWidth>
+                <readAction>clear</readAction></field>
+              <field>
+                <name>MODE</name><bitRange>[9:8]</bitRange>
+                <enumeratedValues>
+                  <enumeratedValue><name>OFF</name><value>0</value></enumeratedValue>
+                  <enumeratedValue><name>RESERVED1</name><value>1</value></enumeratedValue>
+                  <enumeratedValue><name>2WIRE</name><value>#10</value></enumeratedValue>
+                </enumeratedValues>
+              </field>
+            </fields>
+          </register>
+          <register><name>SR</name><addressOffset>0x8</addressOffset></register>
+        </registers>
+      </peripheral>
+    </peripherals></device>");
+
+    let uart = &device.peripherals[0];
+    assert_eq!(uart.base_address, 0x40000000);
+    let cr = register(&uart.registers[0]);
+    assert_eq!(cr.name, "cr");
+    assert_eq!(cr.offset, 4);
+    assert_eq!(cr.size, 32);
+    assert_eq!(cr.reset_value, Some(0x300));
+    assert_eq!(register(&uart.registers[1]).reset_value, Some(0));
+    assert_eq!(cr.fields.len(), 5);
+    assert_eq!(cr.fields[1].name, "f_match");
+    assert_eq!((cr.fields[1].low_bit, cr.fields[1].high_bit), (4, 7));
+    assert_eq!(cr.fields[1].access, Access::ReadOnly);
+    let mode = &cr.fields[4];
+    assert_eq!(mode.values.len(), 2);
+    assert_eq!(mode.values[1].name, "E_2WIRE");
+    assert_eq!(mode.values[1].value, 2);
+    assert_eq!(cr.fields[2].access, Access::SetToClear);
+    assert_eq!(cr.fields[3].access, Access::ReadToClear);
+  }
+
+  #[test]
+  fn resolves_derived_from() {
+    let device = parse("<device><peripherals>
+      <peripheral>
+        <name>TIM2</name>
+        <baseAddress>0x40000000</baseAddress>
+        <registers>
+          <register>
+            <name>CNT</name><addressOffset>0x0</addressOffset>
+            <fields>
+              <field>
+                <name>A</name><bitRange>[1:0]</bitRange>
+                <enumeratedValues>
+                  <name>AValues</name>
+                  <enumeratedValue><name>X</name><value>1</value></enumeratedValue>
+                </enumeratedValues>
+              </field>
+              <field derivedFrom=\"A\"><name>B</name><bitRange>[3:2]</bitRange></field>
+              <field>
+                <name>C</name><bitRange>[5:4]</bitRange>
+                <enumeratedValues derivedFrom=\"TIM2.CNT.A.AValues\"/>
+              </field>
+            </fields>
+          </register>
+          <register derivedFrom=\"CNT\"><name>ARR</name><addressOffset>0x4</addressOffset></register>
+        </registers>
+      </peripheral>
+      <peripheral derivedFrom=\"TIM2\">
+        <name>TIM3</name>
+        <baseAddress>0x40000400</baseAddress>
+      </peripheral>
+    </peripherals></device>");
+
+    let tim3 = &device.peripherals[1];
+    assert_eq!(tim3.name, "TIM3");
+    assert_eq!(tim3.base_address, 0x40000400);
+    assert_eq!(tim3.registers.len(), 2);
+    let arr = register(&tim3.registers[1]);
+    assert_eq!(arr.name, "arr");
+    assert_eq!(arr.offset, 4);
+    assert_eq!(arr.fields.len(), 3);
+    assert_eq!(arr.fields[1].values[0].name, "X");
+    assert_eq!(arr.fields[2].values[0].name, "X");
+  }
+
+  #[test]
+  fn expands_dim_lists_and_arrays() {
+    let device = parse("<device><peripherals>
+      <peripheral>
+        <name>I2C</name>
+        <baseAddress>0x40000000</baseAddress>
+        <registers>
+          <register>
+            <dim>3</dim><dimIncrement>0x4</dimIncrement><dimIndex>1-3</dimIndex>
+            <name>ADR%s</name><addressOffset>0x20</addressOffset>
+          </register>
+          <register>
+            <dim>4</dim><dimIncrement>0x4</dimIncrement>
+            <name>DATA[%s]</name><addressOffset>0x40</addressOffset>
+          </register>
+          <cluster>
+            <dim>2</dim><dimIncrement>0x8</dimIncrement>
+            <name>CH[%s]</name><addressOffset>0x80</addressOffset>
+            <register><name>CR</name><addressOffset>0x0</addressOffset></register>
+            <register><name>SR</name><addressOffset>0x4</addressOffset></register>
+          </cluster>
+        </registers>
+      </peripheral>
+    </peripherals></device>");
+
+    let regs = &device.peripherals[0].registers;
+    assert_eq!(regs.len(), 5);
+    assert_eq!(register(&regs[0]).name, "adr1");
+    assert_eq!(register(&regs[2]).name, "adr3");
+    assert_eq!(register(&regs[2]).offset, 0x28);
+    assert_eq!(register(&regs[3]).name, "data");
+    assert_eq!(register(&regs[3]).count, 4);
+    match regs[4] {
+      RegisterItem::Cluster(ref c) => {
+        assert_eq!(c.name, "ch");
+        assert_eq!(c.count, 2);
+        assert_eq!(c.registers.len(), 2);
+      },
+      RegisterItem::Register(_) => panic!("expected a cluster"),
+    }
+  }
+}
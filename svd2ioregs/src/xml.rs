@@ -0,0 +1,288 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2015 zinc developers <http://zinc.rs>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Minimal XML reader, just enough to load CMSIS-SVD files.
+//!
+//! Namespaces, DTDs and processing instructions are ignored.
+
+use std::collections::HashMap;
+
+/// XML element with its attributes, child elements and text content.
+#[derive(Debug)]
+pub struct Element {
+  pub name: String,
+  pub attributes: HashMap<String, String>,
+  pub children: Vec<Element>,
+  pub text: String,
+}
+
+impl Element {
+  /// Returns the first child element with given name.
+  pub fn child(&self, name: &str) -> Option<&Element> {
+    self.children.iter().find(|c| c.name == name)
+  }
+
+  /// Returns all child elements with given name.
+  pub fn children_named<'a>(&'a self, name: &str) -> Vec<&'a Element> {
+    self.children.iter().filter(|c| c.name == name).collect()
+  }
+
+  /// Returns the text of a child element, trimmed and with newlines replaced
+  /// by spaces.
+  pub fn child_text(&self, name: &str) -> Option<String> {
+    self.child(name).map(|c| c.text.trim().replace("\n", " "))
+  }
+
+  /// Returns the value of an attribute.
+  pub fn attribute(&self, name: &str) -> Option<&str> {
+    self.attributes.get(name).map(|v| v.as_str())
+  }
+}
+
+/// Parses the document and returns its root element.
+pub fn parse(src: &str) -> Result<Element, String> {
+  // XML processors normalize line endings to `\n`.
+  let src = src.replace("\r\n", "\n").replace("\r", "\n");
+  let mut reader = Reader { src: src.as_str(), pos: 0 };
+  try!(reader.skip_misc());
+  let root = try!(reader.parse_element());
+  try!(reader.skip_misc());
+  if reader.pos != src.len() {
+    return Err(reader.error("unexpected content after the root element"));
+  }
+  Ok(root)
+}
+
+struct Reader<'a> {
+  src: &'a str,
+  pos: usize,
+}
+
+impl<'a> Reader<'a> {
+  fn rest(&self) -> &'a str {
+    &self.src[self.pos..]
+  }
+
+  fn error(&self, msg: &str) -> String {
+    let line = self.src[..self.pos].matches('\n').count() + 1;
+    format!("{} at line {}", msg, line)
+  }
+
+  fn skip_whitespace(&mut self) {
+    let rest = self.rest();
+    self.pos += rest.len() - rest.trim_left().len();
+  }
+
+  fn skip_past(&mut self, end: &str) -> Result<(), String> {
+    match self.rest().find(end) {
+      Some(idx) => {
+        self.pos += idx + end.len();
+        Ok(())
+      },
+      None => Err(self.error(format!("missing `{}`", end).as_str())),
+    }
+  }
+
+  /// Skips whitespace, comments, declarations and processing instructions.
+  fn skip_misc(&mut self) -> Result<(), String> {
+    loop {
+      self.skip_whitespace();
+      if self.rest().starts_with("<?") {
+        try!(self.skip_past("?>"));
+      } else if self.rest().starts_with("<!--") {
+        try!(self.skip_past("-->"));
+      } else if self.rest().starts_with("<!") {
+        try!(self.skip_past(">"));
+      } else {
+        return Ok(());
+      }
+    }
+  }
+
+  fn expect(&mut self, s: &str) -> Result<(), String> {
+    if self.rest().starts_with(s) {
+      self.pos += s.len();
+      Ok(())
+    } else {
+      Err(self.error(format!("expected `{}`", s).as_str()))
+    }
+  }
+
+  fn parse_name(&mut self) -> Result<String, String> {
+    let len = self.rest()
+        .find(|c: char| c.is_whitespace() || "/>=".contains(c))
+        .unwrap_or(self.rest().len());
+    if len == 0 {
+      return Err(self.error("expected a name"));
+    }
+    let name = self.rest()[..len].to_string();
+    self.pos += len;
+    Ok(name)
+  }
+
+  fn parse_element(&mut self) -> Result<Element, String> {
+    try!(self.expect("<"));
+    let mut element = Element {
+      name: try!(self.parse_name()),
+      attributes: HashMap::new(),
+      children: vec!(),
+      text: String::new(),
+    };
+
+    loop {
+      self.skip_whitespace();
+      if self.rest().starts_with("/>") {
+        self.pos += 2;
+        return Ok(element);
+      }
+      if self.rest().starts_with(">") {
+        self.pos += 1;
+        break;
+      }
+      let key = try!(self.parse_name());
+      self.skip_whitespace();
+      try!(self.expect("="));
+      self.skip_whitespace();
+      let quote = match self.rest().chars().next() {
+        Some(q) if q == '"' || q == '\'' => q,
+        _ => return Err(self.error("expected a quoted attribute value")),
+      };
+      self.pos += 1;
+      let len = match self.rest().find(quote) {
+        Some(len) => len,
+        None => return Err(self.error("unterminated attribute value")),
+      };
+      let value = try!(unescape(&self.rest()[..len]).map_err(|e| self.error(&e)));
+      self.pos += len + 1;
+      element.attributes.insert(key, value);
+    }
+
+    loop {
+      let rest = self.rest();
+      if rest.starts_with("</") {
+        self.pos += 2;
+        let name = try!(self.parse_name());
+        if name != element.name {
+          return Err(self.error(format!("expected `</{}>`, found `</{}>`",
+              element.name, name).as_str()));
+        }
+        self.skip_whitespace();
+        try!(self.expect(">"));
+        return Ok(element);
+      } else if rest.starts_with("<!--") {
+        try!(self.skip_past("-->"));
+      } else if rest.starts_with("<![CDATA[") {
+        self.pos += "<![CDATA[".len();
+        let len = match self.rest().find("]]>") {
+          Some(len) => len,
+          None => return Err(self.error("unterminated CDATA section")),
+        };
+        element.text.push_str(&self.rest()[..len]);
+        self.pos += len + 3;
+      } else if rest.starts_with("<?") {
+        try!(self.skip_past("?>"));
+      } else if rest.starts_with("<") {
+        element.children.push(try!(self.parse_element()));
+      } else if rest.is_empty() {
+        return Err(self.error(format!("unterminated element `{}`",
+            element.name).as_str()));
+      } else {
+        let len = rest.find('<').unwrap_or(rest.len());
+        let text = try!(unescape(&rest[..len]).map_err(|e| self.error(&e)));
+        element.text.push_str(text.as_str());
+        self.pos += len;
+      }
+    }
+  }
+}
+
+fn unescape(s: &str) -> Result<String, String> {
+  let mut out = String::new();
+  let mut rest = s;
+  while let Some(idx) = rest.find('&') {
+    out.push_str(&rest[..idx]);
+    rest = &rest[idx..];
+    let end = match rest.find(';') {
+      Some(end) => end,
+      None => return Err("unterminated entity".to_string()),
+    };
+    let entity = &rest[1..end];
+    let c = match entity {
+      "lt" => '<',
+      "gt" => '>',
+      "amp" => '&',
+      "quot" => '"',
+      "apos" => '\'',
+      _ if entity.starts_with("#x") =>
+          try!(char_from(u32::from_str_radix(&entity[2..], 16).ok(), entity)),
+      _ if entity.starts_with("#") =>
+          try!(char_from(entity[1..].parse().ok(), entity)),
+      _ => return Err(format!("unknown entity `&{};`", entity)),
+    };
+    out.push(c);
+    rest = &rest[end + 1..];
+  }
+  out.push_str(rest);
+  Ok(out)
+}
+
+fn char_from(code: Option<u32>, entity: &str) -> Result<char, String> {
+  match code.and_then(::std::char::from_u32) {
+    Some(c) => Ok(c),
+    None => Err(format!("invalid character reference `&{};`", entity)),
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::parse;
+
+  #[test]
+  fn parses_nested_elements() {
+    let root = parse("<?xml version=\"1.0\"?>
+      <!-- comment -->
+      <device schemaVersion='1.1'>
+        <name>TEST</name>
+        <empty/>
+        <peripherals><peripheral><name>A</name></peripheral></peripherals>
+      </device>").unwrap();
+    assert_eq!(root.name, "device");
+    assert_eq!(root.attribute("schemaVersion"), Some("1.1"));
+    assert_eq!(root.child_text("name"), Some("TEST".to_string()));
+    assert!(root.child("empty").is_some());
+    let peripherals = root.child("peripherals").unwrap();
+    assert_eq!(peripherals.children_named("peripheral").len(), 1);
+  }
+
+  #[test]
+  fn unescapes_text_and_attributes() {
+    let root = parse("<a b=\"&lt;&#65;&#x42;&gt;\">x &amp; y<![CDATA[ <z> ]]></a>")
+        .unwrap();
+    assert_eq!(root.attribute("b"), Some("<AB>"));
+    assert_eq!(root.text, "x & y <z> ");
+  }
+
+  #[test]
+  fn replaces_newlines_in_child_text() {
+    let root = parse("<a><d>\n  first\n  second\n</d></a>").unwrap();
+    assert_eq!(root.child_text("d"), Some("first   second".to_string()));
+  }
+
+  #[test]
+  fn fails_on_mismatched_tags() {
+    assert!(parse("<a><b></a>").is_err());
+    assert!(parse("<a>").is_err());
+  }
+}
@@ -31,9 +31,14 @@
 #[cfg(feature="replayer")] use core::clone::Clone;
 #[cfg(feature="replayer")] use core::cell::RefCell;
 
+#[cfg(feature="replayer")] use models::PeripheralModel;
+#[cfg(feature="replayer")] use std::boxed::Box;
+
 #[cfg(not(feature="replayer"))] use core::intrinsics::{volatile_load, volatile_store};
 #[cfg(feature="replayer")] use core::intrinsics::transmute;
 
+#[cfg(feature="replayer")] pub mod models;
+
 // TODO(farcaller): why this needs copy/clone?
 /// This structure is used to represent a hardware register.
 /// It is mostly used by the ioreg family of macros.
@@ -71,167 +76,381 @@ impl<T: Copy> VolatileCell<T> {
 }
 
 #[cfg(feature="replayer")]
-impl VolatileCell<u32> {
-  pub fn get(&self) -> u32 {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().get_cell(transmute(&self.value)) })
-    }
-  }
+macro_rules! replayer_impl {
+  ($ty: ty, $width: expr) => (
+    #[cfg(feature="replayer")]
+    impl VolatileCell<$ty> {
+      pub fn get(&self) -> $ty {
+        unsafe {
+          GLOBAL_REPLAYER.with(|gr| {
+            gr.borrow_mut().get_cell(transmute(&self.value), $width)
+          }) as $ty
+        }
+      }
 
-  pub fn set(&self, value: u32) {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().set_cell(transmute(&self.value), value) })
+      pub fn set(&self, value: $ty) {
+        unsafe {
+          GLOBAL_REPLAYER.with(|gr| {
+            gr.borrow_mut().set_cell(transmute(&self.value), value as u64, $width)
+          })
+        }
+      }
     }
-  }
+  );
 }
 
-#[cfg(feature="replayer")]
-impl VolatileCell<u16> {
-  pub fn get(&self) -> u16 {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().get_cell(transmute(&self.value)) }) as u16
-    }
-  }
+#[cfg(feature="replayer")] replayer_impl!(u64, Width::U64);
+#[cfg(feature="replayer")] replayer_impl!(u32, Width::U32);
+#[cfg(feature="replayer")] replayer_impl!(u16, Width::U16);
+#[cfg(feature="replayer")] replayer_impl!(u8, Width::U8);
 
-  pub fn set(&self, value: u16) {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().set_cell(transmute(&self.value), value as u32) })
-    }
-  }
+/// Width of a replayed access.
+#[cfg(feature="replayer")]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Width {
+  U8,
+  U16,
+  U32,
+  U64,
 }
 
 #[cfg(feature="replayer")]
-impl VolatileCell<u8> {
-  pub fn get(&self) -> u8 {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().get_cell(transmute(&self.value)) }) as u8
-    }
+impl core::fmt::Display for Width {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    let bits = match *self {
+      Width::U8  => 8,
+      Width::U16 => 16,
+      Width::U32 => 32,
+      Width::U64 => 64,
+    };
+    write!(f, "{}-bit", bits)
   }
+}
 
-  pub fn set(&self, value: u8) {
-    unsafe {
-      GLOBAL_REPLAYER.with(|gr| { gr.borrow_mut().set_cell(transmute(&self.value), value as u32) })
-    }
-  }
+/// How many accesses a replay record stands for.
+#[cfg(feature="replayer")]
+#[derive(Copy, Clone, PartialEq)]
+enum ReplayKind {
+  /// Exactly one access.
+  Once,
+  /// Any number of reads, including none, all returning the same value.
+  Repeated,
+  /// `reads` reads returning the expected value, then one returning `ready`.
+  Poll { reads: usize, ready: u64 },
 }
 
 #[cfg(feature="replayer")]
 struct ReplayRecord {
   is_read: bool,
   address: usize,
-  value: u32,
+  value: u64,
+  mask: u64,
+  width: Width,
+  kind: ReplayKind,
+  group: Option<usize>,
 
   replayed: bool,
+  reads_done: usize,
   did_read: bool,
   actual_address: usize,
-  actual_value: u32,
+  actual_value: u64,
+  actual_width: Width,
 
   loc: expectest::core::SourceLocation,
 }
 
+#[cfg(feature="replayer")]
+impl ReplayRecord {
+  fn matches(&self, is_read: bool, address: usize, value: u64, width: Width) -> bool {
+    self.is_read == is_read && self.address == address && self.width == width &&
+        (is_read || (self.value ^ value) & self.mask == 0)
+  }
+}
+
 #[cfg(feature="replayer")]
 impl core::fmt::Display for ReplayRecord {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    match self.is_read {
-      true  => write!(f, "read 0x{:x} from 0x{:x}", self.value, self.address),
-      false => write!(f, "write 0x{:x} to 0x{:x}", self.value, self.address),
+    match (self.is_read, self.kind) {
+      (true, ReplayKind::Once) => write!(f, "{} read 0x{:x} from 0x{:x}",
+                      self.width, self.value, self.address),
+      (true, ReplayKind::Repeated) => write!(f, "{} reads of 0x{:x} from 0x{:x}",
+                      self.width, self.value, self.address),
+      (true, ReplayKind::Poll { reads, ready }) => write!(f,
+                      "{} poll of 0x{:x} from 0x{:x}, ready 0x{:x} after {} reads",
+                      self.width, self.value, self.address, ready, reads),
+      (false, _) if self.mask != !0 => write!(f, "{} write 0x{:x} (mask 0x{:x}) to 0x{:x}",
+                      self.width, self.value, self.mask, self.address),
+      (false, _) => write!(f, "{} write 0x{:x} to 0x{:x}",
+                      self.width, self.value, self.address),
     }
   }
 }
 
+/// Replays expected volatile accesses in tests.
+///
+/// Expectations are matched in the order they were added, except for the ones
+/// added between `begin_unordered` and `end_unordered`, which may be matched
+/// in any order relative to each other. Accesses to registers simulated by a
+/// model are handled by the model and don't match any expectation.
 #[cfg(feature="replayer")]
 pub struct VolatileCellReplayer {
+  models: Vec<Box<PeripheralModel>>,
   replays: Vec<ReplayRecord>,
   current_replay: usize,
+  next_group: usize,
+  open_group: Option<usize>,
 }
 
 #[cfg(feature="replayer")]
 impl VolatileCellReplayer {
   pub fn new() -> VolatileCellReplayer {
     VolatileCellReplayer {
+      models: Vec::new(),
       replays: Vec::new(),
       current_replay: 0,
+      next_group: 0,
+      open_group: None,
     }
   }
 
   pub fn expect_read(&mut self, address: usize, value: u32,
       loc: expectest::core::SourceLocation) {
-    self.replays.push(ReplayRecord {
-      is_read: true,
-      address: address,
-      value: value,
-      replayed: false,
-      did_read: false,
-      actual_address: 0,
-      actual_value: 0,
-      loc: loc,
-    });
+    self.expect_read_width(address, value as u64, Width::U32, loc);
   }
 
   pub fn expect_write(&mut self, address: usize, value: u32,
       loc: expectest::core::SourceLocation) {
+    self.expect_write_width(address, value as u64, Width::U32, loc);
+  }
+
+  /// Expects a write where only the bits set in `mask` are checked.
+  pub fn expect_write_masked(&mut self, address: usize, value: u32, mask: u32,
+      loc: expectest::core::SourceLocation) {
+    self.expect_write_masked_width(address, value as u64, mask as u64,
+        Width::U32, loc);
+  }
+
+  /// Expects any number of reads, including none, returning `value`. The
+  /// expectation is passed over by the first access that doesn't match it.
+  pub fn expect_reads(&mut self, address: usize, value: u32,
+      loc: expectest::core::SourceLocation) {
+    self.expect_reads_width(address, value as u64, Width::U32, loc);
+  }
+
+  /// Expects a status register to be polled: the first `reads` reads return
+  /// `value` and the next one returns `ready`.
+  pub fn expect_poll(&mut self, address: usize, value: u32, ready: u32,
+      reads: usize, loc: expectest::core::SourceLocation) {
+    self.expect_poll_width(address, value as u64, ready as u64, reads,
+        Width::U32, loc);
+  }
+
+  pub fn expect_read_width(&mut self, address: usize, value: u64, width: Width,
+      loc: expectest::core::SourceLocation) {
+    self.push_replay(true, address, value, !0, width, ReplayKind::Once, loc);
+  }
+
+  pub fn expect_write_width(&mut self, address: usize, value: u64, width: Width,
+      loc: expectest::core::SourceLocation) {
+    self.push_replay(false, address, value, !0, width, ReplayKind::Once, loc);
+  }
+
+  pub fn expect_write_masked_width(&mut self, address: usize, value: u64,
+      mask: u64, width: Width, loc: expectest::core::SourceLocation) {
+    self.push_replay(false, address, value, mask, width, ReplayKind::Once, loc);
+  }
+
+  pub fn expect_reads_width(&mut self, address: usize, value: u64, width: Width,
+      loc: expectest::core::SourceLocation) {
+    if self.open_group.is_some() {
+      panic!("repeated reads of 0x{:x} can't be part of an unordered group", address);
+    }
+    self.push_replay(true, address, value, !0, width, ReplayKind::Repeated, loc);
+  }
+
+  pub fn expect_poll_width(&mut self, address: usize, value: u64, ready: u64,
+      reads: usize, width: Width, loc: expectest::core::SourceLocation) {
+    self.push_replay(true, address, value, !0, width,
+        ReplayKind::Poll { reads: reads, ready: ready }, loc);
+  }
+
+  /// Adds a simulated peripheral. Models added first take precedence.
+  pub fn add_model<M: PeripheralModel + 'static>(&mut self, model: M) {
+    self.models.push(Box::new(model));
+  }
+
+  /// Starts a group of expectations that may be matched in any order.
+  pub fn begin_unordered(&mut self) {
+    if self.open_group.is_some() {
+      panic!("unordered groups can't be nested");
+    }
+    self.open_group = Some(self.next_group);
+    self.next_group += 1;
+  }
+
+  /// Ends the group started by `begin_unordered`.
+  pub fn end_unordered(&mut self) {
+    if self.open_group.is_none() {
+      panic!("no unordered group to end");
+    }
+    self.open_group = None;
+  }
+
+  fn push_replay(&mut self, is_read: bool, address: usize, value: u64, mask: u64,
+      width: Width, kind: ReplayKind, loc: expectest::core::SourceLocation) {
+    let group = self.open_group;
     self.replays.push(ReplayRecord {
-      is_read: false,
+      is_read: is_read,
       address: address,
       value: value,
+      mask: mask,
+      width: width,
+      kind: kind,
+      group: group,
       replayed: false,
+      reads_done: 0,
       did_read: false,
       actual_address: 0,
       actual_value: 0,
+      actual_width: width,
       loc: loc,
     });
   }
 
   pub fn verify(&self, loc: expectest::core::SourceLocation) {
-    expect(self.current_replay).location(loc).to(
+    let required = self.replays.iter()
+        .filter(|r| r.kind != ReplayKind::Repeated).count();
+    let performed = self.replays.iter()
+        .filter(|r| r.kind != ReplayKind::Repeated && r.replayed).count();
+    expect(performed).location(loc).to(
       be_equal_to_with_context(
-          self.replays.len(),
-          format!("expected {} replays, performed {}",
-              self.replays.len(), self.current_replay)));
+          required,
+          format!("expected {} replays, performed {}", required, performed)));
 
     for ref replay in &*self.replays {
+      if replay.kind == ReplayKind::Repeated {
+        continue;
+      }
       expect(replay.replayed).location(replay.loc).to(be_equal_to_with_context(true,
         format!("expected replay {} to be performed, was not", replay)));
+      if !replay.replayed {
+        continue;
+      }
       expect(replay.is_read).location(replay.loc).to(be_equal_to_with_context(replay.did_read,
         format!("expected replay to be {} replay, was {} replay",
           if replay.is_read {"read"} else {"write"},
           if replay.is_read {"write"} else {"read"})));
       expect(replay.address).location(replay.loc).to(be_equal_to_with_context(replay.actual_address,
         format!("expected replay address 0x{:x}, was 0x{:x}", replay.address, replay.actual_address)));
+      expect(replay.width).location(replay.loc).to(be_equal_to_with_context(replay.actual_width,
+        format!("expected {} access to 0x{:x}, was {} access",
+          replay.width, replay.address, replay.actual_width)));
       if !replay.is_read {
-        expect(replay.value).location(replay.loc).to(be_equal_to_with_context(replay.actual_value,
-          format!("expected replay to write 0x{:x}, written 0x{:x}", replay.value, replay.actual_value)));
+        expect(replay.value & replay.mask).location(replay.loc).to(be_equal_to_with_context(
+          replay.actual_value & replay.mask,
+          format!("expected replay to write 0x{:x} (mask 0x{:x}), written 0x{:x}",
+            replay.value, replay.mask, replay.actual_value)));
       }
     }
   }
 
-  pub fn get_cell(&mut self, address: usize) -> u32 {
-    if self.current_replay >= self.replays.len() {
-      panic!("get_cell(0x{:x}) faled, current replay: {}, total replays: {}",
-        address, self.current_replay+1, self.replays.len());
+  pub fn get_cell(&mut self, address: usize, width: Width) -> u64 {
+    for model in self.models.iter_mut() {
+      if model.handles(address) {
+        return model.read(address, width);
+      }
     }
-    let replay: &mut ReplayRecord = &mut self.replays[self.current_replay];
-    replay.replayed = true;
-    replay.did_read = true;
-    replay.actual_address = address;
-
-    self.current_replay += 1;
+    match self.replay(true, address, 0, width) {
+      Some(value) => value,
+      None => panic!("get_cell(0x{:x}) faled, current replay: {}, total replays: {}",
+        address, self.current_replay+1, self.replays.len()),
+    }
+  }
 
-    replay.value
+  pub fn set_cell(&mut self, address: usize, value: u64, width: Width) {
+    for model in self.models.iter_mut() {
+      if model.handles(address) {
+        return model.write(address, value, width);
+      }
+    }
+    match self.replay(false, address, value, width) {
+      Some(_) => (),
+      None => panic!("set_cell(0x{:x}, 0x{:x}) faled, current replay: {}, total replays: {}",
+        address, value, self.current_replay+1, self.replays.len()),
+    }
   }
 
-  pub fn set_cell(&mut self, address: usize, value: u32) {
-    if self.current_replay >= self.replays.len() {
-      panic!("set_cell(0x{:x}, 0x{:x}) faled, current replay: {}, total replays: {}",
-        address, value, self.current_replay+1, self.replays.len());
+  /// Matches an access against the pending expectations, returning the value
+  /// to read or `None` if there are no expectations left.
+  fn replay(&mut self, is_read: bool, address: usize, value: u64,
+      width: Width) -> Option<u64> {
+    let idx = match self.find_replay(is_read, address, value, width) {
+      Some(idx) => idx,
+      None => return None,
+    };
+
+    let result = {
+      let replay: &mut ReplayRecord = &mut self.replays[idx];
+      let matched = replay.matches(is_read, address, value, width);
+      replay.did_read = is_read;
+      replay.actual_address = address;
+      replay.actual_value = value;
+      replay.actual_width = width;
+
+      match replay.kind {
+        ReplayKind::Poll { reads, ready } if matched => {
+          if replay.reads_done < reads {
+            replay.reads_done += 1;
+            replay.value
+          } else {
+            replay.replayed = true;
+            ready
+          }
+        },
+        _ => {
+          replay.replayed = true;
+          replay.value
+        },
+      }
+    };
+
+    // Repeated reads stay current until an access doesn't match them.
+    while self.current_replay < self.replays.len() {
+      let replay = &self.replays[self.current_replay];
+      if !replay.replayed || replay.kind == ReplayKind::Repeated {
+        break;
+      }
+      self.current_replay += 1;
     }
-    let replay: &mut ReplayRecord = &mut self.replays[self.current_replay];
-    replay.replayed = true;
-    replay.did_read = false;
-    replay.actual_address = address;
-    replay.actual_value = value;
 
-    self.current_replay += 1;
+    Some(result)
+  }
+
+  fn find_replay(&mut self, is_read: bool, address: usize, value: u64,
+      width: Width) -> Option<usize> {
+    while self.current_replay < self.replays.len() {
+      let idx = self.current_replay;
+      let replay = &self.replays[idx];
+      match replay.group {
+        Some(group) => {
+          // Any pending member of the group may match; if none does, the
+          // first pending one is blamed for the mismatch.
+          let found = self.replays[idx..].iter()
+              .take_while(|r| r.group == Some(group))
+              .position(|r| !r.replayed && r.matches(is_read, address, value, width));
+          return Some(idx + found.unwrap_or(0));
+        },
+        None => {
+          if replay.kind == ReplayKind::Repeated &&
+              !replay.matches(is_read, address, value, width) {
+            self.current_replay += 1;
+            continue;
+          }
+          return Some(idx);
+        },
+      }
+    }
+    None
   }
 }
 
@@ -285,6 +504,24 @@ impl<A, E> Matcher<A, E> for BeEqualToWithContext<E>
 
 #[macro_export]
 macro_rules! expect_volatile_read {
+  (u8, $addr: expr, $val: expr) => (
+    $crate::with_mut_replayer(|r| {
+      r.expect_read_width($addr, $val as u64, $crate::Width::U8,
+          expectest::core::SourceLocation::new(file!(), line!()));
+    })
+  );
+  (u16, $addr: expr, $val: expr) => (
+    $crate::with_mut_replayer(|r| {
+      r.expect_read_width($addr, $val as u64, $crate::Width::U16,
+          expectest::core::SourceLocation::new(file!(), line!()));
+    })
+  );
+  (u64, $addr: expr, $val: expr) => (
+    $crate::with_mut_replayer(|r| {
+      r.expect_read_width($addr, $val, $crate::Width::U64,
+          expectest::core::SourceLocation::new(file!(), line!()));
+    })
+  );
   ($addr: expr, $val: expr) => (
     $crate::with_mut_replayer(|r| {
       r.expect_read($addr, $val, expectest::core::SourceLocation::new(file!(), line!()));
@@ -294,6 +531,24 @@ macro_rules! expect_volatile_read {
 
 #[macro_export]
 macro_rules! expect_volatile_write {
+  (u8, $addr: expr, $val: expr) => (
+    $crate::with_mut_replayer(|r| {
+      r.expect_write_width($addr, $val as u64, $crate::Width::U8,
+          expectest::core::SourceLocation::new(file!(), line!()));
+    })
+  );
+  (u16, $addr: expr, $val: expr) => (
+    $crate::with_mut_replayer(|r| {
+      r.expect_write_width($addr, $val as u64, $crate::Width::U16,
+          expectest::core::SourceLocation::new(file!(), line!()));
+    })
+  );
+  (u64, $addr: expr, $val: expr) => (
+    $crate::with_mut_replayer(|r| {
+      r.expect_write_width($addr, $val, $crate::Width::U64,
+          expectest::core::SourceLocation::new(file!(), line!()));
+    })
+  );
   ($addr: expr, $val: expr) => (
     $crate::with_mut_replayer(|r| {
       r.expect_write($addr, $val, expectest::core::SourceLocation::new(file!(), line!()));
@@ -301,6 +556,56 @@ macro_rules! expect_volatile_write {
   );
 }
 
+/// Expects a write where only the bits set in the mask are checked.
+#[macro_export]
+macro_rules! expect_volatile_write_masked {
+  ($addr: expr, $val: expr, $mask: expr) => (
+    $crate::with_mut_replayer(|r| {
+      r.expect_write_masked($addr, $val, $mask,
+          expectest::core::SourceLocation::new(file!(), line!()));
+    })
+  );
+}
+
+/// Expects any number of reads, including none, returning the same value.
+#[macro_export]
+macro_rules! expect_volatile_reads {
+  ($addr: expr, $val: expr) => (
+    $crate::with_mut_replayer(|r| {
+      r.expect_reads($addr, $val, expectest::core::SourceLocation::new(file!(), line!()));
+    })
+  );
+}
+
+/// Expects `$reads` reads returning `$val` followed by one returning `$ready`.
+#[macro_export]
+macro_rules! expect_volatile_poll {
+  ($addr: expr, $val: expr, $ready: expr, $reads: expr) => (
+    $crate::with_mut_replayer(|r| {
+      r.expect_poll($addr, $val, $ready, $reads,
+          expectest::core::SourceLocation::new(file!(), line!()));
+    })
+  );
+}
+
+/// Expects the accesses set up in the block in any order.
+#[macro_export]
+macro_rules! expect_volatile_unordered {
+  ($body: block) => ({
+    $crate::with_mut_replayer(|r| r.begin_unordered());
+    $body;
+    $crate::with_mut_replayer(|r| r.end_unordered());
+  });
+}
+
+/// Adds a simulated peripheral to the replayer.
+#[macro_export]
+macro_rules! add_volatile_model {
+  ($model: expr) => (
+    $crate::with_mut_replayer(|r| r.add_model($model))
+  );
+}
+
 #[macro_export]
 macro_rules! expect_replayer_valid {
   () => (
@@ -0,0 +1,335 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Simulated peripherals for host-side tests.
+//!
+//! Models are added to the replayer with `add_volatile_model!` and take
+//! over all accesses to the registers they handle, so a driver can be run
+//! against a peripheral that reacts to it instead of a scripted sequence of
+//! accesses. Accesses to other registers are still matched against the
+//! replayer expectations.
+
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::vec::Vec;
+
+use super::Width;
+
+/// A simulated peripheral reacting to volatile accesses.
+pub trait PeripheralModel {
+  /// Returns true if the model simulates the register at `address`.
+  fn handles(&self, address: usize) -> bool;
+
+  /// Simulates a read of the register at `address`.
+  fn read(&mut self, address: usize, width: Width) -> u64;
+
+  /// Simulates a write of the register at `address`.
+  fn write(&mut self, address: usize, value: u64, width: Width);
+}
+
+fn width_mask(width: Width) -> u64 {
+  match width {
+    Width::U8  => 0xff,
+    Width::U16 => 0xffff,
+    Width::U32 => 0xffff_ffff,
+    Width::U64 => !0,
+  }
+}
+
+/// Simulated time shared between models, in timer ticks.
+#[derive(Clone)]
+pub struct Clock {
+  ticks: Rc<Cell<u64>>,
+}
+
+impl Clock {
+  pub fn new() -> Clock {
+    Clock { ticks: Rc::new(Cell::new(0)) }
+  }
+
+  /// Returns the current time.
+  pub fn now(&self) -> u64 {
+    self.ticks.get()
+  }
+
+  /// Moves the time forward.
+  pub fn advance(&self, ticks: u64) {
+    self.ticks.set(self.ticks.get() + ticks);
+  }
+}
+
+/// Plain memory backing a range of registers, reads return the last value
+/// written.
+pub struct RegisterFile {
+  base: usize,
+  size: usize,
+  values: BTreeMap<usize, u64>,
+}
+
+impl RegisterFile {
+  /// Creates registers covering `size` bytes from `base`, all reading zero.
+  pub fn new(base: usize, size: usize) -> RegisterFile {
+    RegisterFile {
+      base: base,
+      size: size,
+      values: BTreeMap::new(),
+    }
+  }
+
+  /// Sets the initial value of the register at `address`.
+  pub fn with_value(mut self, address: usize, value: u64) -> RegisterFile {
+    self.values.insert(address, value);
+    self
+  }
+}
+
+impl PeripheralModel for RegisterFile {
+  fn handles(&self, address: usize) -> bool {
+    address >= self.base && address < self.base + self.size
+  }
+
+  fn read(&mut self, address: usize, width: Width) -> u64 {
+    self.values.get(&address).map_or(0, |v| *v) & width_mask(width)
+  }
+
+  fn write(&mut self, address: usize, value: u64, _: Width) {
+    self.values.insert(address, value);
+  }
+}
+
+/// A free-running timer counter, the clock advances on every read of it.
+pub struct CounterModel {
+  address: usize,
+  clock: Clock,
+  ticks_per_read: u64,
+  offset: u64,
+}
+
+impl CounterModel {
+  /// Creates a counter at `address` reading the time of `clock`.
+  pub fn new(address: usize, clock: Clock, ticks_per_read: u64) -> CounterModel {
+    CounterModel {
+      address: address,
+      clock: clock,
+      ticks_per_read: ticks_per_read,
+      offset: 0,
+    }
+  }
+}
+
+impl PeripheralModel for CounterModel {
+  fn handles(&self, address: usize) -> bool {
+    address == self.address
+  }
+
+  fn read(&mut self, _: usize, width: Width) -> u64 {
+    self.clock.advance(self.ticks_per_read);
+    self.clock.now().wrapping_sub(self.offset) & width_mask(width)
+  }
+
+  fn write(&mut self, _: usize, value: u64, _: Width) {
+    self.offset = self.clock.now().wrapping_sub(value);
+  }
+}
+
+/// A UART transmitter. The empty flag in the status register clears on a
+/// write to the data register and sets again after a number of status reads.
+pub struct UartModel {
+  data: usize,
+  status: usize,
+  empty_mask: u64,
+  reads_to_empty: usize,
+  pending_reads: usize,
+  transmitted: Rc<RefCell<Vec<u8>>>,
+}
+
+impl UartModel {
+  pub fn new(data: usize, status: usize, empty_mask: u64,
+      reads_to_empty: usize) -> UartModel {
+    UartModel {
+      data: data,
+      status: status,
+      empty_mask: empty_mask,
+      reads_to_empty: reads_to_empty,
+      pending_reads: 0,
+      transmitted: Rc::new(RefCell::new(Vec::new())),
+    }
+  }
+
+  /// Returns the bytes transmitted so far, shared with the model.
+  pub fn transmitted(&self) -> Rc<RefCell<Vec<u8>>> {
+    self.transmitted.clone()
+  }
+}
+
+impl PeripheralModel for UartModel {
+  fn handles(&self, address: usize) -> bool {
+    address == self.data || address == self.status
+  }
+
+  fn read(&mut self, address: usize, _: Width) -> u64 {
+    if address != self.status {
+      return 0;
+    }
+    if self.pending_reads > 0 {
+      self.pending_reads -= 1;
+      0
+    } else {
+      self.empty_mask
+    }
+  }
+
+  fn write(&mut self, address: usize, value: u64, _: Width) {
+    if address != self.data {
+      return;
+    }
+    if self.pending_reads > 0 {
+      panic!("UART data register at 0x{:x} written while transmitting 0x{:x}",
+          address, value);
+    }
+    self.transmitted.borrow_mut().push(value as u8);
+    self.pending_reads = self.reads_to_empty;
+  }
+}
+
+/// The role of a register simulated by `GpioModel`.
+#[derive(Copy, Clone, PartialEq)]
+pub enum GpioRegister {
+  /// Reads the pin level, writes are ignored.
+  Input,
+  /// Reads and writes the output latch.
+  Output,
+  /// Reads the pin level, writes the output latch.
+  Pin,
+  /// Writing a one sets the output latch.
+  Set,
+  /// Writing a one clears the output latch.
+  Clear,
+  /// Writing a one to the low half sets the latch, to the high half clears it.
+  SetReset,
+}
+
+/// A single GPIO pin.
+///
+/// The output latch follows the writes of the driver, and the pin reads the
+/// latch unless it is driven by a waveform. The waveform starts at the first
+/// read of the pin level and is a list of `(ticks, level)` steps.
+pub struct GpioModel {
+  bit: usize,
+  clock: Clock,
+  registers: Vec<(usize, GpioRegister)>,
+  latch: bool,
+  latches: Rc<RefCell<Vec<(u64, bool)>>>,
+  waveform: Vec<(u64, bool)>,
+  waveform_start: Option<u64>,
+}
+
+impl GpioModel {
+  /// Creates a pin at `bit` of its port registers.
+  pub fn new(clock: Clock, bit: usize) -> GpioModel {
+    GpioModel {
+      bit: bit,
+      clock: clock,
+      registers: Vec::new(),
+      latch: false,
+      latches: Rc::new(RefCell::new(Vec::new())),
+      waveform: Vec::new(),
+      waveform_start: None,
+    }
+  }
+
+  /// Adds a port register of the pin.
+  pub fn with_register(mut self, address: usize, role: GpioRegister) -> GpioModel {
+    self.registers.push((address, role));
+    self
+  }
+
+  /// Drives the pin with the given steps.
+  pub fn with_waveform(mut self, waveform: Vec<(u64, bool)>) -> GpioModel {
+    self.waveform = waveform;
+    self
+  }
+
+  /// Returns the changes of the output latch with their time, shared with the
+  /// model.
+  pub fn latches(&self) -> Rc<RefCell<Vec<(u64, bool)>>> {
+    self.latches.clone()
+  }
+
+  fn role(&self, address: usize) -> Option<GpioRegister> {
+    self.registers.iter().find(|r| r.0 == address).map(|r| r.1)
+  }
+
+  fn level(&mut self) -> bool {
+    let now = self.clock.now();
+    let start = match self.waveform_start {
+      Some(start) => start,
+      None => {
+        self.waveform_start = Some(now);
+        now
+      },
+    };
+
+    let mut end = start;
+    for &(ticks, level) in self.waveform.iter() {
+      end += ticks;
+      if now < end {
+        return level;
+      }
+    }
+    self.latch
+  }
+
+  fn set_latch(&mut self, latch: bool) {
+    if self.latch != latch || self.latches.borrow().is_empty() {
+      self.latches.borrow_mut().push((self.clock.now(), latch));
+    }
+    self.latch = latch;
+  }
+}
+
+impl PeripheralModel for GpioModel {
+  fn handles(&self, address: usize) -> bool {
+    self.role(address).is_some()
+  }
+
+  fn read(&mut self, address: usize, _: Width) -> u64 {
+    let high = match self.role(address) {
+      Some(GpioRegister::Input) | Some(GpioRegister::Pin) => self.level(),
+      Some(GpioRegister::Output) => self.latch,
+      _ => false,
+    };
+    (high as u64) << self.bit
+  }
+
+  fn write(&mut self, address: usize, value: u64, _: Width) {
+    let bit = value & (1 << self.bit) != 0;
+    match self.role(address) {
+      Some(GpioRegister::Output) | Some(GpioRegister::Pin) => self.set_latch(bit),
+      Some(GpioRegister::Set) if bit => self.set_latch(true),
+      Some(GpioRegister::Clear) if bit => self.set_latch(false),
+      Some(GpioRegister::SetReset) => {
+        // Setting takes priority, as on STM32 BSRR
+        if bit {
+          self.set_latch(true);
+        } else if value & (1 << (self.bit + 16)) != 0 {
+          self.set_latch(false);
+        }
+      },
+      _ => (),
+    }
+  }
+}
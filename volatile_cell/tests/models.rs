@@ -0,0 +1,136 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the simulated peripherals, run with `--features replayer`.
+
+#![cfg(feature="replayer")]
+
+#[macro_use] extern crate volatile_cell;
+extern crate expectest;
+
+use volatile_cell::{VolatileCell, VolatileCellReplayer, set_replayer};
+use volatile_cell::models::{Clock, CounterModel, GpioModel, GpioRegister,
+                            RegisterFile, UartModel};
+
+fn address_of<T: Copy>(cell: &VolatileCell<T>) -> usize {
+  cell as *const VolatileCell<T> as usize
+}
+
+#[test]
+fn register_file_reads_back_writes() {
+  init_replayer!();
+  let regs = [VolatileCell::new(0u32), VolatileCell::new(0u32)];
+  add_volatile_model!(RegisterFile::new(address_of(&regs[0]), 8)
+      .with_value(address_of(&regs[1]), 0x55));
+
+  assert_eq!(regs[0].get(), 0);
+  assert_eq!(regs[1].get(), 0x55);
+  regs[0].set(0x1234);
+  assert_eq!(regs[0].get(), 0x1234);
+
+  expect_replayer_valid!();
+}
+
+#[test]
+fn counter_advances_on_reads() {
+  init_replayer!();
+  let cnt = VolatileCell::new(0u32);
+  let clock = Clock::new();
+  add_volatile_model!(CounterModel::new(address_of(&cnt), clock.clone(), 2));
+
+  assert_eq!(cnt.get(), 2);
+  assert_eq!(cnt.get(), 4);
+  cnt.set(100);
+  assert_eq!(cnt.get(), 102);
+  assert_eq!(clock.now(), 6);
+
+  expect_replayer_valid!();
+}
+
+#[test]
+fn uart_sets_empty_flag_after_transmitting() {
+  init_replayer!();
+  let (data, status) = (VolatileCell::new(0u32), VolatileCell::new(0u32));
+  let uart = UartModel::new(address_of(&data), address_of(&status), 0x20, 2);
+  let transmitted = uart.transmitted();
+  add_volatile_model!(uart);
+
+  assert_eq!(status.get(), 0x20);
+  data.set('h' as u32);
+  assert_eq!(status.get(), 0);
+  assert_eq!(status.get(), 0);
+  assert_eq!(status.get(), 0x20);
+  data.set('i' as u32);
+
+  assert_eq!(&*transmitted.borrow(), b"hi");
+  expect_replayer_valid!();
+}
+
+#[test]
+#[should_panic]
+fn uart_fails_on_write_while_transmitting() {
+  init_replayer!();
+  let (data, status) = (VolatileCell::new(0u32), VolatileCell::new(0u32));
+  add_volatile_model!(UartModel::new(address_of(&data), address_of(&status), 0x20, 2));
+
+  data.set('h' as u32);
+  data.set('i' as u32);
+}
+
+#[test]
+fn gpio_latches_writes_and_follows_waveform() {
+  init_replayer!();
+  let (set, clr, pin) = (VolatileCell::new(0u32), VolatileCell::new(0u32),
+                         VolatileCell::new(0u32));
+  let clock = Clock::new();
+  let gpio = GpioModel::new(clock.clone(), 3)
+      .with_register(address_of(&set), GpioRegister::Set)
+      .with_register(address_of(&clr), GpioRegister::Clear)
+      .with_register(address_of(&pin), GpioRegister::Pin)
+      .with_waveform(vec!((10, false), (5, true)));
+  let latches = gpio.latches();
+  add_volatile_model!(gpio);
+
+  set.set(1 << 3);
+  clock.advance(1);
+  clr.set(1 << 3);
+  clr.set(1 << 2);
+  assert_eq!(&*latches.borrow(), &[(0, true), (1, false)]);
+
+  set.set(1 << 3);
+  assert_eq!(pin.get(), 0);
+  clock.advance(10);
+  assert_eq!(pin.get(), 1 << 3);
+  clock.advance(5);
+  set.set(0);
+  clr.set(1 << 3);
+  assert_eq!(pin.get(), 0);
+
+  expect_replayer_valid!();
+}
+
+#[test]
+fn unmodelled_registers_are_replayed() {
+  init_replayer!();
+  let (modelled, replayed) = (VolatileCell::new(0u32), VolatileCell::new(0u32));
+  add_volatile_model!(RegisterFile::new(address_of(&modelled), 4));
+
+  expect_volatile_write!(address_of(&replayed), 0x1);
+
+  modelled.set(0x2);
+  replayed.set(0x1);
+
+  expect_replayer_valid!();
+}
@@ -0,0 +1,183 @@
+// Zinc, the bare metal stack for rust.
+// Copyright 2014 Vladimir "farcaller" Pouzanov <farcaller@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the volatile cell replayer, run with `--features replayer`.
+
+#![cfg(feature="replayer")]
+
+#[macro_use] extern crate volatile_cell;
+extern crate expectest;
+
+use volatile_cell::{VolatileCell, VolatileCellReplayer, set_replayer};
+
+fn address_of<T: Copy>(cell: &VolatileCell<T>) -> usize {
+  cell as *const VolatileCell<T> as usize
+}
+
+#[test]
+fn replays_accesses_of_all_widths() {
+  init_replayer!();
+  let cells: (VolatileCell<u8>, VolatileCell<u16>, VolatileCell<u32>,
+              VolatileCell<u64>) = (VolatileCell::new(0), VolatileCell::new(0),
+                                    VolatileCell::new(0), VolatileCell::new(0));
+
+  expect_volatile_read!(u8, address_of(&cells.0), 0x12);
+  expect_volatile_write!(u16, address_of(&cells.1), 0x1234);
+  expect_volatile_read!(address_of(&cells.2), 0x1234_5678);
+  expect_volatile_write!(u64, address_of(&cells.3), 0x1234_5678_9abc_def0);
+
+  assert_eq!(cells.0.get(), 0x12);
+  cells.1.set(0x1234);
+  assert_eq!(cells.2.get(), 0x1234_5678);
+  cells.3.set(0x1234_5678_9abc_def0);
+
+  expect_replayer_valid!();
+}
+
+#[test]
+fn replays_unaligned_byte_accesses() {
+  init_replayer!();
+  let bytes = [VolatileCell::new(0u8), VolatileCell::new(0u8)];
+
+  expect_volatile_write!(u8, address_of(&bytes[1]), 0xa5);
+  bytes[1].set(0xa5);
+
+  expect_replayer_valid!();
+}
+
+#[test]
+#[should_panic]
+fn fails_on_word_write_when_byte_write_expected() {
+  init_replayer!();
+  let cell = VolatileCell::new(0u32);
+
+  expect_volatile_write!(u8, address_of(&cell), 0x1);
+  cell.set(0x1);
+
+  expect_replayer_valid!();
+}
+
+#[test]
+fn skips_over_repeated_reads() {
+  init_replayer!();
+  let (status, data) = (VolatileCell::new(0u32), VolatileCell::new(0u32));
+
+  expect_volatile_reads!(address_of(&status), 0x1);
+  expect_volatile_write!(address_of(&data), 0x42);
+  expect_volatile_reads!(address_of(&status), 0x1);
+
+  assert_eq!(status.get(), 0x1);
+  assert_eq!(status.get(), 0x1);
+  data.set(0x42);
+
+  expect_replayer_valid!();
+}
+
+#[test]
+fn polls_until_ready() {
+  init_replayer!();
+  let (status, data) = (VolatileCell::new(0u32), VolatileCell::new(0u32));
+
+  expect_volatile_poll!(address_of(&status), 0x0, 0x20, 3);
+  expect_volatile_write!(address_of(&data), 0x42);
+
+  let mut reads = 0;
+  loop {
+    reads += 1;
+    if status.get() & 0x20 != 0 {
+      break;
+    }
+  }
+  data.set(0x42);
+
+  assert_eq!(reads, 4);
+  expect_replayer_valid!();
+}
+
+#[test]
+#[should_panic]
+fn fails_on_poll_stopped_early() {
+  init_replayer!();
+  let status = VolatileCell::new(0u32);
+
+  expect_volatile_poll!(address_of(&status), 0x0, 0x20, 3);
+  status.get();
+
+  expect_replayer_valid!();
+}
+
+#[test]
+fn checks_only_masked_bits() {
+  init_replayer!();
+  let cell = VolatileCell::new(0u32);
+
+  expect_volatile_write_masked!(address_of(&cell), 0x0500, 0xff00);
+  cell.set(0x05a5);
+
+  expect_replayer_valid!();
+}
+
+#[test]
+#[should_panic]
+fn fails_on_masked_bits_mismatch() {
+  init_replayer!();
+  let cell = VolatileCell::new(0u32);
+
+  expect_volatile_write_masked!(address_of(&cell), 0x0500, 0xff00);
+  cell.set(0x06a5);
+
+  expect_replayer_valid!();
+}
+
+#[test]
+fn replays_unordered_group() {
+  init_replayer!();
+  let cells = [VolatileCell::new(0u32), VolatileCell::new(0u32),
+               VolatileCell::new(0u32)];
+
+  expect_volatile_unordered!({
+    expect_volatile_write!(address_of(&cells[0]), 0x1);
+    expect_volatile_write!(address_of(&cells[1]), 0x2);
+    expect_volatile_read!(address_of(&cells[1]), 0x3);
+  });
+  expect_volatile_write!(address_of(&cells[2]), 0x4);
+
+  assert_eq!(cells[1].get(), 0x3);
+  cells[1].set(0x2);
+  cells[0].set(0x1);
+  cells[2].set(0x4);
+
+  expect_replayer_valid!();
+}
+
+#[test]
+#[should_panic]
+fn fails_on_access_leaving_unordered_group_early() {
+  init_replayer!();
+  let cells = [VolatileCell::new(0u32), VolatileCell::new(0u32),
+               VolatileCell::new(0u32)];
+
+  expect_volatile_unordered!({
+    expect_volatile_write!(address_of(&cells[0]), 0x1);
+    expect_volatile_write!(address_of(&cells[1]), 0x2);
+  });
+  expect_volatile_write!(address_of(&cells[2]), 0x4);
+
+  cells[1].set(0x2);
+  cells[2].set(0x4);
+  cells[0].set(0x1);
+
+  expect_replayer_valid!();
+}